@@ -53,6 +53,11 @@ const fn int_size(n: usize) -> usize {
     n + (8 - (n % 8))
 }
 
+#[inline]
+const fn fixed_decimals(n: usize) -> usize {
+    n % 81
+}
+
 #[inline]
 #[cfg(feature = "eip712")]
 const fn ident_char(x: u8, first: bool) -> u8 {
@@ -132,6 +137,8 @@ enum Choice {
     Bool,
     Int,
     Uint,
+    Fixed,
+    Ufixed,
     Address,
     Function,
     FixedBytes,
@@ -151,6 +158,12 @@ impl<'a> arbitrary::Arbitrary<'a> for DynYlmType {
             Choice::Bool => Ok(Self::Bool),
             Choice::Int => u.arbitrary().map(int_size).map(Self::Int),
             Choice::Uint => u.arbitrary().map(int_size).map(Self::Uint),
+            Choice::Fixed => {
+                Ok(Self::Fixed(int_size(u.arbitrary()?), fixed_decimals(u.arbitrary()?)))
+            }
+            Choice::Ufixed => {
+                Ok(Self::Ufixed(int_size(u.arbitrary()?), fixed_decimals(u.arbitrary()?)))
+            }
             Choice::Address => Ok(Self::Address),
             Choice::Function => Ok(Self::Function),
             Choice::FixedBytes => Ok(Self::FixedBytes(u.int_in_range(1..=32)?)),
@@ -316,6 +329,8 @@ impl DynYlmType {
             Just(Self::Address),
             any::<usize>().prop_map(|x| Self::Int(int_size(x))),
             any::<usize>().prop_map(|x| Self::Uint(int_size(x))),
+            any::<(usize, usize)>().prop_map(|(m, n)| Self::Fixed(int_size(m), fixed_decimals(n))),
+            any::<(usize, usize)>().prop_map(|(m, n)| Self::Ufixed(int_size(m), fixed_decimals(n))),
             (1..=32usize).prop_map(Self::FixedBytes),
             Just(Self::Bytes),
             Just(Self::String),
@@ -362,6 +377,12 @@ impl DynYlmValue {
             DynYlmType::Function => u.arbitrary().map(Self::Function),
             &DynYlmType::Int(sz) => u.arbitrary().map(|x| Self::Int(adjust_int(x, sz), sz)),
             &DynYlmType::Uint(sz) => u.arbitrary().map(|x| Self::Uint(adjust_uint(x, sz), sz)),
+            &DynYlmType::Fixed(sz, n) => {
+                u.arbitrary().map(|x| Self::Fixed(adjust_int(x, sz), sz, n))
+            }
+            &DynYlmType::Ufixed(sz, n) => {
+                u.arbitrary().map(|x| Self::Ufixed(adjust_uint(x, sz), sz, n))
+            }
             &DynYlmType::FixedBytes(sz) => {
                 u.arbitrary().map(|x| Self::FixedBytes(adjust_fb(x, sz), sz))
             }
@@ -416,6 +437,12 @@ impl DynYlmValue {
             &DynYlmType::Uint(sz) => {
                 any::<U256>().prop_map(move |x| Self::Uint(adjust_uint(x, sz), sz)).sboxed()
             }
+            &DynYlmType::Fixed(sz, n) => {
+                any::<I256>().prop_map(move |x| Self::Fixed(adjust_int(x, sz), sz, n)).sboxed()
+            }
+            &DynYlmType::Ufixed(sz, n) => {
+                any::<U256>().prop_map(move |x| Self::Ufixed(adjust_uint(x, sz), sz, n)).sboxed()
+            }
             &DynYlmType::FixedBytes(sz) => {
                 any::<B256>().prop_map(move |x| Self::FixedBytes(adjust_fb(x, sz), sz)).sboxed()
             }
@@ -464,6 +491,16 @@ impl DynYlmValue {
             any::<IcanAddress>().prop_map(Self::Address),
             int_strategy::<I256>().prop_map(|(x, sz)| Self::Int(adjust_int(x, sz), sz)),
             int_strategy::<U256>().prop_map(|(x, sz)| Self::Uint(adjust_uint(x, sz), sz)),
+            (int_strategy::<I256>(), any::<usize>()).prop_map(|((x, sz), n)| Self::Fixed(
+                adjust_int(x, sz),
+                sz,
+                fixed_decimals(n)
+            )),
+            (int_strategy::<U256>(), any::<usize>()).prop_map(|((x, sz), n)| Self::Ufixed(
+                adjust_uint(x, sz),
+                sz,
+                fixed_decimals(n)
+            )),
             (any::<B256>(), 1..=32usize).prop_map(|(x, sz)| Self::FixedBytes(adjust_fb(x, sz), sz)),
             any::<Vec<u8>>().prop_map(Self::Bytes),
             any::<String>().prop_map(Self::String),
@@ -705,11 +742,11 @@ mod tests {
         }
 
         match value {
-            DynYlmValue::Int(int, size) => {
+            DynYlmValue::Int(int, size) | DynYlmValue::Fixed(int, size, _) => {
                 let bits = int.into_sign_and_abs().1.bit_len();
                 prop_assert!(bits <= *size, "int: {int}, {size}, {bits}")
             }
-            DynYlmValue::Uint(uint, size) => {
+            DynYlmValue::Uint(uint, size) | DynYlmValue::Ufixed(uint, size, _) => {
                 let bits = uint.bit_len();
                 prop_assert!(bits <= *size, "uint: {uint}, {size}, {bits}")
             }
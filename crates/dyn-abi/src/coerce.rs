@@ -1,6 +1,8 @@
 use crate::{dynamic::ty::as_tuple, DynYlmType, DynYlmValue, Result};
-use alloc::vec::Vec;
-use base_primitives::{Function, IcanAddress, Sign, I256, U256};
+use alloc::{string::String, vec::Vec};
+use base_primitives::{
+    utils::parse_decimal, Function, IcanAddress, ParseFixedError, Sign, I256, U256,
+};
 use base_ylm_types::Word;
 use core::fmt;
 use hex::FromHexError;
@@ -27,11 +29,16 @@ impl DynYlmType {
     /// - [`Uint`](DynYlmType::Uint): `{literal}(\.[0-9]+)?(\s*{unit})?`
     ///   - literal: base 2, 8, 10, or 16 integer literal. If not in base 10, must be prefixed with
     ///     `0b`, `0o`, or `0x` respectively.
-    ///   - unit: same as [Ylem ether units](https://docs.soliditylang.org/en/latest/units-and-global-variables.html#ether-units)
+    ///   - unit: one of the Core units `ore`, `wav`, `grav`, `nucle`, `atom`, `moli` or `core`,
+    ///     or one of the [Ylem ether units](https://docs.soliditylang.org/en/latest/units-and-global-variables.html#ether-units)
+    ///     `wei`, `gwei` or `ether`
     ///   - decimals with more digits than the unit's exponent value are not allowed
+    /// - [`Fixed`](DynYlmType::Fixed): `[+-]?[0-9]+(\.[0-9]+)?`
+    ///   - decimals with more digits than the type's decimals are not allowed
+    /// - [`Ufixed`](DynYlmType::Ufixed): the same as `Fixed`, but must not be negative
     /// - [`FixedBytes`](DynYlmType::FixedBytes): `(0x)?[0-9A-Fa-f]{$0*2}`
     /// - [`IcanAddress`](DynYlmType::Address): `[0-9A-Fa-f]{44}`
-    /// - [`Function`](DynYlmType::Function): `(0x)?[0-9A-Fa-f]{48}`
+    /// - [`Function`](DynYlmType::Function): `(0x)?[0-9A-Fa-f]{52}`
     /// - [`Bytes`](DynYlmType::Bytes): `(0x)?[0-9A-Fa-f]+`
     /// - [`String`](DynYlmType::String): `.*`
     ///   - can be surrounded by a pair of `"` or `'`
@@ -99,6 +106,12 @@ impl<'i> Parser<&'i str, DynYlmValue, ContextError> for ValueParser<'_> {
             &DynYlmType::Uint(size) => {
                 uint(size).parse_next(input).map(|uint| DynYlmValue::Uint(uint, size))
             }
+            &DynYlmType::Fixed(bits, decimals) => fixed(bits, decimals)
+                .parse_next(input)
+                .map(|raw| DynYlmValue::Fixed(raw, bits, decimals)),
+            &DynYlmType::Ufixed(bits, decimals) => ufixed(bits, decimals)
+                .parse_next(input)
+                .map(|raw| DynYlmValue::Ufixed(raw, bits, decimals)),
             &DynYlmType::FixedBytes(size) => {
                 fixed_bytes(size).parse_next(input).map(|word| DynYlmValue::FixedBytes(word, size))
             }
@@ -264,7 +277,7 @@ impl fmt::Display for Error {
             }
             Self::FractionalNotAllowed(n) => write!(
                 f,
-                "non-zero fraction 0.{n} not allowed without specifying non-wei units (gwei, ether, etc.)"
+                "non-zero fraction 0.{n} not allowed without specifying non-ore units (nucle, core, etc.)"
             ),
             Self::InvalidFixedBytesLength(len) => {
                 write!(f, "fixed bytes length {len} greater than 32")
@@ -392,6 +405,69 @@ fn uint<'i>(len: usize) -> impl Parser<&'i str, U256, ContextError> {
     })
 }
 
+#[inline]
+fn fixed<'i>(bits: usize, decimals: usize) -> impl Parser<&'i str, I256, ContextError> {
+    #[cfg(feature = "debug")]
+    let name = format!("fixed{bits}x{decimals}");
+    #[cfg(not(feature = "debug"))]
+    let name = "fixed";
+    trace(
+        name,
+        decimal(decimals).try_map(move |(sign, abs)| {
+            I256::checked_from_sign_and_abs(sign, abs)
+                .filter(|raw| raw.bits() as usize <= bits)
+                .ok_or(Error::IntOverflow)
+        }),
+    )
+}
+
+#[inline]
+fn ufixed<'i>(bits: usize, decimals: usize) -> impl Parser<&'i str, U256, ContextError> {
+    #[cfg(feature = "debug")]
+    let name = format!("ufixed{bits}x{decimals}");
+    #[cfg(not(feature = "debug"))]
+    let name = "ufixed";
+    trace(
+        name,
+        decimal(decimals).try_map(move |(sign, abs)| {
+            if (sign.is_negative() && !abs.is_zero()) || abs.bit_len() > bits {
+                return Err(Error::IntOverflow);
+            }
+            Ok(abs)
+        }),
+    )
+}
+
+/// Parses a decimal number into its sign and `abs(number) * 10^decimals`,
+/// with the same grammar as [`parse_decimal`].
+#[inline]
+fn decimal<'i>(decimals: usize) -> impl Parser<&'i str, (Sign, U256), ContextError> {
+    trace("decimal", move |input: &mut &'i str| {
+        let (s, (_, _, fract)) = spanned((
+            int_sign,
+            digit1,
+            opt(preceded(
+                '.',
+                cut_err(digit1.context(StrContext::Expected(StrContextValue::Description(
+                    "at least one digit",
+                )))),
+            )),
+        ))
+        .parse_next(input)?;
+
+        parse_decimal(s, decimals).map_err(|e| {
+            let e = match e {
+                ParseFixedError::TooManyDecimals => {
+                    Error::TooManyDecimals(decimals, fract.map_or(0, str::len))
+                }
+                // The digits were already checked above.
+                ParseFixedError::InvalidDigit | ParseFixedError::Overflow => Error::IntOverflow,
+            };
+            ErrMode::from_external_error(input, ErrorKind::Verify, e)
+        })
+    })
+}
+
 #[inline]
 fn prefixed_int<'i>(input: &mut &'i str) -> PResult<&'i str> {
     trace("prefixed_int", |input: &mut &'i str| {
@@ -420,9 +496,13 @@ fn int_units(input: &mut &str) -> PResult<usize> {
     trace(
         "int_units",
         dispatch! {alpha0;
-            "ether" => empty.value(18),
-            "gwei" | "nano" | "nanoether" => empty.value(9),
-            "" | "wei" => empty.value(0),
+            "core" | "ether" => empty.value(18),
+            "moli" => empty.value(15),
+            "atom" => empty.value(12),
+            "nucle" | "gwei" | "nano" | "nanoether" => empty.value(9),
+            "grav" => empty.value(6),
+            "wav" => empty.value(3),
+            "" | "ore" | "wei" => empty.value(0),
             _ => fail,
         },
     )
@@ -502,7 +582,7 @@ mod tests {
         boxed::Box,
         string::{String, ToString},
     };
-    use base_primitives::{address, cAddress};
+    use base_primitives::{address, cAddress, hex};
     use core::str::FromStr;
 
     #[track_caller]
@@ -802,6 +882,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn coerce_uint_core() {
+        let units = [
+            ("ore", 0),
+            ("wav", 3),
+            ("grav", 6),
+            ("nucle", 9),
+            ("atom", 12),
+            ("moli", 15),
+            ("core", 18),
+        ];
+        for (unit, exp) in units {
+            assert_eq!(
+                DynYlmType::Uint(256).coerce_str(&format!("1{unit}")).unwrap(),
+                DynYlmValue::Uint(U256::from(10).pow(U256::from(exp)), 256)
+            );
+            assert_eq!(
+                DynYlmType::Uint(256).coerce_str(&format!("2 {unit}")).unwrap(),
+                DynYlmValue::Uint(U256::from(2) * U256::from(10).pow(U256::from(exp)), 256)
+            );
+        }
+
+        assert_eq!(
+            DynYlmType::Uint(256).coerce_str("0.123456789123456789core").unwrap(),
+            DynYlmValue::Uint(U256::from(123456789123456789u64), 256)
+        );
+        assert_eq!(
+            DynYlmType::Uint(256).coerce_str("1.5 nucle").unwrap(),
+            DynYlmValue::Uint(U256::from(1_500_000_000u64), 256)
+        );
+        assert!(DynYlmType::Uint(256).coerce_str("0.1 ore").is_err());
+    }
+
     #[test]
     fn coerce_uint_array_ether() {
         assert_eq!(
@@ -848,6 +961,81 @@ mod tests {
         assert!(DynYlmType::Uint(256).coerce_str("1gwei 1 gwei").is_err());
     }
 
+    #[test]
+    fn coerce_fixed() {
+        let raw = |n: i128| I256::try_from(n).unwrap();
+        let ty = DynYlmType::Fixed(128, 18);
+        assert_eq!(
+            ty.coerce_str("1.5").unwrap(),
+            DynYlmValue::Fixed(raw(1_500_000_000_000_000_000), 128, 18)
+        );
+        assert_eq!(
+            ty.coerce_str("-0.000000000000000001").unwrap(),
+            DynYlmValue::Fixed(raw(-1), 128, 18)
+        );
+        assert_eq!(
+            ty.coerce_str("+2").unwrap(),
+            DynYlmValue::Fixed(raw(2 * 10i128.pow(18)), 128, 18)
+        );
+        assert_eq!(ty.coerce_str("-0").unwrap(), DynYlmValue::Fixed(I256::ZERO, 128, 18));
+
+        let e = ty.coerce_str("0.0000000000000000001").unwrap_err();
+        assert_error_contains(&e, &Error::TooManyDecimals(18, 19).to_string());
+        for s in ["", "-", "1.", ".5", "1..5", "1.5.", "0x1", "1 core", "1e18"] {
+            assert!(ty.coerce_str(s).is_err(), "{s:?}");
+        }
+
+        let ty = DynYlmType::Fixed(8, 1);
+        assert_eq!(ty.coerce_str("12.7").unwrap(), DynYlmValue::Fixed(raw(127), 8, 1));
+        assert_eq!(ty.coerce_str("-12.8").unwrap(), DynYlmValue::Fixed(raw(-128), 8, 1));
+        let e = ty.coerce_str("12.8").unwrap_err();
+        assert_error_contains(&e, &Error::IntOverflow.to_string());
+        assert!(ty.coerce_str("-12.9").is_err());
+    }
+
+    #[test]
+    fn coerce_ufixed() {
+        let ty = DynYlmType::Ufixed(128, 18);
+        assert_eq!(
+            ty.coerce_str("1234.5").unwrap(),
+            DynYlmValue::Ufixed(U256::from(1_234_500_000_000_000_000_000u128), 128, 18)
+        );
+        assert_eq!(ty.coerce_str("-0").unwrap(), DynYlmValue::Ufixed(U256::ZERO, 128, 18));
+        assert!(ty.coerce_str("-1").is_err());
+
+        for s in ["1.", ".5", "-.5"] {
+            assert!(ty.coerce_str(s).is_err(), "{s:?}");
+        }
+
+        let ty = DynYlmType::Ufixed(8, 1);
+        assert_eq!(ty.coerce_str("25.5").unwrap(), DynYlmValue::Ufixed(U256::from(255), 8, 1));
+        assert!(ty.coerce_str("25.6").is_err());
+
+        // 10^80 does not fit in 256 bits
+        let ty = DynYlmType::Ufixed(256, 80);
+        assert!(ty.coerce_str("1").is_err());
+        assert_eq!(
+            ty.coerce_str("0.00000000000000000000000000000000000000000000000000000000000000000000000000000003")
+                .unwrap(),
+            DynYlmValue::Ufixed(U256::from(3), 256, 80)
+        );
+
+        let ty: DynYlmType = "(ufixed128x18,fixed8x1[])".parse().unwrap();
+        let value = ty.coerce_str("(0.5, [1.5, -2])").unwrap();
+        assert_eq!(
+            value,
+            DynYlmValue::Tuple(vec![
+                DynYlmValue::Ufixed(U256::from(500_000_000_000_000_000u64), 128, 18),
+                DynYlmValue::Array(vec![
+                    DynYlmValue::Fixed(I256::try_from(15).unwrap(), 8, 1),
+                    DynYlmValue::Fixed(I256::try_from(-20).unwrap(), 8, 1),
+                ]),
+            ])
+        );
+        assert!(value.matches(&ty));
+        assert_eq!(value.as_type().unwrap(), ty);
+    }
+
     #[test]
     fn coerce_fixed_bytes() {
         let mk_word = |sl: &[u8]| {
@@ -932,22 +1120,35 @@ mod tests {
     fn coerce_function() {
         assert_eq!(
             DynYlmType::Function
-                .coerce_str("000000000000000000000000000000000000000000000000")
+                .coerce_str("0000000000000000000000000000000000000000000000000000")
                 .unwrap(),
             DynYlmValue::Function(Function::ZERO)
         );
         assert_eq!(
             DynYlmType::Function
-                .coerce_str("0x111111111111111111111111111111111111111111111111")
+                .coerce_str("0x1111111111111111111111111111111111111111111111111111")
                 .unwrap(),
-            DynYlmValue::Function(Function::new([0x11; 24]))
+            DynYlmValue::Function(Function::new([0x11; 26]))
         );
         assert_eq!(
             DynYlmType::Function
-                .coerce_str("222222222222222222222222222222222222222222222222")
+                .coerce_str("2222222222222222222222222222222222222222222222222222")
                 .unwrap(),
-            DynYlmValue::Function(Function::new([0x22; 24]))
+            DynYlmValue::Function(Function::new([0x22; 26]))
         );
+        assert_eq!(
+            DynYlmType::Function
+                .coerce_str("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc512345678")
+                .unwrap(),
+            DynYlmValue::Function(Function::from_address_and_selector(
+                cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"),
+                hex!("12345678")
+            ))
+        );
+        // Ethereum-sized function pointers are rejected
+        DynYlmType::Function
+            .coerce_str("111111111111111111111111111111111111111111111111")
+            .unwrap_err();
     }
 
     #[test]
@@ -1227,11 +1428,11 @@ mod tests {
                 DynYlmType::Array(Box::new(DynYlmType::Tuple(vec![DynYlmType::Address]))),
                 DynYlmType::Uint(256),
             ])
-            .coerce_str("([(00005c9d55b78febcc2061715ba4f57ecf8ea2711f2c)],2)")
+            .coerce_str("([(00715c9d55b78febcc2061715ba4f57ecf8ea2711f2c)],2)")
             .unwrap(),
             DynYlmValue::Tuple(vec![
                 DynYlmValue::Array(vec![DynYlmValue::Tuple(vec![DynYlmValue::Address(
-                    cAddress!("00005c9d55b78febcc2061715ba4f57ecf8ea2711f2c")
+                    cAddress!("00715c9d55b78febcc2061715ba4f57ecf8ea2711f2c")
                 )])]),
                 DynYlmValue::Uint(U256::from(2), 256),
             ])
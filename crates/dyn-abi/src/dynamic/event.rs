@@ -157,7 +157,7 @@ mod test {
     fn it_decodes_logs_with_indexed_params() {
         let t0 = b256!("cf74b4e62f836eeedcd6f92120ffb5afea90e6fa490d36f8b81075e2a7de0cf7");
         let log = LogData::new_unchecked(
-            vec![t0, b256!("0000000000000000000000000000000000000000000000000000000000012321")],
+            vec![t0, b256!("0000000000000000000000800000000000000000000000000000000000012321")],
             bytes!(
                 "
 			    0000000000000000000000000000000000000000000000000000000000012345
@@ -177,7 +177,7 @@ mod test {
         let decoded = event.decode_log(&log, true).unwrap();
         assert_eq!(
             decoded.indexed,
-            vec![DynYlmValue::Address(cAddress!("00000000000000000000000000000000000000012321"))]
+            vec![DynYlmValue::Address(cAddress!("00800000000000000000000000000000000000012321"))]
         );
     }
 }
@@ -84,6 +84,10 @@ pub enum DynYlmType {
     Int(usize),
     /// Unsigned Integer.
     Uint(usize),
+    /// Signed fixed-point number: the number of bits and decimals.
+    Fixed(usize, usize),
+    /// Unsigned fixed-point number: the number of bits and decimals.
+    Ufixed(usize, usize),
     /// Fixed-size bytes, up to 32.
     FixedBytes(usize),
     /// Address.
@@ -161,6 +165,8 @@ impl DynYlmType {
             DynYlmType::Bool
             | DynYlmType::Int(_)
             | DynYlmType::Uint(_)
+            | DynYlmType::Fixed(..)
+            | DynYlmType::Ufixed(..)
             | DynYlmType::FixedBytes(_)
             | DynYlmType::Address
             | DynYlmType::Function
@@ -231,6 +237,12 @@ impl DynYlmType {
             Self::Bool => matches!(value, DynYlmValue::Bool(_)),
             Self::Int(size) => matches!(value, DynYlmValue::Int(_, s) if s == size),
             Self::Uint(size) => matches!(value, DynYlmValue::Uint(_, s) if s == size),
+            Self::Fixed(bits, decimals) => {
+                matches!(value, DynYlmValue::Fixed(_, b, d) if b == bits && d == decimals)
+            }
+            Self::Ufixed(bits, decimals) => {
+                matches!(value, DynYlmValue::Ufixed(_, b, d) if b == bits && d == decimals)
+            }
             Self::FixedBytes(size) => matches!(value, DynYlmValue::FixedBytes(_, s) if s == size),
             Self::Address => matches!(value, DynYlmValue::Address(_)),
             Self::Function => matches!(value, DynYlmValue::Function(_)),
@@ -281,6 +293,18 @@ impl DynYlmType {
                 Ok(DynYlmValue::Uint(ylm_data::Uint::<256>::detokenize(word.into()), *size))
             }
 
+            (Self::Fixed(bits, decimals), DynToken::Word(word)) => Ok(DynYlmValue::Fixed(
+                ylm_data::Int::<256>::detokenize(word.into()),
+                *bits,
+                *decimals,
+            )),
+
+            (Self::Ufixed(bits, decimals), DynToken::Word(word)) => Ok(DynYlmValue::Ufixed(
+                ylm_data::Uint::<256>::detokenize(word.into()),
+                *bits,
+                *decimals,
+            )),
+
             (Self::FixedBytes(size), DynToken::Word(word)) => Ok(DynYlmValue::FixedBytes(
                 ylm_data::FixedBytes::<32>::detokenize(word.into()),
                 *size,
@@ -390,6 +414,16 @@ impl DynYlmType {
                 out.push_str(itoa::Buffer::new().format(*size));
             }
 
+            Self::Fixed(bits, decimals) | Self::Ufixed(bits, decimals) => {
+                if let Self::Ufixed(..) = self {
+                    out.push('u');
+                }
+                out.push_str("fixed");
+                out.push_str(itoa::Buffer::new().format(*bits));
+                out.push('x');
+                out.push_str(itoa::Buffer::new().format(*decimals));
+            }
+
             as_tuple!(Self tuple) => {
                 out.push('(');
                 for (i, val) in tuple.iter().enumerate() {
@@ -433,6 +467,10 @@ impl DynYlmType {
             | Self::Uint(_) // 4 + 3
             => 8,
 
+            | Self::Fixed(..) // 5 + 3 + 1 + 2
+            | Self::Ufixed(..) // 6 + 3 + 1 + 2
+            => 16,
+
             | Self::Array(t) // t + 2
             | Self::FixedArray(t, _) // t + 2 + log10(len)
             => t.ylm_type_name_capacity() + 8,
@@ -478,7 +516,9 @@ impl DynYlmType {
             | Self::Bool
             | Self::FixedBytes(_)
             | Self::Int(_)
-            | Self::Uint(_) => DynToken::Word(Word::ZERO),
+            | Self::Uint(_)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => DynToken::Word(Word::ZERO),
 
             Self::Bytes | Self::String => DynToken::PackedSeq(&[]),
 
@@ -507,7 +547,9 @@ impl DynYlmType {
             | Self::Bool
             | Self::FixedBytes(_)
             | Self::Int(_)
-            | Self::Uint(_) => self.detokenize(DynToken::Word(topic)).unwrap(),
+            | Self::Uint(_)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => self.detokenize(DynToken::Word(topic)).unwrap(),
             _ => DynYlmValue::FixedBytes(topic, 32),
         }
     }
@@ -568,6 +610,8 @@ impl DynYlmType {
             DynYlmType::Bool |
             DynYlmType::Int(_) |
             DynYlmType::Uint(_) |
+            DynYlmType::Fixed(..) |
+            DynYlmType::Ufixed(..) |
             DynYlmType::FixedBytes(_) |
             DynYlmType::Address |
             DynYlmType::Function |
@@ -1,7 +1,7 @@
 use super::ty::as_tuple;
 use crate::{DynToken, DynYlmType, Word};
 use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
-use base_primitives::{Function, IcanAddress, I256, U256};
+use base_primitives::{Fixed, Function, IcanAddress, Ufixed, I256, U256};
 use base_ylm_types::{abi::Encoder, utils::words_for_len};
 
 #[cfg(feature = "eip712")]
@@ -65,6 +65,12 @@ pub enum DynYlmValue {
     Int(I256, usize),
     /// An unsigned integer. The second parameter is the number of bits, not bytes.
     Uint(U256, usize),
+    /// A signed fixed-point number. The first parameter is the raw value,
+    /// `value * 10^decimals`, followed by the number of bits and decimals.
+    Fixed(I256, usize, usize),
+    /// An unsigned fixed-point number. The first parameter is the raw value,
+    /// `value * 10^decimals`, followed by the number of bits and decimals.
+    Ufixed(U256, usize, usize),
     /// A fixed-length byte array. The second parameter is the number of bytes.
     FixedBytes(Word, usize),
     /// An address.
@@ -189,6 +195,20 @@ impl From<U256> for DynYlmValue {
     }
 }
 
+impl<const M: usize, const N: usize> From<Fixed<M, N>> for DynYlmValue {
+    #[inline]
+    fn from(value: Fixed<M, N>) -> Self {
+        Self::Fixed(value.into_raw(), M, N)
+    }
+}
+
+impl<const M: usize, const N: usize> From<Ufixed<M, N>> for DynYlmValue {
+    #[inline]
+    fn from(value: Ufixed<M, N>) -> Self {
+        Self::Ufixed(value.into_raw(), M, N)
+    }
+}
+
 impl DynYlmValue {
     /// The Ylem type. This returns the Ylem type corresponding to this
     /// value, if it is known. A type will not be known if the value contains
@@ -202,6 +222,8 @@ impl DynYlmValue {
             Self::FixedBytes(_, size) => DynYlmType::FixedBytes(*size),
             Self::Int(_, size) => DynYlmType::Int(*size),
             Self::Uint(_, size) => DynYlmType::Uint(*size),
+            Self::Fixed(_, bits, decimals) => DynYlmType::Fixed(*bits, *decimals),
+            Self::Ufixed(_, bits, decimals) => DynYlmType::Ufixed(*bits, *decimals),
             Self::String(_) => DynYlmType::String,
             Self::Tuple(inner) => {
                 return inner
@@ -259,6 +281,16 @@ impl DynYlmValue {
                 out.push_str(itoa::Buffer::new().format(*size));
             }
 
+            Self::Fixed(_, bits, decimals) | Self::Ufixed(_, bits, decimals) => {
+                if let Self::Ufixed(..) = self {
+                    out.push('u');
+                }
+                out.push_str("fixed");
+                out.push_str(itoa::Buffer::new().format(*bits));
+                out.push('x');
+                out.push_str(itoa::Buffer::new().format(*decimals));
+            }
+
             Self::Array(values) | Self::FixedArray(values) => {
                 // SAFETY: checked in `ylm_type_name_capacity`
                 debug_assert!(!values.is_empty());
@@ -306,6 +338,8 @@ impl DynYlmValue {
             | Self::Bytes(_)
             | Self::String(_) => Some(8),
 
+            Self::Fixed(..) | Self::Ufixed(..) => Some(16),
+
             Self::Array(t) | Self::FixedArray(t) => {
                 t.first().and_then(Self::ylm_type_name_capacity).map(|x| x + 8)
             }
@@ -339,6 +373,8 @@ impl DynYlmValue {
             Self::Bool(_)
                 | Self::Int(..)
                 | Self::Uint(..)
+                | Self::Fixed(..)
+                | Self::Ufixed(..)
                 | Self::FixedBytes(..)
                 | Self::Address(_)
         )
@@ -351,6 +387,8 @@ impl DynYlmValue {
             Self::Bool(b) => Some(Word::with_last_byte(b as u8)),
             Self::Int(i, _) => Some(i.into()),
             Self::Uint(u, _) => Some(u.into()),
+            Self::Fixed(i, ..) => Some(i.into()),
+            Self::Ufixed(u, ..) => Some(u.into()),
             Self::FixedBytes(w, _) => Some(w),
             Self::Address(a) => Some(a.into_word()),
             Self::Function(f) => Some(f.into_word()),
@@ -412,6 +450,26 @@ impl DynYlmValue {
         }
     }
 
+    /// Fallible cast to the contents of a variant: the raw value, the number
+    /// of bits and the number of decimals.
+    #[inline]
+    pub const fn as_fixed(&self) -> Option<(I256, usize, usize)> {
+        match self {
+            Self::Fixed(i, bits, decimals) => Some((*i, *bits, *decimals)),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant: the raw value, the number
+    /// of bits and the number of decimals.
+    #[inline]
+    pub const fn as_ufixed(&self) -> Option<(U256, usize, usize)> {
+        match self {
+            Self::Ufixed(u, bits, decimals) => Some((*u, *bits, *decimals)),
+            _ => None,
+        }
+    }
+
     /// Fallible cast to the contents of a variant.
     #[inline]
     pub fn as_str(&self) -> Option<&str> {
@@ -524,6 +582,8 @@ impl DynYlmValue {
             | Self::Bool(_)
             | Self::Int(..)
             | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(..) => false,
             Self::Bytes(_) | Self::String(_) | Self::Array(_) => true,
             as_fixed_seq!(tuple) => tuple.iter().any(Self::is_dynamic),
@@ -580,7 +640,9 @@ impl DynYlmValue {
             | Self::Bool(_)
             | Self::FixedBytes(..)
             | Self::Int(..)
-            | Self::Uint(..) => 0,
+            | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => 0,
 
             // `self.as_packed_seq()`
             // 1 for the length, then the body padded to the next word.
@@ -624,7 +686,9 @@ impl DynYlmValue {
             | Self::Bool(_)
             | Self::FixedBytes(..)
             | Self::Int(..)
-            | Self::Uint(..) => enc.append_word(unsafe { self.as_word().unwrap_unchecked() }),
+            | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => enc.append_word(unsafe { self.as_word().unwrap_unchecked() }),
 
             Self::String(_) | Self::Bytes(_) | Self::Array(_) => enc.append_indirection(),
 
@@ -649,7 +713,9 @@ impl DynYlmValue {
             | Self::Bool(_)
             | Self::FixedBytes(..)
             | Self::Int(..)
-            | Self::Uint(..) => {}
+            | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => {}
 
             Self::String(string) => enc.append_packed_seq(string.as_bytes()),
             Self::Bytes(bytes) => enc.append_packed_seq(bytes),
@@ -678,12 +744,12 @@ impl DynYlmValue {
             Self::String(s) => buf.extend_from_slice(s.as_bytes()),
             Self::Bytes(bytes) => buf.extend_from_slice(bytes),
             Self::FixedBytes(word, size) => buf.extend_from_slice(&word[..(*size).max(32)]),
-            Self::Int(num, size) => {
+            Self::Int(num, size) | Self::Fixed(num, size, _) => {
                 let byte_size = *size / 8;
                 let start = 32usize.saturating_sub(byte_size);
                 buf.extend_from_slice(&num.to_be_bytes::<32>()[start..]);
             }
-            Self::Uint(num, size) => {
+            Self::Uint(num, size) | Self::Ufixed(num, size, _) => {
                 let byte_size = *size / 8;
                 let start = 32usize.saturating_sub(byte_size);
                 buf.extend_from_slice(&num.to_be_bytes::<32>()[start..]);
@@ -743,8 +809,8 @@ impl DynYlmValue {
             Self::Bool(b) => Word::with_last_byte(*b as u8).into(),
             Self::Bytes(buf) => DynToken::PackedSeq(buf),
             Self::FixedBytes(buf, _) => (*buf).into(),
-            Self::Int(int, _) => int.to_be_bytes::<32>().into(),
-            Self::Uint(uint, _) => uint.to_be_bytes::<32>().into(),
+            Self::Int(int, _) | Self::Fixed(int, ..) => int.to_be_bytes::<32>().into(),
+            Self::Uint(uint, _) | Self::Ufixed(uint, ..) => uint.to_be_bytes::<32>().into(),
             Self::String(s) => DynToken::PackedSeq(s.as_bytes()),
             Self::Array(t) => DynToken::from_dyn_seq(t),
             as_fixed_seq!(t) => DynToken::from_fixed_seq(t),
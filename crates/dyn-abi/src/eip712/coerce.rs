@@ -13,6 +13,8 @@ impl DynYlmType {
             Self::Bool
             | Self::Int(_)
             | Self::Uint(_)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(_)
             | Self::Address
             | Self::Function
@@ -42,6 +44,7 @@ impl DynYlmType {
             Self::Bool => bool(value).map(DynYlmValue::Bool),
             &Self::Int(n) => int(n, value).map(|x| DynYlmValue::Int(x, n)),
             &Self::Uint(n) => uint(n, value).map(|x| DynYlmValue::Uint(x, n)),
+            Self::Fixed(..) | Self::Ufixed(..) => fixed(self, value),
             &Self::FixedBytes(n) => fixed_bytes(n, value).map(|x| DynYlmValue::FixedBytes(x, n)),
             Self::Address => address(value).map(DynYlmValue::Address),
             Self::Function => function(value).map(DynYlmValue::Function),
@@ -76,6 +79,14 @@ fn uint(n: usize, value: &serde_json::Value) -> Option<U256> {
     .and_then(|x| (x.bit_len() <= n).then_some(x))
 }
 
+fn fixed(ty: &DynYlmType, value: &serde_json::Value) -> Option<DynYlmValue> {
+    match value {
+        serde_json::Value::String(s) => ty.coerce_str(s).ok(),
+        serde_json::Value::Number(n) => ty.coerce_str(&n.to_string()).ok(),
+        _ => None,
+    }
+}
+
 fn fixed_bytes(n: usize, value: &serde_json::Value) -> Option<Word> {
     if let Some(Ok(buf)) = value.as_str().map(hex::decode) {
         let mut word = Word::ZERO;
@@ -194,6 +205,16 @@ mod tests {
         assert_eq!(ty.coerce_json(&j), Ok(DynYlmValue::Bytes(vec![1, 2, 3, 4])));
     }
 
+    #[test]
+    fn test_fixed() {
+        let ty = DynYlmType::Ufixed(128, 18);
+        let half = DynYlmValue::Ufixed(U256::from(500_000_000_000_000_000u64), 128, 18);
+        assert_eq!(ty.coerce_json(&json!("0.5")), Ok(half.clone()));
+        assert_eq!(ty.coerce_json(&json!(0.5)), Ok(half));
+        assert!(ty.coerce_json(&json!(-1)).is_err());
+        assert!(ty.coerce_json(&json!(true)).is_err());
+    }
+
     #[test]
     fn it_coerces() {
         let j = json!({
@@ -2,8 +2,8 @@
 //!
 //! This allows for the encoding of EIP-712 messages without having to know the
 //! types at compile time. This is useful for things like off-chain signing.
-//! It implements the encoding portion of the EIP-712 spec, and does not
-//! contain any of the signing logic.
+//! It implements the encoding portion of the EIP-712 spec, and connects the
+//! resulting hashes to Ed448 [signatures](base_primitives::Signature).
 //!
 //! <https://eips.ethereum.org/EIPS/eip-712#specification-of-the-eth_signtypeddata-json-rpc>
 
@@ -12,6 +12,9 @@ pub mod parser;
 mod typed_data;
 pub use typed_data::{Eip712Types, TypedData};
 
+mod signature;
+pub use signature::TypedDataSignatureError;
+
 mod resolver;
 pub use resolver::{PropertyDef, Resolver, TypeDef};
 
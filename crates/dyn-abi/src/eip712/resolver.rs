@@ -9,7 +9,7 @@ use alloc::{
     vec::Vec,
 };
 use base_primitives::{sha3, B256};
-use base_ylm_types::YlmStruct;
+use base_ylm_types::{Eip712Domain, YlmStruct};
 use core::{cmp::Ordering, fmt};
 use parser::{RootType, TypeSpecifier, TypeStem};
 use serde::{Deserialize, Deserializer, Serialize};
@@ -488,6 +488,27 @@ impl Resolver {
         Ok(sha3(to_hash))
     }
 
+    /// Hashes `domain` according to the `EIP712Domain` type of this resolver.
+    ///
+    /// Like go-core, only the fields declared in the type are encoded, in the
+    /// declared order. Declared fields that the domain does not set are left
+    /// out too, where go-core would fail. If the resolver has no
+    /// `EIP712Domain` type, this is the same as [`Eip712Domain::separator`].
+    pub fn domain_separator(&self, domain: &Eip712Domain) -> Result<B256> {
+        let declared = match self.nodes.get(Eip712Domain::NAME) {
+            Some(declared) => declared,
+            None => return Ok(domain.separator()),
+        };
+        let json = serde_json::to_value(domain).map_err(|e| Error::custom(e.to_string()))?;
+        let props = declared.props().iter().filter(|prop| json.get(prop.name()).is_some());
+
+        let mut resolver = Self::default();
+        resolver
+            .ingest(TypeDef::new_unchecked(Eip712Domain::NAME.into(), props.cloned().collect()));
+        let ty = resolver.resolve(Eip712Domain::NAME)?;
+        resolver.eip712_data_word(&ty.coerce_json(&json)?)
+    }
+
     /// Check if the resolver graph contains a type by its name.
     ///
     /// ## Warning
@@ -0,0 +1,183 @@
+use crate::{eip712::TypedData, Error};
+use base_primitives::{IcanAddress, Signature, SignatureError};
+use core::fmt;
+
+/// Errors when signing [`TypedData`] or verifying its signature.
+#[derive(Debug)]
+pub enum TypedDataSignatureError {
+    /// The typed data could not be hashed.
+    Eip712(Error),
+    /// The signature is invalid or was made by a different address.
+    Signature(SignatureError),
+    /// The signer failed to sign the hash.
+    #[cfg(feature = "signer")]
+    Signer(base_primitives::SignerError),
+}
+
+impl From<Error> for TypedDataSignatureError {
+    #[inline]
+    fn from(e: Error) -> Self {
+        Self::Eip712(e)
+    }
+}
+
+impl From<SignatureError> for TypedDataSignatureError {
+    #[inline]
+    fn from(e: SignatureError) -> Self {
+        Self::Signature(e)
+    }
+}
+
+#[cfg(feature = "signer")]
+impl From<base_primitives::SignerError> for TypedDataSignatureError {
+    #[inline]
+    fn from(e: base_primitives::SignerError) -> Self {
+        Self::Signer(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TypedDataSignatureError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Eip712(e) => Some(e),
+            Self::Signature(e) => Some(e),
+            #[cfg(feature = "signer")]
+            Self::Signer(e) => Some(e),
+        }
+    }
+}
+
+impl fmt::Display for TypedDataSignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Eip712(e) => e.fmt(f),
+            Self::Signature(e) => e.fmt(f),
+            #[cfg(feature = "signer")]
+            Self::Signer(e) => e.fmt(f),
+        }
+    }
+}
+
+impl TypedData {
+    /// Signs the [EIP-712 signing hash](Self::eip712_signing_hash) of this
+    /// value.
+    #[cfg(feature = "signer")]
+    pub fn sign_typed_data(
+        &self,
+        signer: &base_primitives::LocalSigner,
+    ) -> Result<Signature, TypedDataSignatureError> {
+        let hash = self.eip712_signing_hash()?;
+        signer.sign_hash(&hash).map_err(Into::into)
+    }
+
+    /// Checks that `signature` is a valid signature of the
+    /// [EIP-712 signing hash](Self::eip712_signing_hash) of this value, made by
+    /// `expected` on the given network.
+    pub fn verify_typed_data(
+        &self,
+        signature: &Signature,
+        expected: &IcanAddress,
+        network_id: u64,
+    ) -> Result<(), TypedDataSignatureError> {
+        let hash = self.eip712_signing_hash()?;
+        signature.verify(&hash, expected, network_id).map_err(Into::into)
+    }
+
+    /// Verifies `signature` against the
+    /// [EIP-712 signing hash](Self::eip712_signing_hash) of this value, and
+    /// returns the address of the signer on the given network.
+    pub fn recover_typed_data_address(
+        &self,
+        signature: &Signature,
+        network_id: u64,
+    ) -> Result<IcanAddress, TypedDataSignatureError> {
+        let hash = self.eip712_signing_hash()?;
+        signature.recover_address_from_prehash(&hash, network_id).map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base_primitives::{b256, cAddress, hex};
+    use serde_json::json;
+
+    // Same as `sign_verify_nested` in `base-ylm-types`.
+    #[test]
+    fn sign_verify_nested() {
+        let json = json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "version", "type": "string" },
+                    { "name": "verifyingContract", "type": "address" }
+                ],
+                "Person": [
+                    { "name": "name", "type": "string" },
+                    { "name": "wallet", "type": "address" }
+                ],
+                "Order": [
+                    { "name": "maker", "type": "Person" },
+                    { "name": "takers", "type": "Person[]" },
+                    { "name": "amounts", "type": "uint256[]" },
+                    { "name": "salt", "type": "bytes32" }
+                ]
+            },
+            "primaryType": "Order",
+            "domain": {
+                "name": "Exchange",
+                "version": "1",
+                "verifyingContract": "cb203333333333333333333333333333333333333333"
+            },
+            "message": {
+                "maker": { "name": "Cow", "wallet": "cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5" },
+                "takers": [
+                    { "name": "Bob", "wallet": "cb751111111111111111111111111111111111111111" },
+                    { "name": "Alice", "wallet": "cb962222222222222222222222222222222222222222" }
+                ],
+                "amounts": ["100", "0x100000000000000000000000000000000"],
+                "salt": "0x4242424242424242424242424242424242424242424242424242424242424242"
+            }
+        });
+        let typed_data: TypedData = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            typed_data.eip712_signing_hash().unwrap(),
+            b256!("fe046b235feb4399f88f17b5638b94ea1739a1fe34414f8bcdbd582e75ecf754")
+        );
+
+        let maker = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+        let signature = Signature::from_bytes(&hex!(
+            "ef5caff99a454ff14b60370496b0a49067deb37bde920d2e6dde1ad542a0a7406a67844e83a2b72b71e21376e155d3372c77fc1016e0765680cc6f7b28021af3b711ff8bc3cbf4d0ae3683329c925a8de8384f2c5c778af0e86a76c24a4ac3f9a5b93e9dd61e18c455ce6aa30d40ad303600"
+            "315484db568379ce94f9c894e3e6e4c7ee216676b713ca892d9b26746ae902a772e217a6a8bb493ce2bb313cf0cb66e76765d4c45ec6b68600"
+        ))
+        .unwrap();
+
+        #[cfg(feature = "signer")]
+        {
+            let signer = base_primitives::LocalSigner::from_slice(&hex!("69bb68c3a00a0cd9cbf2cab316476228c758329bbfe0b1759e8634694a9497afea05bcbf24e2aa0627eac4240484bb71de646a9296872a3c0e")).unwrap();
+            assert_eq!(typed_data.sign_typed_data(&signer).unwrap(), signature);
+        }
+
+        typed_data.verify_typed_data(&signature, &maker, 1).unwrap();
+        assert_eq!(typed_data.recover_typed_data_address(&signature, 1).unwrap(), maker);
+        assert!(matches!(
+            typed_data.verify_typed_data(&signature, &maker, 3),
+            Err(TypedDataSignatureError::Signature(SignatureError::AddressMismatch { .. }))
+        ));
+
+        let mut tampered = typed_data;
+        tampered.message["amounts"][0] = json!("101");
+        assert!(matches!(
+            tampered.verify_typed_data(&signature, &maker, 1),
+            Err(TypedDataSignatureError::Signature(_))
+        ));
+
+        tampered.message["takers"] = json!("not an array");
+        assert!(matches!(
+            tampered.verify_typed_data(&signature, &maker, 1),
+            Err(TypedDataSignatureError::Eip712(_))
+        ));
+    }
+}
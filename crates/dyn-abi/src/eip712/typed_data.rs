@@ -199,15 +199,23 @@ impl TypedData {
         self.resolver.encode_type(&self.primary_type)
     }
 
+    /// Calculate the domain separator for this value.
+    ///
+    /// The domain is encoded according to the `EIP712Domain` entry of the
+    /// types, if there is one. See [`Resolver::domain_separator`].
+    pub fn domain_separator(&self) -> Result<B256> {
+        self.resolver.domain_separator(&self.domain)
+    }
+
     /// Calculate the EIP-712 signing hash for this value.
     ///
-    /// This is the hash of the magic bytes 0x1901 concatenated with the domain
-    /// separator and the `hashStruct` result.
+    /// This is the hash of the magic bytes 0x1901 concatenated with the
+    /// [domain separator](Self::domain_separator) and the `hashStruct` result.
     pub fn eip712_signing_hash(&self) -> Result<B256> {
         let mut buf = [0u8; 66];
         buf[0] = 0x19;
         buf[1] = 0x01;
-        buf[2..34].copy_from_slice(self.domain.separator().as_slice());
+        buf[2..34].copy_from_slice(self.domain_separator()?.as_slice());
 
         // compatibility with <https://github.com/MetaMask/eth-sig-util>
         let len = if self.primary_type != "EIP712Domain" {
@@ -291,6 +299,99 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_full_domain_network_id() {
+        let json = json!({
+            "types": {
+                "EIP712Domain": [
+                    {
+                        "name": "name",
+                        "type": "string"
+                    },
+                    {
+                        "name": "version",
+                        "type": "string"
+                    },
+                    {
+                        "name": "networkId",
+                        "type": "uint256"
+                    },
+                    {
+                        "name": "verifyingContract",
+                        "type": "address"
+                    },
+                    {
+                        "name": "salt",
+                        "type": "bytes32"
+                    }
+                ]
+            },
+            "primaryType": "EIP712Domain",
+            "domain": {
+                "name": "example.metamask.io",
+                "version": "1",
+                "networkId": 1,
+                "verifyingContract": "0x00000000000000000000000000000000000000000000",
+                "salt": "0xa2fd14675b9e4427cb66663f91d6cadfb9f7c7ab9b44b3bfe110d236a5f317d0"
+            },
+            "message": {}
+        });
+
+        let typed_data: TypedData = serde_json::from_value(json).unwrap();
+
+        // computed independently with Python's `hashlib.sha3_256`
+        let separator = typed_data.domain_separator().unwrap();
+        assert_eq!(
+            hex::encode(&separator[..]),
+            "c61fc58c7a88cc36800295fbf21a3a0e239f274fe5964a06022acfe1ec04fc8a",
+        );
+        let hash = typed_data.eip712_signing_hash().unwrap();
+        assert_eq!(
+            hex::encode(&hash[..]),
+            "12bed817f8ef31bffc526b2639512369643e5dd149c5de48cdd31ba1e07caf23",
+        );
+    }
+
+    #[test]
+    fn test_domain_uses_declared_type() {
+        let mut json = json!({
+            "types": {
+                "EIP712Domain": [
+                    {
+                        "name": "name",
+                        "type": "string"
+                    },
+                    {
+                        "name": "networkId",
+                        "type": "uint256"
+                    }
+                ]
+            },
+            "primaryType": "EIP712Domain",
+            "domain": {
+                "name": "example.metamask.io",
+                "version": "1",
+                "networkId": 1
+            },
+            "message": {}
+        });
+
+        // fields that are not declared are not encoded, like in go-core
+        let typed_data: TypedData = serde_json::from_value(json.clone()).unwrap();
+        let expected = Eip712Domain { version: None, ..typed_data.domain.clone() };
+        assert_eq!(typed_data.domain_separator().unwrap(), expected.separator());
+
+        // declared fields that are not set are not encoded either
+        json["domain"]["networkId"].take();
+        let typed_data: TypedData = serde_json::from_value(json.clone()).unwrap();
+        let expected = Eip712Domain { network_id: None, ..expected };
+        assert_eq!(typed_data.domain_separator().unwrap(), expected.separator());
+
+        // unknown domain fields are rejected
+        json["domain"]["networkID"] = json!(1);
+        assert!(serde_json::from_value::<TypedData>(json).is_err());
+    }
+
     #[test]
     fn test_minimal_message() {
         let json = json!({
@@ -635,7 +736,7 @@ mod tests {
                 "name": "Seaport",
                 "version": "1.1",
                 "networkId": "1",
-                "verifyingContract": "0x000000000000006c3852cbEf3e08E8dF289169EdE581"
+                "verifyingContract": "0x001900000000006c3852cbEf3e08E8dF289169EdE581"
             },
             "message": {
                 "offerer": "0x0000f39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
@@ -657,10 +758,15 @@ mod tests {
 
         let typed_data: TypedData = serde_json::from_value(json).unwrap();
 
+        // The original `verifyingContract`, `0x000000000000006c38…`, has invalid
+        // checksum digits and is rejected when deserialized, so they were set to
+        // `19`. The hash was computed independently with Python's
+        // `hashlib.sha3_256`, which also reproduces the original `d74c6ac1…`
+        // hash for the original address.
         let hash = typed_data.eip712_signing_hash().unwrap();
         assert_eq!(
             hex::encode(&hash[..]),
-            "d74c6ac12d70617592bde8bf77c9cc7475668622799ced9be59fc32300e5e0a8",
+            "99f9a54606379d2f415be3ee51a3eed98aa8b4af5aa6d021fd96166a806a057d",
         );
     }
 
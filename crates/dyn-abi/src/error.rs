@@ -1,4 +1,4 @@
-use alloc::{borrow::Cow, string::String};
+use alloc::{borrow::Cow, string::String, vec::Vec};
 use base_primitives::{Selector, B256};
 use base_ylm_types::Error as YlmTypesError;
 use core::fmt;
@@ -62,6 +62,28 @@ pub enum Error {
         actual: B256,
     },
 
+    /// No item in the ABI has the given selector.
+    UnknownSelector(Selector),
+
+    /// No event in the ABI has the given signature.
+    UnknownEventSignature(B256),
+
+    /// More than one function or error in the ABI has the given selector.
+    AmbiguousSelector {
+        /// The selector.
+        selector: Selector,
+        /// The full signatures of the candidates.
+        candidates: Vec<String>,
+    },
+
+    /// More than one event in the ABI has the given signature.
+    AmbiguousEventSignature {
+        /// The signature.
+        signature: B256,
+        /// The full signatures of the candidates.
+        candidates: Vec<String>,
+    },
+
     /// [`hex`] error.
     Hex(hex::FromHexError),
     /// [`base_ylm_type_parser`] error.
@@ -138,6 +160,18 @@ impl fmt::Display for Error {
             Self::SelectorMismatch { expected, actual } => {
                 write!(f, "selector mismatch: expected {expected}, got {actual}",)
             }
+            Self::UnknownSelector(selector) => write!(f, "unknown selector: {selector}"),
+            Self::UnknownEventSignature(signature) => {
+                write!(f, "unknown event signature: {signature}")
+            }
+            Self::AmbiguousSelector { selector, candidates } => {
+                write!(f, "ambiguous selector {selector}: candidates are {}", candidates.join(", "))
+            }
+            Self::AmbiguousEventSignature { signature, candidates } => write!(
+                f,
+                "ambiguous event signature {signature}: candidates are {}",
+                candidates.join(", ")
+            ),
             Self::Hex(e) => e.fmt(f),
             Self::TypeParser(e) => e.fmt(f),
             Self::YlmTypes(e) => e.fmt(f),
@@ -258,6 +258,41 @@ mod tests {
         assert!(func.abi_decode_output(&bad_response, false).is_err());
     }
 
+    #[test]
+    fn function_pointer() {
+        let json = r#"{
+            "inputs": [
+                {
+                    "internalType": "function (uint256) external returns (bool)",
+                    "name": "callback",
+                    "type": "function"
+                }
+            ],
+            "name": "subscribe",
+            "outputs": [],
+            "stateMutability": "nonpayable",
+            "type": "function"
+        }"#;
+
+        let func: Function = serde_json::from_str(json).unwrap();
+        assert_eq!(func.signature(), "subscribe(function)");
+
+        let callback = base_primitives::Function::from_address_and_selector(
+            cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"),
+            hex!("12345678"),
+        );
+        let input = [DynYlmValue::Function(callback)];
+        let result = func.abi_encode_input(&input).unwrap();
+        assert_eq!(result[..4], func.selector());
+        assert_eq!(
+            result[4..],
+            hex!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc512345678000000000000")
+        );
+
+        let decoded = func.abi_decode_input(&result[4..], true).unwrap();
+        assert_eq!(decoded, input);
+    }
+
     // https://github.com/foundry-rs/foundry/issues/7280
     // Same as `encode_empty_bytes_array_in_tuple` in ylm-types.
     #[test]
@@ -265,7 +300,7 @@ mod tests {
         let func = Function::parse("register(bytes,address,bytes[])").unwrap();
         let input = [
             DynYlmValue::Bytes(bytes!("09736b79736b79736b79026f7300").into()),
-            DynYlmValue::Address(cAddress!("0000B7b54cd129e6D8B24e6AE652a473449B273eE3E4")),
+            DynYlmValue::Address(cAddress!("0043B7b54cd129e6D8B24e6AE652a473449B273eE3E4")),
             DynYlmValue::Array(vec![]),
         ];
         let result = func.abi_encode_input(&input).unwrap();
@@ -274,7 +309,7 @@ mod tests {
             "
             99c0f2b3
             0000000000000000000000000000000000000000000000000000000000000060
-            000000000000000000000000B7b54cd129e6D8B24e6AE652a473449B273eE3E4
+            000000000000000000000043B7b54cd129e6D8B24e6AE652a473449B273eE3E4
             00000000000000000000000000000000000000000000000000000000000000a0
             000000000000000000000000000000000000000000000000000000000000000e
             09736b79736b79736b79026f7300000000000000000000000000000000000000
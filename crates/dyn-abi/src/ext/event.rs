@@ -127,7 +127,7 @@ mod tests {
                 [
                     b256!("0000000000000000000000000000000000000000000000000000000000000000"),
                     b256!("0000000000000000000000000000000000000000000000000000000000000002"),
-                    b256!("0000000000000000000011111111111111111111111111111111111111111111"),
+                    b256!("0000000000000000000011721111111111111111111111111111111111111111"),
                     b256!("00000000000000000aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"),
                     b256!("00000000000000000bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"),
                     b256!("00000000000000000ccccccccccccccccccccccccccccccccccccccccccccccc"),
@@ -135,7 +135,7 @@ mod tests {
                 &hex!(
                     "
                     0000000000000000000000000000000000000000000000000000000000000003
-                    0000000000000000000022222222222222222222222222222222222222222222
+                    0000000000000000000022462222222222222222222222222222222222222222
                 "
                 ),
                 false,
@@ -151,7 +151,7 @@ mod tests {
                     )),
                     256
                 ),
-                DynYlmValue::Address(cAddress!("22222222222222222222222222222222222222222222")),
+                DynYlmValue::Address(cAddress!("22462222222222222222222222222222222222222222")),
             ]
         );
         assert_eq!(
@@ -163,7 +163,7 @@ mod tests {
                     )),
                     256
                 ),
-                DynYlmValue::Address(cAddress!("11111111111111111111111111111111111111111111")),
+                DynYlmValue::Address(cAddress!("11721111111111111111111111111111111111111111")),
                 DynYlmValue::FixedBytes(
                     b256!("00000000000000000aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"),
                     32
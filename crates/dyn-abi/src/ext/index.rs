@@ -0,0 +1,289 @@
+use crate::{
+    DecodedError, DecodedEvent, DynYlmValue, Error as CrateError, ErrorExt, EventExt, JsonAbiExt,
+    Result,
+};
+use alloc::{string::String, vec::Vec};
+use base_json_abi::{Error, Event, Function, SelectorIndex};
+use base_primitives::{LogData, Selector, B256};
+use base_ylm_types::Error as YlmTypesError;
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for base_json_abi::SelectorIndex<'_> {}
+}
+use sealed::Sealed;
+
+/// Provides decoding of calldata, logs and revert data against a whole ABI,
+/// by looking up the item to decode with in a [`SelectorIndex`].
+///
+/// This trait is sealed and cannot be implemented for types outside of this
+/// crate. It is implemented only for [`SelectorIndex`].
+///
+/// # Examples
+///
+/// ```
+/// use base_dyn_abi::{DynYlmValue, SelectorIndexExt};
+/// use base_json_abi::JsonAbi;
+/// use base_primitives::{hex, U256};
+///
+/// let abi = JsonAbi::parse(["function balanceOf(address owner) returns (uint256)"])?;
+/// let index = abi.selector_index();
+///
+/// let calldata = hex!(
+///     "1d7976f3"
+///     "0000000000000000000000000000000000000000000000000000000000000001"
+/// );
+/// let (function, values) = index.decode_calldata(&calldata, true)?;
+/// assert_eq!(function.name, "balanceOf");
+/// assert_eq!(values[0].as_address().unwrap().as_slice()[21], 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait SelectorIndexExt<'a>: Sealed {
+    /// Decodes the given calldata with the function that its selector belongs
+    /// to.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the data is shorter than a
+    /// selector, if no function or more than one function has the selector,
+    /// or if the data does not match the function's input types.
+    fn decode_calldata(
+        &self,
+        data: &[u8],
+        validate: bool,
+    ) -> Result<(&'a Function, Vec<DynYlmValue>)>;
+
+    /// Decodes the given log info with the non-anonymous event that its first
+    /// topic belongs to.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if there are no topics, if no event
+    /// or more than one event has the signature, or if the log does not match
+    /// the event's input types.
+    fn decode_log_parts<I>(
+        &self,
+        topics: I,
+        data: &[u8],
+        validate: bool,
+    ) -> Result<(&'a Event, DecodedEvent)>
+    where
+        I: IntoIterator<Item = B256>;
+
+    /// Decodes the given log object with the non-anonymous event that its
+    /// first topic belongs to.
+    ///
+    /// See [`decode_log_parts`](SelectorIndexExt::decode_log_parts).
+    #[inline]
+    fn decode_log(&self, log: &LogData, validate: bool) -> Result<(&'a Event, DecodedEvent)> {
+        self.decode_log_parts(log.topics().iter().copied(), &log.data, validate)
+    }
+
+    /// Decodes the given revert data with the custom error that its selector
+    /// belongs to.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the data is shorter than a
+    /// selector, if no error or more than one error has the selector, or if
+    /// the data does not match the error's input types.
+    fn decode_revert(&self, data: &[u8]) -> Result<(&'a Error, DecodedError)>;
+}
+
+impl<'a> SelectorIndexExt<'a> for SelectorIndex<'a> {
+    fn decode_calldata(
+        &self,
+        data: &[u8],
+        validate: bool,
+    ) -> Result<(&'a Function, Vec<DynYlmValue>)> {
+        let selector = selector(data)?;
+        let function = self
+            .function_by_selector(selector)
+            .map_err(|candidates| ambiguous(selector, candidates, Function::full_signature))?
+            .ok_or(CrateError::UnknownSelector(selector))?;
+        let values = function.abi_decode_input(&data[4..], validate)?;
+        Ok((function, values))
+    }
+
+    fn decode_log_parts<I>(
+        &self,
+        topics: I,
+        data: &[u8],
+        validate: bool,
+    ) -> Result<(&'a Event, DecodedEvent)>
+    where
+        I: IntoIterator<Item = B256>,
+    {
+        let mut topics = topics.into_iter();
+        let topic0 =
+            topics.next().ok_or(CrateError::TopicLengthMismatch { expected: 1, actual: 0 })?;
+        let event = self
+            .event_by_topic0(topic0)
+            .map_err(|candidates| CrateError::AmbiguousEventSignature {
+                signature: topic0,
+                candidates: candidates.iter().map(|event| event.full_signature()).collect(),
+            })?
+            .ok_or(CrateError::UnknownEventSignature(topic0))?;
+        let decoded =
+            event.decode_log_parts(core::iter::once(topic0).chain(topics), data, validate)?;
+        Ok((event, decoded))
+    }
+
+    fn decode_revert(&self, data: &[u8]) -> Result<(&'a Error, DecodedError)> {
+        let selector = selector(data)?;
+        let error = self
+            .error_by_selector(selector)
+            .map_err(|candidates| ambiguous(selector, candidates, Error::full_signature))?
+            .ok_or(CrateError::UnknownSelector(selector))?;
+        let decoded = error.decode_error(data)?;
+        Ok((error, decoded))
+    }
+}
+
+fn selector(data: &[u8]) -> Result<Selector> {
+    match data.get(..4) {
+        Some(selector) => Ok(Selector::from_slice(selector)),
+        None => Err(YlmTypesError::Overrun.into()),
+    }
+}
+
+fn ambiguous<T>(selector: Selector, candidates: &[&T], signature: fn(&T) -> String) -> CrateError {
+    CrateError::AmbiguousSelector {
+        selector,
+        candidates: candidates.iter().map(|item| signature(item)).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base_json_abi::JsonAbi;
+    use base_primitives::{cAddress, hex, U256};
+
+    fn abi() -> JsonAbi {
+        JsonAbi::parse([
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "error InsufficientBalance(uint256 available, uint256 required)",
+            "event Transfer(address indexed from, address indexed to, uint256 amount)",
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn decode_calldata() {
+        let abi = abi();
+        let index = abi.selector_index();
+        let transfer = &abi.functions["transfer"][0];
+        let to = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+        let values = [DynYlmValue::Address(to), DynYlmValue::Uint(U256::from(42), 256)];
+        let calldata = transfer.abi_encode_input(&values).unwrap();
+
+        let (function, decoded) = index.decode_calldata(&calldata, true).unwrap();
+        assert_eq!(function, transfer);
+        assert_eq!(decoded, values);
+
+        assert_eq!(
+            index.decode_calldata(&hex!("00000000"), true),
+            Err(CrateError::UnknownSelector(Selector::ZERO))
+        );
+        assert_eq!(index.decode_calldata(&calldata[..3], true), Err(YlmTypesError::Overrun.into()));
+    }
+
+    #[test]
+    fn decode_log() {
+        let abi = abi();
+        let index = abi.selector_index();
+        let event = &abi.events["Transfer"][0];
+        let from = B256::left_padding_from(&hex!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"));
+        let to = B256::left_padding_from(&hex!("ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"));
+        let log = LogData::new_unchecked(
+            vec![event.selector(), from, to],
+            U256::from(42).to_be_bytes_vec().into(),
+        );
+
+        let (decoded_event, decoded) = index.decode_log(&log, true).unwrap();
+        assert_eq!(decoded_event, event);
+        assert_eq!(decoded.indexed.len(), 2);
+        assert_eq!(decoded.body, [DynYlmValue::Uint(U256::from(42), 256)]);
+
+        assert_eq!(
+            index.decode_log_parts([from], &[], true),
+            Err(CrateError::UnknownEventSignature(from))
+        );
+        assert_eq!(
+            index.decode_log_parts(None, &[], true),
+            Err(CrateError::TopicLengthMismatch { expected: 1, actual: 0 })
+        );
+    }
+
+    #[test]
+    fn decode_revert() {
+        let abi = abi();
+        let index = abi.selector_index();
+        let error = &abi.errors["InsufficientBalance"][0];
+        let values = [DynYlmValue::Uint(U256::from(1), 256), DynYlmValue::Uint(U256::from(2), 256)];
+        let data = error.abi_encode_input(&values).unwrap();
+
+        let (decoded_error, decoded) = index.decode_revert(&data).unwrap();
+        assert_eq!(decoded_error, error);
+        assert_eq!(decoded.body, values);
+
+        // Function selectors are not error selectors.
+        let transfer = abi.functions["transfer"][0].selector();
+        assert_eq!(
+            index.decode_revert(transfer.as_slice()),
+            Err(CrateError::UnknownSelector(transfer))
+        );
+    }
+
+    #[test]
+    fn ambiguous() {
+        let abi = JsonAbi::parse([
+            "function transfer(address to, uint256 amount)",
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "event Transfer(address indexed from, address indexed to, uint256 amount)",
+            "event Transfer(address indexed from, address indexed to, uint256 indexed id)",
+            "error InsufficientBalance(uint256 available)",
+            "error InsufficientBalance(uint256 required)",
+        ])
+        .unwrap();
+        let index = abi.selector_index();
+
+        let selector = abi.functions["transfer"][0].selector();
+        let err = index.decode_calldata(selector.as_slice(), true).unwrap_err();
+        assert_eq!(
+            err,
+            CrateError::AmbiguousSelector {
+                selector,
+                candidates: vec![
+                    "function transfer(address to, uint256 amount)".into(),
+                    "function transfer(address to, uint256 amount) returns (bool)".into(),
+                ],
+            }
+        );
+
+        let signature = abi.events["Transfer"][0].selector();
+        let err = index.decode_log_parts([signature], &[], true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "ambiguous event signature {signature}: candidates are \
+                 event Transfer(address indexed from, address indexed to, uint256 amount), \
+                 event Transfer(address indexed from, address indexed to, uint256 indexed id)"
+            )
+        );
+
+        // Errors that share a selector usually share their types too.
+        let selector = abi.errors["InsufficientBalance"][0].selector();
+        assert_eq!(
+            index.decode_revert(selector.as_slice()),
+            Err(CrateError::AmbiguousSelector {
+                selector,
+                candidates: vec![
+                    "error InsufficientBalance(uint256 available)".into(),
+                    "error InsufficientBalance(uint256 required)".into(),
+                ],
+            })
+        );
+    }
+}
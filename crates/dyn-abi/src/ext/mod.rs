@@ -6,3 +6,6 @@ pub use event::EventExt;
 
 mod error;
 pub use error::ErrorExt;
+
+mod index;
+pub use index::SelectorIndexExt;
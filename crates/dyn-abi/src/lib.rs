@@ -41,7 +41,7 @@ mod error;
 pub use error::{Error, Result};
 
 mod ext;
-pub use ext::{ErrorExt, EventExt, FunctionExt, JsonAbiExt};
+pub use ext::{ErrorExt, EventExt, FunctionExt, JsonAbiExt, SelectorIndexExt};
 
 mod specifier;
 pub use specifier::Specifier;
@@ -49,7 +49,10 @@ pub use specifier::Specifier;
 #[cfg(feature = "eip712")]
 pub mod eip712;
 #[cfg(feature = "eip712")]
-pub use eip712::{parser as eip712_parser, Eip712Types, PropertyDef, Resolver, TypeDef, TypedData};
+pub use eip712::{
+    parser as eip712_parser, Eip712Types, PropertyDef, Resolver, TypeDef, TypedData,
+    TypedDataSignatureError,
+};
 
 #[doc(no_inline)]
 pub use base_ylm_types::{
@@ -61,6 +61,8 @@ impl Specifier<DynYlmType> for RootType<'_> {
             "bytes" => Ok(DynYlmType::Bytes),
             "uint" => Ok(DynYlmType::Uint(256)),
             "int" => Ok(DynYlmType::Int(256)),
+            "ufixed" => Ok(DynYlmType::Ufixed(128, 18)),
+            "fixed" => Ok(DynYlmType::Fixed(128, 18)),
             name => {
                 if let Some(sz) = name.strip_prefix("bytes") {
                     if let Ok(sz) = sz.parse() {
@@ -86,6 +88,19 @@ impl Specifier<DynYlmType> for RootType<'_> {
                         }
                     }
                     Err(parser::Error::invalid_size(name).into())
+                } else if let Some(sz) = s.strip_prefix("fixed") {
+                    if let Some((bits, decimals)) = sz.split_once('x') {
+                        if let (Ok(bits), Ok(decimals)) = (bits.parse(), decimals.parse()) {
+                            if bits != 0 && bits <= 256 && bits % 8 == 0 && decimals <= 80 {
+                                return if is_uint {
+                                    Ok(DynYlmType::Ufixed(bits, decimals))
+                                } else {
+                                    Ok(DynYlmType::Fixed(bits, decimals))
+                                };
+                            }
+                        }
+                    }
+                    Err(parser::Error::invalid_size(name).into())
                 } else {
                     Err(parser::Error::invalid_type_string(name).into())
                 }
@@ -297,6 +312,13 @@ mod tests {
         assert_eq!(parse("string"), Ok(DynYlmType::String));
         assert_eq!(parse("bytes"), Ok(DynYlmType::Bytes));
         assert_eq!(parse("bytes32"), Ok(DynYlmType::FixedBytes(32)));
+        assert_eq!(parse("ufixed128x18"), Ok(DynYlmType::Ufixed(128, 18)));
+        assert_eq!(parse("fixed8x0"), Ok(DynYlmType::Fixed(8, 0)));
+        assert_eq!(parse("fixed"), Ok(DynYlmType::Fixed(128, 18)));
+        assert_eq!(parse("ufixed"), Ok(DynYlmType::Ufixed(128, 18)));
+        assert!(parse("ufixed128x81").is_err());
+        assert!(parse("fixed7x18").is_err());
+        assert!(parse("fixed128").is_err());
     }
 
     #[test]
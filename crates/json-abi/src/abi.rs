@@ -1,15 +1,15 @@
 use crate::{
     to_ylm::{ToYlmConfig, YlmPrinter},
-    AbiItem, Constructor, Error, Event, Fallback, Function, Receive,
+    AbiItem, Constructor, Error, Event, Fallback, Function, Receive, UnlinkedBytecode,
 };
 use alloc::{collections::btree_map, string::String, vec::Vec};
-use base_primitives::Bytes;
+use base_primitives::{Bytes, IcanAddress};
 use btree_map::BTreeMap;
 use core::{fmt, iter, iter::Flatten};
 use serde::{
     de::{MapAccess, SeqAccess, Visitor},
-    ser::SerializeSeq,
-    Deserialize, Deserializer, Serialize,
+    ser::{SerializeMap, SerializeSeq},
+    Deserialize, Deserializer, Serialize, Serializer,
 };
 
 macro_rules! set_if_none {
@@ -499,18 +499,85 @@ impl<'de> Visitor<'de> for JsonAbiVisitor {
 ///
 /// Can be deserialized from both an ABI array, and a JSON object with the `abi`
 /// field with optionally the bytecode fields.
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize)]
-#[serde(rename_all = "camelCase")]
+///
+/// Bytecode that still contains library placeholders is kept in
+/// `unlinked_bytecode` and `unlinked_deployed_bytecode` until it is fully
+/// [linked](Self::link).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct ContractObject {
     /// The contract ABI.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub abi: Option<JsonAbi>,
     /// The contract bytecode.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bytecode: Option<Bytes>,
     /// The contract deployed bytecode.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deployed_bytecode: Option<Bytes>,
+    /// The contract bytecode, if it references libraries that have not been
+    /// linked yet.
+    pub unlinked_bytecode: Option<UnlinkedBytecode>,
+    /// The contract deployed bytecode, if it references libraries that have not
+    /// been linked yet.
+    pub unlinked_deployed_bytecode: Option<UnlinkedBytecode>,
+}
+
+impl ContractObject {
+    /// Returns `true` if none of the bytecode fields contain library
+    /// placeholders.
+    #[inline]
+    pub const fn is_linked(&self) -> bool {
+        self.unlinked_bytecode.is_none() && self.unlinked_deployed_bytecode.is_none()
+    }
+
+    /// Links the library with the given fully qualified name at `address` in
+    /// both the bytecode and the deployed bytecode.
+    ///
+    /// Unlinked bytecode is moved into `bytecode` and `deployed_bytecode` once
+    /// all of its placeholders have been replaced. See
+    /// [`UnlinkedBytecode::link`] for details.
+    ///
+    /// Returns `true` if any placeholder was replaced.
+    pub fn link(&mut self, library_fqn: &str, address: IcanAddress) -> bool {
+        link(&mut self.bytecode, &mut self.unlinked_bytecode, library_fqn, address)
+            | link(
+                &mut self.deployed_bytecode,
+                &mut self.unlinked_deployed_bytecode,
+                library_fqn,
+                address,
+            )
+    }
+}
+
+fn link(
+    bytes: &mut Option<Bytes>,
+    unlinked: &mut Option<UnlinkedBytecode>,
+    library_fqn: &str,
+    address: IcanAddress,
+) -> bool {
+    let Some(code) = unlinked else { return false };
+    let linked = code.link(library_fqn, address);
+    if code.is_linked() {
+        *bytes = unlinked.take().and_then(|code| code.into_bytes().ok());
+    }
+    linked
+}
+
+impl Serialize for ContractObject {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        if let Some(abi) = &self.abi {
+            map.serialize_entry("abi", abi)?;
+        }
+        if let Some(bytecode) = &self.bytecode {
+            map.serialize_entry("bytecode", bytecode)?;
+        } else if let Some(bytecode) = &self.unlinked_bytecode {
+            map.serialize_entry("bytecode", bytecode)?;
+        }
+        if let Some(bytecode) = &self.deployed_bytecode {
+            map.serialize_entry("deployedBytecode", bytecode)?;
+        } else if let Some(bytecode) = &self.unlinked_deployed_bytecode {
+            map.serialize_entry("deployedBytecode", bytecode)?;
+        }
+        map.end()
+    }
 }
 
 impl<'de> Deserialize<'de> for ContractObject {
@@ -534,11 +601,9 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
 
     #[inline]
     fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
-        JsonAbiVisitor.visit_seq(seq).map(|abi| ContractObject {
-            abi: Some(abi),
-            bytecode: None,
-            deployed_bytecode: None,
-        })
+        JsonAbiVisitor
+            .visit_seq(seq)
+            .map(|abi| ContractObject { abi: Some(abi), ..Default::default() })
     }
 
     fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
@@ -546,25 +611,46 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
         #[serde(untagged)]
         enum Bytecode {
             Bytes(Bytes),
-            Object { object: Bytes },
+            Object {
+                object: Bytes,
+            },
             Unlinked(String),
-            UnlinkedObject { object: String },
+            UnlinkedObject {
+                object: String,
+                #[serde(default, rename = "linkReferences")]
+                link_references: crate::LinkReferences,
+            },
         }
 
         impl Bytecode {
-            fn ensure_bytes<E: serde::de::Error>(self) -> Result<Bytes, E> {
-                match self {
-                    Bytecode::Bytes(bytes) | Bytecode::Object { object: bytes } => Ok(bytes),
-                    Bytecode::Unlinked(unlinked)
-                    | Bytecode::UnlinkedObject { object: unlinked } => {
-                        if let Some((_, unlinked)) = unlinked.split_once("__$") {
-                            if let Some((addr, _)) = unlinked.split_once("$__") {
-                                return Err(E::custom(format!("expected bytecode, found unlinked bytecode with placeholder: {addr}")));
-                            }
-                        }
-                        Err(E::custom("invalid contract bytecode"))
+            /// Stores the bytecode in `bytes` if it is linked, or in `unlinked`
+            /// otherwise.
+            fn set<E: serde::de::Error>(
+                self,
+                name: &'static str,
+                bytes: &mut Option<Bytes>,
+                unlinked: &mut Option<UnlinkedBytecode>,
+            ) -> Result<(), E> {
+                if bytes.is_some() || unlinked.is_some() {
+                    return Err(E::duplicate_field(name));
+                }
+                let (object, link_references) = match self {
+                    Bytecode::Bytes(object) | Bytecode::Object { object } => {
+                        *bytes = Some(object);
+                        return Ok(());
                     }
+                    Bytecode::Unlinked(object) => (object, Default::default()),
+                    Bytecode::UnlinkedObject { object, link_references } => {
+                        (object, link_references)
+                    }
+                };
+                let code = UnlinkedBytecode::new(&object, link_references)
+                    .map_err(|e| E::custom(format_args!("invalid contract bytecode: {e}")))?;
+                match code.into_bytes() {
+                    Ok(code) => *bytes = Some(code),
+                    Err(code) => *unlinked = Some(code),
                 }
+                Ok(())
             }
         }
 
@@ -579,25 +665,39 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
         let mut abi = None;
         let mut bytecode = None;
         let mut deployed_bytecode = None;
+        let mut unlinked_bytecode = None;
+        let mut unlinked_deployed_bytecode = None;
 
         while let Some(key) = map.next_key::<&str>()? {
             match key {
                 "abi" => set_if_none!(@serde abi, map.next_value()?),
                 "evm" => {
                     let evm = map.next_value::<EvmObj>()?;
-                    if let Some(bytes) = evm.bytecode {
-                        set_if_none!(@serde bytecode, bytes.ensure_bytes()?);
+                    if let Some(code) = evm.bytecode {
+                        code.set("bytecode", &mut bytecode, &mut unlinked_bytecode)?;
                     }
-                    if let Some(bytes) = evm.deployed_bytecode {
-                        set_if_none!(@serde deployed_bytecode, bytes.ensure_bytes()?);
+                    if let Some(code) = evm.deployed_bytecode {
+                        code.set(
+                            "deployed_bytecode",
+                            &mut deployed_bytecode,
+                            &mut unlinked_deployed_bytecode,
+                        )?;
                     }
                 }
                 "bytecode" | "bin" => {
-                    set_if_none!(@serde bytecode, map.next_value::<Bytecode>()?.ensure_bytes()?);
+                    map.next_value::<Bytecode>()?.set(
+                        "bytecode",
+                        &mut bytecode,
+                        &mut unlinked_bytecode,
+                    )?;
                 }
                 "deployedBytecode" | "deployedbytecode" | "deployed_bytecode" | "runtimeBin"
                 | "runtimebin" | "runtime " => {
-                    set_if_none!(@serde deployed_bytecode, map.next_value::<Bytecode>()?.ensure_bytes()?);
+                    map.next_value::<Bytecode>()?.set(
+                        "deployed_bytecode",
+                        &mut deployed_bytecode,
+                        &mut unlinked_deployed_bytecode,
+                    )?;
                 }
                 _ => {
                     map.next_value::<serde::de::IgnoredAny>()?;
@@ -605,6 +705,12 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
             }
         }
 
-        Ok(ContractObject { abi, bytecode, deployed_bytecode })
+        Ok(ContractObject {
+            abi,
+            bytecode,
+            deployed_bytecode,
+            unlinked_bytecode,
+            unlinked_deployed_bytecode,
+        })
     }
 }
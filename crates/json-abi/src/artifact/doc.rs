@@ -0,0 +1,95 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// NatSpec developer documentation, as emitted in `devdoc`.
+///
+/// Items are indexed by their signature, e.g. `transfer(address,uint256)`,
+/// except for state variables, which are indexed by name.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DevDoc {
+    /// The documentation kind, always `dev`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// The documentation format version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+    /// The `@author` of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// The `@dev` details of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// The `@title` of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The documented functions and the constructor.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub methods: BTreeMap<String, DocItem>,
+    /// The documented events.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub events: BTreeMap<String, DocItem>,
+    /// The documented errors. An error may be documented once per definition.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub errors: BTreeMap<String, Vec<DocItem>>,
+    /// The documented public state variables.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub state_variables: BTreeMap<String, DocItem>,
+    /// Other entries, such as `@custom:...` tags.
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// NatSpec user documentation, as emitted in `userdoc`.
+///
+/// Items are indexed in the same way as in [`DevDoc`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDoc {
+    /// The documentation kind, always `user`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// The documentation format version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+    /// The `@notice` of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notice: Option<String>,
+    /// The documented functions and the constructor.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub methods: BTreeMap<String, DocItem>,
+    /// The documented events.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub events: BTreeMap<String, DocItem>,
+    /// The documented errors. An error may be documented once per definition.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub errors: BTreeMap<String, Vec<DocItem>>,
+    /// Other entries.
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// The NatSpec documentation of a single item.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocItem {
+    /// The `@notice` text. Only present in user documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notice: Option<String>,
+    /// The `@dev` text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// The `@param` texts, indexed by parameter name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+    /// The `@return` texts, indexed by return variable name, or `_<index>` for
+    /// unnamed return variables.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub returns: BTreeMap<String, String>,
+    /// The `@return` text of a public state variable.
+    #[serde(default, rename = "return", skip_serializing_if = "Option::is_none")]
+    pub return_: Option<String>,
+    /// Other entries, such as `@custom:...` tags.
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
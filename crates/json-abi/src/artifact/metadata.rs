@@ -0,0 +1,131 @@
+use super::{DevDoc, UserDoc};
+use crate::JsonAbi;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use base_primitives::{IcanAddress, B256};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The contract metadata, which describes how to reproduce the compilation.
+///
+/// Its hash is appended to the deployed bytecode. Use
+/// [`ContractArtifact::raw_metadata`](super::ContractArtifact::raw_metadata)
+/// to recompute it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metadata {
+    /// The compiler that produced the contract.
+    pub compiler: MetadataCompiler,
+    /// The source language.
+    pub language: String,
+    /// The compilation output.
+    pub output: MetadataOutput,
+    /// The settings that the contract was compiled with.
+    pub settings: MetadataSettings,
+    /// The source files, indexed by their source unit name.
+    pub sources: BTreeMap<String, MetadataSource>,
+    /// The metadata format version.
+    pub version: u32,
+}
+
+impl Metadata {
+    /// Returns the fully qualified name of the compiled contract:
+    /// `<file>:<name>`.
+    pub fn compilation_target(&self) -> Option<String> {
+        let (file, name) = self.settings.compilation_target.iter().next()?;
+        Some(format!("{file}:{name}"))
+    }
+}
+
+/// The compiler in [`Metadata`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MetadataCompiler {
+    /// The full compiler version, including the commit hash.
+    pub version: String,
+}
+
+/// The compilation output in [`Metadata`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataOutput {
+    /// The contract ABI.
+    pub abi: JsonAbi,
+    /// The developer documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub devdoc: Option<DevDoc>,
+    /// The user documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub userdoc: Option<UserDoc>,
+}
+
+/// The compiler settings in [`Metadata`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataSettings {
+    /// The file and the name of the compiled contract.
+    #[serde(default)]
+    pub compilation_target: BTreeMap<String, String>,
+    /// The targeted VM version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evm_version: Option<String>,
+    /// The linked libraries, indexed by their fully qualified name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub libraries: BTreeMap<String, IcanAddress>,
+    /// The metadata settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<SettingsMetadata>,
+    /// The optimizer settings.
+    #[serde(default)]
+    pub optimizer: Optimizer,
+    /// The import remappings, sorted.
+    #[serde(default)]
+    pub remappings: Vec<String>,
+    /// Whether the IR-based code generator was used.
+    #[serde(default, rename = "viaIR", skip_serializing_if = "Option::is_none")]
+    pub via_ir: Option<bool>,
+}
+
+/// The `metadata` compiler settings.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsMetadata {
+    /// Whether to include the literal source contents in the metadata instead
+    /// of their URLs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_literal_content: Option<bool>,
+    /// The kind of metadata hash appended to the bytecode: `ipfs`, `bzzr1` or
+    /// `none`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytecode_hash: Option<String>,
+    /// Whether to append the CBOR-encoded metadata to the bytecode.
+    #[serde(default, rename = "appendCBOR", skip_serializing_if = "Option::is_none")]
+    pub append_cbor: Option<bool>,
+}
+
+/// The optimizer settings.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Optimizer {
+    /// Whether the optimizer is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The number of times the code is expected to run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runs: Option<u64>,
+    /// The fine-grained optimizer step settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<Value>,
+}
+
+/// A source file in [`Metadata`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MetadataSource {
+    /// The hash of the source file contents.
+    #[serde(alias = "sha3")]
+    pub keccak256: B256,
+    /// The SPDX license identifier of the source file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    /// The URLs that the source file can be fetched from.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub urls: Vec<String>,
+    /// The literal source file contents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
@@ -0,0 +1,331 @@
+//! Ylem compiler artifacts.
+//!
+//! See [`ContractArtifact`] for more details.
+
+use crate::{ContractObject, JsonAbi, Offsets, UnlinkedBytecode};
+use alloc::{borrow::Cow, boxed::Box, collections::BTreeMap, string::String, vec::Vec};
+use base_primitives::{Bytes, IcanAddress, Selector};
+use serde::{
+    de::{self, DeserializeOwned},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use serde_json::value::RawValue;
+
+mod doc;
+pub use doc::{DevDoc, DocItem, UserDoc};
+
+mod metadata;
+pub use metadata::{
+    Metadata, MetadataCompiler, MetadataOutput, MetadataSettings, MetadataSource, Optimizer,
+    SettingsMetadata,
+};
+
+mod storage;
+pub use storage::{StorageLayout, StorageSlot, StorageType};
+
+/// A compiled contract, with everything the compiler can emit for it.
+///
+/// Can be deserialized from both a contract of the standard-JSON output
+/// (`contracts.<file>.<name>`) and a contract of the `--combined-json` output
+/// (`contracts.<file>:<name>`). Values that the compiler emits as JSON strings,
+/// such as `metadata`, are parsed as well.
+///
+/// Serializes in the standard-JSON output format.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContractArtifact {
+    /// The contract ABI.
+    pub abi: Option<JsonAbi>,
+    /// The parsed contract metadata.
+    pub metadata: Option<Metadata>,
+    /// The contract metadata exactly as emitted by the compiler.
+    ///
+    /// Its hash is embedded in the bytecode, so this must be kept verbatim for
+    /// source verification.
+    pub raw_metadata: Option<String>,
+    /// The developer documentation.
+    pub devdoc: Option<DevDoc>,
+    /// The user documentation.
+    pub userdoc: Option<UserDoc>,
+    /// The layout of the contract's state variables in storage.
+    pub storage_layout: Option<StorageLayout>,
+    /// The function signatures mapped to their selectors.
+    pub method_identifiers: BTreeMap<String, Selector>,
+    /// The contract creation bytecode.
+    pub bytecode: Option<Bytecode>,
+    /// The contract deployed bytecode.
+    pub deployed_bytecode: Option<Bytecode>,
+}
+
+impl From<ContractArtifact> for ContractObject {
+    fn from(artifact: ContractArtifact) -> Self {
+        fn split(code: Option<Bytecode>) -> (Option<Bytes>, Option<UnlinkedBytecode>) {
+            match code.map(|code| code.object.into_bytes()) {
+                Some(Ok(bytes)) => (Some(bytes), None),
+                Some(Err(unlinked)) => (None, Some(unlinked)),
+                None => (None, None),
+            }
+        }
+
+        let (bytecode, unlinked_bytecode) = split(artifact.bytecode);
+        let (deployed_bytecode, unlinked_deployed_bytecode) = split(artifact.deployed_bytecode);
+        Self {
+            abi: artifact.abi,
+            bytecode,
+            deployed_bytecode,
+            unlinked_bytecode,
+            unlinked_deployed_bytecode,
+        }
+    }
+}
+
+impl ContractArtifact {
+    /// Links the library with the given fully qualified name at `address` in
+    /// both the bytecode and the deployed bytecode.
+    ///
+    /// See [`UnlinkedBytecode::link`] for details. Returns `true` if any
+    /// placeholder was replaced.
+    pub fn link(&mut self, library_fqn: &str, address: IcanAddress) -> bool {
+        let mut linked = false;
+        for code in [&mut self.bytecode, &mut self.deployed_bytecode].into_iter().flatten() {
+            linked |= code.link(library_fqn, address);
+        }
+        linked
+    }
+}
+
+impl Serialize for ContractArtifact {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Evm<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            bytecode: Option<&'a Bytecode>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            deployed_bytecode: Option<&'a Bytecode>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            method_identifiers: Option<&'a BTreeMap<String, Selector>>,
+        }
+
+        let mut map = serializer.serialize_map(None)?;
+        if let Some(abi) = &self.abi {
+            map.serialize_entry("abi", abi)?;
+        }
+        if let Some(raw) = &self.raw_metadata {
+            map.serialize_entry("metadata", raw)?;
+        } else if let Some(metadata) = &self.metadata {
+            let raw = serde_json::to_string(metadata).map_err(serde::ser::Error::custom)?;
+            map.serialize_entry("metadata", &raw)?;
+        }
+        if let Some(devdoc) = &self.devdoc {
+            map.serialize_entry("devdoc", devdoc)?;
+        }
+        if let Some(userdoc) = &self.userdoc {
+            map.serialize_entry("userdoc", userdoc)?;
+        }
+        if let Some(storage_layout) = &self.storage_layout {
+            map.serialize_entry("storageLayout", storage_layout)?;
+        }
+        let evm = Evm {
+            bytecode: self.bytecode.as_ref(),
+            deployed_bytecode: self.deployed_bytecode.as_ref(),
+            method_identifiers: Some(&self.method_identifiers)
+                .filter(|method_identifiers| !method_identifiers.is_empty()),
+        };
+        if evm.bytecode.is_some()
+            || evm.deployed_bytecode.is_some()
+            || evm.method_identifiers.is_some()
+        {
+            map.serialize_entry("evm", &evm)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ContractArtifact {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// The union of the standard-JSON and `--combined-json` contract
+        /// formats.
+        #[derive(Deserialize)]
+        struct Repr {
+            abi: Option<Box<RawValue>>,
+            metadata: Option<Box<RawValue>>,
+            devdoc: Option<Box<RawValue>>,
+            userdoc: Option<Box<RawValue>>,
+            #[serde(rename = "storageLayout", alias = "storage-layout")]
+            storage_layout: Option<Box<RawValue>>,
+            evm: Option<Evm>,
+            bin: Option<String>,
+            #[serde(rename = "bin-runtime")]
+            bin_runtime: Option<String>,
+            srcmap: Option<String>,
+            #[serde(rename = "srcmap-runtime")]
+            srcmap_runtime: Option<String>,
+            hashes: Option<BTreeMap<String, Selector>>,
+        }
+
+        #[derive(Default, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Evm {
+            bytecode: Option<Bytecode>,
+            deployed_bytecode: Option<Bytecode>,
+            #[serde(default)]
+            method_identifiers: BTreeMap<String, Selector>,
+        }
+
+        fn combined<E: de::Error>(
+            bin: Option<String>,
+            source_map: Option<String>,
+        ) -> Result<Option<Bytecode>, E> {
+            bin.map(|bin| {
+                let object = UnlinkedBytecode::new(&bin, Default::default())
+                    .map_err(|e| E::custom(format_args!("invalid contract bytecode: {e}")))?;
+                Ok(Bytecode { object, source_map, ..Default::default() })
+            })
+            .transpose()
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+        let evm = repr.evm.unwrap_or_default();
+
+        let metadata = unquote(repr.metadata.as_deref())?;
+        // Owned JSON was unquoted from a string, which is kept verbatim.
+        let raw_metadata = match &metadata {
+            Some(Cow::Owned(raw)) => Some(raw.clone()),
+            _ => None,
+        };
+
+        let bytecode = match evm.bytecode {
+            Some(bytecode) => Some(bytecode),
+            None => combined(repr.bin, repr.srcmap)?,
+        };
+        let deployed_bytecode = match evm.deployed_bytecode {
+            Some(bytecode) => Some(bytecode),
+            None => combined(repr.bin_runtime, repr.srcmap_runtime)?,
+        };
+        let method_identifiers = match repr.hashes {
+            Some(hashes) if evm.method_identifiers.is_empty() => hashes,
+            _ => evm.method_identifiers,
+        };
+
+        Ok(Self {
+            abi: parse(unquote(repr.abi.as_deref())?)?,
+            metadata: parse(metadata)?,
+            raw_metadata,
+            devdoc: parse(unquote(repr.devdoc.as_deref())?)?,
+            userdoc: parse(unquote(repr.userdoc.as_deref())?)?,
+            storage_layout: parse(unquote(repr.storage_layout.as_deref())?)?,
+            method_identifiers,
+            bytecode,
+            deployed_bytecode,
+        })
+    }
+}
+
+/// Compiled bytecode with its source map and link information.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bytecode {
+    /// The bytecode and the link references of the libraries it uses.
+    #[serde(flatten)]
+    pub object: UnlinkedBytecode,
+    /// The opcodes, as a space-separated string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opcodes: Option<String>,
+    /// The compressed source map.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_map: Option<String>,
+    /// The AST IDs of immutable variables mapped to the locations in the
+    /// deployed bytecode where their values are inserted.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub immutable_references: BTreeMap<String, Vec<Offsets>>,
+}
+
+impl Bytecode {
+    /// Returns the bytecode as [`Bytes`], or `None` if it still contains
+    /// library placeholders.
+    #[inline]
+    pub fn bytes(&self) -> Option<Bytes> {
+        self.object.to_bytes()
+    }
+
+    /// Links the library with the given fully qualified name at `address`.
+    ///
+    /// See [`UnlinkedBytecode::link`] for details.
+    #[inline]
+    pub fn link(&mut self, library_fqn: &str, address: IcanAddress) -> bool {
+        self.object.link(library_fqn, address)
+    }
+}
+
+/// The output of `ylem --combined-json`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use base_json_abi::CombinedJson;
+///
+/// # let json = "";
+/// let output: CombinedJson = serde_json::from_str(json)?;
+/// let token = output.find("Token").unwrap();
+/// println!("{:?}", token.method_identifiers);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CombinedJson {
+    /// The contracts, indexed by their fully qualified name: `<file>:<name>`.
+    #[serde(default)]
+    pub contracts: BTreeMap<String, ContractArtifact>,
+    /// The source files, indexed by the source IDs used in source maps.
+    #[serde(default)]
+    pub source_list: Vec<String>,
+    /// The compiler version.
+    #[serde(default)]
+    pub version: String,
+}
+
+impl CombinedJson {
+    /// Returns the contract with the given fully qualified or plain name.
+    ///
+    /// Plain names return the first contract with that name, ordered by file.
+    pub fn find(&self, name: &str) -> Option<&ContractArtifact> {
+        self.contracts.get(name).or_else(|| {
+            self.contracts
+                .iter()
+                .find(|(fqn, _)| fqn.rsplit_once(':').map_or(false, |(_, n)| n == name))
+                .map(|(_, contract)| contract)
+        })
+    }
+}
+
+/// Returns the JSON of a value that is either emitted as-is, or as a string
+/// that contains it. `null` and empty strings are treated as missing.
+fn unquote<E: de::Error>(raw: Option<&RawValue>) -> Result<Option<Cow<'_, str>>, E> {
+    let json = match raw {
+        Some(raw) if raw.get() != "null" => raw.get(),
+        _ => return Ok(None),
+    };
+    if !json.starts_with('"') {
+        return Ok(Some(Cow::Borrowed(json)));
+    }
+    let s: String = serde_json::from_str(json).map_err(E::custom)?;
+    Ok(Some(s).filter(|s| !s.is_empty()).map(Cow::Owned))
+}
+
+/// Parses the JSON returned by [`unquote`].
+///
+/// This goes through a string rather than [`serde_json::Value`], since ABI
+/// types can only be deserialized from borrowed strings.
+fn parse<T: DeserializeOwned, E: de::Error>(json: Option<Cow<'_, str>>) -> Result<Option<T>, E> {
+    json.map(|json| serde_json::from_str(&json)).transpose().map_err(E::custom)
+}
+
+/// Deserializes `null` as the default value.
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Option::unwrap_or_default)
+}
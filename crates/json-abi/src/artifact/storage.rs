@@ -0,0 +1,66 @@
+use super::null_as_default;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+/// The layout of a contract's state variables in storage, as emitted in
+/// `storageLayout`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StorageLayout {
+    /// The state variables, in declaration order.
+    #[serde(default)]
+    pub storage: Vec<StorageSlot>,
+    /// The types of the state variables, indexed by type identifier.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub types: BTreeMap<String, StorageType>,
+}
+
+impl StorageLayout {
+    /// Returns the state variable with the given name.
+    pub fn get(&self, label: &str) -> Option<&StorageSlot> {
+        self.storage.iter().find(|slot| slot.label == label)
+    }
+}
+
+/// The storage location of a state variable or struct member.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageSlot {
+    /// The AST ID of the variable declaration.
+    pub ast_id: u64,
+    /// The fully qualified name of the contract that declares the variable.
+    pub contract: String,
+    /// The variable name.
+    pub label: String,
+    /// The byte offset of the variable inside of its slot.
+    pub offset: u64,
+    /// The slot, as a decimal string.
+    pub slot: String,
+    /// The type identifier of the variable; a key of [`StorageLayout::types`].
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+/// A type in a [`StorageLayout`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageType {
+    /// How the value is encoded: `inplace`, `mapping`, `dynamic_array` or
+    /// `bytes`.
+    pub encoding: String,
+    /// The canonical type name.
+    pub label: String,
+    /// The number of bytes used by the value, as a decimal string.
+    pub number_of_bytes: String,
+    /// The key type of a mapping.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// The value type of a mapping.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// The element type of an array.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    /// The members of a struct.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<StorageSlot>>,
+}
@@ -0,0 +1,504 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use base_primitives::{hex, sha3, Bytes, IcanAddress};
+use core::{fmt, iter};
+use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+/// The length of an address in bytes.
+const ADDRESS_LEN: usize = 22;
+
+/// The length of a library placeholder in hex characters.
+const PLACEHOLDER_LEN: usize = ADDRESS_LEN * 2;
+
+/// The number of hash characters in a hashed library placeholder.
+const PLACEHOLDER_HASH_LEN: usize = PLACEHOLDER_LEN - 6;
+
+/// Library link references, as emitted by the compiler: source file name to
+/// library name to the list of offsets at which the library address must be
+/// inserted.
+pub type LinkReferences = BTreeMap<String, BTreeMap<String, Vec<Offsets>>>;
+
+/// The location of a library address inside of bytecode, in bytes.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct Offsets {
+    /// The byte offset of the placeholder.
+    pub start: u32,
+    /// The length of the placeholder in bytes. Always equal to the address
+    /// length.
+    pub length: u32,
+}
+
+/// An error that occurred while parsing [`UnlinkedBytecode`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LinkError {
+    /// The bytecode has an odd number of hex characters.
+    OddLength,
+    /// The bytecode contains a character that is neither hex nor part of a
+    /// library placeholder.
+    InvalidCharacter {
+        /// The byte offset of the invalid character.
+        offset: usize,
+    },
+    /// The bytecode contains a malformed library placeholder.
+    InvalidPlaceholder {
+        /// The byte offset of the placeholder.
+        offset: usize,
+    },
+    /// A link reference does not point at a library placeholder.
+    InvalidLinkReference {
+        /// The fully qualified name of the library.
+        library: String,
+        /// The referenced location.
+        offsets: Offsets,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LinkError {}
+
+impl fmt::Display for LinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddLength => f.write_str("odd number of hex characters in bytecode"),
+            Self::InvalidCharacter { offset } => {
+                write!(f, "invalid character in bytecode at byte {offset}")
+            }
+            Self::InvalidPlaceholder { offset } => {
+                write!(f, "invalid library placeholder in bytecode at byte {offset}")
+            }
+            Self::InvalidLinkReference { library, offsets: Offsets { start, length } } => write!(
+                f,
+                "link reference for {library} at byte {start} with length {length} \
+                 does not point at a library placeholder"
+            ),
+        }
+    }
+}
+
+/// Bytecode that may still contain library placeholders.
+///
+/// Ylem replaces the address of every external library with a placeholder
+/// that spans the 44 hex characters of a 22-byte address. Two formats exist:
+/// - hashed: `__$` followed by the first 38 hex characters of
+///   `sha3(library_fqn)`, then `$__`;
+/// - legacy: `__` followed by the library name, padded or truncated with `_`
+///   to 44 characters.
+///
+/// Placeholders are replaced with [`link`](Self::link). Once none remain, the
+/// bytecode can be converted to [`Bytes`] with [`into_bytes`](Self::into_bytes).
+///
+/// # Examples
+///
+/// ```
+/// use base_json_abi::UnlinkedBytecode;
+/// use base_primitives::{bytes, IcanAddress};
+///
+/// let placeholder = UnlinkedBytecode::placeholder("src/Math.ylm:Math");
+/// let mut code: UnlinkedBytecode = format!("75{placeholder}3014").parse()?;
+/// assert!(!code.is_linked());
+///
+/// assert!(code.link("src/Math.ylm:Math", IcanAddress::repeat_byte(0x11)));
+/// assert_eq!(
+///     code.into_bytes().unwrap(),
+///     bytes!("75111111111111111111111111111111111111111111113014")
+/// );
+/// # Ok::<(), base_json_abi::LinkError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnlinkedBytecode {
+    /// The hex-encoded bytecode without the `0x` prefix. Only contains hex
+    /// characters and valid placeholders.
+    object: String,
+    /// The remaining link references.
+    link_references: LinkReferences,
+}
+
+impl fmt::Display for UnlinkedBytecode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("0x")?;
+        f.write_str(&self.object)
+    }
+}
+
+impl core::str::FromStr for UnlinkedBytecode {
+    type Err = LinkError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s, LinkReferences::new())
+    }
+}
+
+impl From<Bytes> for UnlinkedBytecode {
+    #[inline]
+    fn from(bytes: Bytes) -> Self {
+        Self { object: hex::encode(bytes), link_references: LinkReferences::new() }
+    }
+}
+
+impl Serialize for UnlinkedBytecode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len = 1 + !self.link_references.is_empty() as usize;
+        let mut s = serializer.serialize_struct("UnlinkedBytecode", len)?;
+        s.serialize_field("object", &self.to_string())?;
+        if !self.link_references.is_empty() {
+            s.serialize_field("linkReferences", &self.link_references)?;
+        }
+        s.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for UnlinkedBytecode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Str(String),
+            Object {
+                object: String,
+                #[serde(default, rename = "linkReferences")]
+                link_references: LinkReferences,
+            },
+        }
+
+        let (object, link_references) = match Repr::deserialize(deserializer)? {
+            Repr::Str(object) => (object, LinkReferences::new()),
+            Repr::Object { object, link_references } => (object, link_references),
+        };
+        Self::new(&object, link_references).map_err(serde::de::Error::custom)
+    }
+}
+
+impl UnlinkedBytecode {
+    /// Parses hex-encoded bytecode, with or without the `0x` prefix, together
+    /// with the link references reported by the compiler.
+    ///
+    /// Every link reference must point at a placeholder in the bytecode.
+    pub fn new(object: &str, link_references: LinkReferences) -> Result<Self, LinkError> {
+        let object = object.strip_prefix("0x").unwrap_or(object);
+        let placeholders = scan(object)?;
+        for (file, library, offsets) in iter_references(&link_references) {
+            let valid = offsets.length as usize == ADDRESS_LEN
+                && placeholders.contains(&(offsets.start as usize));
+            if !valid {
+                let library = format!("{file}:{library}");
+                return Err(LinkError::InvalidLinkReference { library, offsets: *offsets });
+            }
+        }
+        Ok(Self { object: object.to_string(), link_references })
+    }
+
+    /// Returns the hashed placeholder of the library with the given fully
+    /// qualified name, e.g. `src/Math.ylm:Math`.
+    pub fn placeholder(library_fqn: &str) -> String {
+        let hash = hex::encode(sha3(library_fqn));
+        format!("__${}$__", &hash[..PLACEHOLDER_HASH_LEN])
+    }
+
+    /// Returns the legacy placeholder of the library with the given name.
+    ///
+    /// The name is truncated to fit the placeholder. Names with non-ASCII
+    /// characters never match a placeholder in valid bytecode.
+    pub fn legacy_placeholder(library_name: &str) -> String {
+        // Truncate on bytes, at a character boundary, so the placeholder is
+        // never longer than `PLACEHOLDER_LEN` bytes.
+        let mut end = library_name.len().min(PLACEHOLDER_LEN - 4);
+        while !library_name.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut placeholder = String::with_capacity(PLACEHOLDER_LEN);
+        placeholder.push_str("__");
+        placeholder.push_str(&library_name[..end]);
+        placeholder.extend(iter::repeat('_').take(PLACEHOLDER_LEN - placeholder.len()));
+        placeholder
+    }
+
+    /// Returns the hex-encoded bytecode, without the `0x` prefix.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.object
+    }
+
+    /// Returns the link references that have not been linked yet.
+    #[inline]
+    pub const fn link_references(&self) -> &LinkReferences {
+        &self.link_references
+    }
+
+    /// Returns `true` if the bytecode does not contain any placeholders.
+    #[inline]
+    pub fn is_linked(&self) -> bool {
+        !self.object.contains('_')
+    }
+
+    /// Returns an iterator over the remaining placeholders, in order of
+    /// appearance.
+    pub fn placeholders(&self) -> impl Iterator<Item = &str> + '_ {
+        let mut rest = self.object.as_str();
+        iter::from_fn(move || {
+            let start = rest.find('_')?;
+            let placeholder = &rest[start..start + PLACEHOLDER_LEN];
+            rest = &rest[start + PLACEHOLDER_LEN..];
+            Some(placeholder)
+        })
+    }
+
+    /// Replaces every placeholder of the given library with `address`.
+    ///
+    /// `library_fqn` is the fully qualified name of the library, e.g.
+    /// `src/Math.ylm:Math`, and matches link references, hashed placeholders,
+    /// and legacy placeholders of either the fully qualified or the plain
+    /// library name. A plain name only matches link references and legacy
+    /// placeholders.
+    ///
+    /// Returns `true` if any placeholder was replaced.
+    pub fn link(&mut self, library_fqn: &str, address: IcanAddress) -> bool {
+        let mut starts = Vec::new();
+        self.link_references.retain(|file, libraries| {
+            libraries.retain(|library, offsets| {
+                let matches = match library_fqn.split_once(':') {
+                    Some(_) => {
+                        library_fqn.strip_prefix(file.as_str()).and_then(|s| s.strip_prefix(':'))
+                            == Some(library)
+                    }
+                    None => library == library_fqn,
+                };
+                if matches {
+                    starts.extend(offsets.iter().map(|o| o.start as usize * 2));
+                }
+                !matches
+            });
+            !libraries.is_empty()
+        });
+
+        let name = library_fqn.rsplit_once(':').map_or(library_fqn, |(_, name)| name);
+        let candidates = [
+            Self::placeholder(library_fqn),
+            Self::legacy_placeholder(library_fqn),
+            Self::legacy_placeholder(name),
+        ];
+        let object = self.object.as_str();
+        starts.extend(
+            self.placeholders()
+                .filter(|p| candidates.iter().any(|c| c == p))
+                .map(|p| p.as_ptr() as usize - object.as_ptr() as usize),
+        );
+
+        let address = hex::encode(address);
+        for &start in &starts {
+            self.object.replace_range(start..start + PLACEHOLDER_LEN, &address);
+        }
+        !starts.is_empty()
+    }
+
+    /// Returns the bytecode as [`Bytes`], or `None` if it still contains
+    /// placeholders.
+    pub fn to_bytes(&self) -> Option<Bytes> {
+        if self.is_linked() {
+            hex::decode(&self.object).ok().map(Into::into)
+        } else {
+            None
+        }
+    }
+
+    /// Converts the bytecode into [`Bytes`], returning `self` back if it still
+    /// contains placeholders.
+    pub fn into_bytes(self) -> Result<Bytes, Self> {
+        self.to_bytes().ok_or(self)
+    }
+}
+
+/// Validates the hex-encoded bytecode and returns the byte offsets of its
+/// placeholders.
+fn scan(object: &str) -> Result<Vec<usize>, LinkError> {
+    if object.len() % 2 != 0 {
+        return Err(LinkError::OddLength);
+    }
+
+    let bytes = object.as_bytes();
+    let mut placeholders = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b == b'_' {
+            let valid =
+                i % 2 == 0 && bytes.get(i..i + PLACEHOLDER_LEN).map_or(false, is_placeholder);
+            if !valid {
+                return Err(LinkError::InvalidPlaceholder { offset: i / 2 });
+            }
+            placeholders.push(i / 2);
+            i += PLACEHOLDER_LEN;
+        } else if b.is_ascii_hexdigit() {
+            i += 1;
+        } else {
+            return Err(LinkError::InvalidCharacter { offset: i / 2 });
+        }
+    }
+    Ok(placeholders)
+}
+
+/// Returns `true` if `s` is a hashed or legacy placeholder.
+fn is_placeholder(s: &[u8]) -> bool {
+    let inner = match s.strip_prefix(b"__").and_then(|s| s.strip_suffix(b"__")) {
+        Some(inner) => inner,
+        None => return false,
+    };
+    match inner.strip_prefix(b"$").and_then(|s| s.strip_suffix(b"$")) {
+        Some(hash) => hash.iter().all(u8::is_ascii_hexdigit),
+        None => inner.iter().all(|&b| b.is_ascii_graphic() && b != b'$'),
+    }
+}
+
+fn iter_references(
+    link_references: &LinkReferences,
+) -> impl Iterator<Item = (&String, &String, &Offsets)> {
+    link_references.iter().flat_map(|(file, libraries)| {
+        libraries.iter().flat_map(move |(library, offsets)| {
+            offsets.iter().map(move |offsets| (file, library, offsets))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FQN: &str = "src/Math.ylm:Math";
+
+    fn references(start: u32) -> LinkReferences {
+        let offsets = vec![Offsets { start, length: ADDRESS_LEN as u32 }];
+        BTreeMap::from([("src/Math.ylm".into(), BTreeMap::from([("Math".into(), offsets)]))])
+    }
+
+    #[test]
+    fn placeholders() {
+        let hashed = UnlinkedBytecode::placeholder(FQN);
+        assert_eq!(hashed.len(), PLACEHOLDER_LEN);
+        assert!(hashed.starts_with("__$") && hashed.ends_with("$__"));
+        assert_eq!(&hashed[3..41], &hex::encode(sha3(FQN))[..38]);
+
+        assert_eq!(
+            UnlinkedBytecode::legacy_placeholder("Math"),
+            "__Math______________________________________"
+        );
+        let long = UnlinkedBytecode::legacy_placeholder(&"A".repeat(50));
+        assert_eq!(long, format!("__{}__", "A".repeat(40)));
+
+        // Truncated on bytes, not characters.
+        let fqn = "contracts/ライブラリ/数学/Math.ylm:Math";
+        let non_ascii = UnlinkedBytecode::legacy_placeholder(fqn);
+        assert_eq!(non_ascii.len(), PLACEHOLDER_LEN);
+        assert_eq!(non_ascii, "__contracts/ライブラリ/数学/Math.yl__");
+        let cut = UnlinkedBytecode::legacy_placeholder(&format!("src/{}", "数".repeat(20)));
+        assert_eq!(cut.len(), PLACEHOLDER_LEN);
+        assert_eq!(cut, format!("__src/{}__", "数".repeat(12)));
+        // The 40th byte is inside a character.
+        let cut = UnlinkedBytecode::legacy_placeholder(&format!("src/a{}", "数".repeat(20)));
+        assert_eq!(cut.len(), PLACEHOLDER_LEN);
+        assert_eq!(cut, format!("__src/a{}____", "数".repeat(11)));
+        let mut code: UnlinkedBytecode =
+            format!("0x60{}", "00".repeat(ADDRESS_LEN)).parse().unwrap();
+        assert!(!code.link(fqn, IcanAddress::repeat_byte(0x11)));
+    }
+
+    #[test]
+    fn link_hashed() {
+        let p = UnlinkedBytecode::placeholder(FQN);
+        let mut code: UnlinkedBytecode = format!("0x60{p}61{p}").parse().unwrap();
+        assert_eq!(code.placeholders().collect::<Vec<_>>(), [p.as_str(), p.as_str()]);
+        assert_eq!(code.to_bytes(), None);
+
+        assert!(!code.link("src/Other.ylm:Math", IcanAddress::repeat_byte(0x22)));
+        assert!(!code.link("Math", IcanAddress::repeat_byte(0x22)));
+        assert!(code.link(FQN, IcanAddress::repeat_byte(0x11)));
+        assert!(code.is_linked());
+        assert_eq!(code.placeholders().count(), 0);
+
+        let addr = "11".repeat(ADDRESS_LEN);
+        let expected = hex::decode(format!("60{addr}61{addr}")).unwrap();
+        assert_eq!(code.into_bytes().unwrap(), expected);
+    }
+
+    #[test]
+    fn link_legacy() {
+        let fqn = UnlinkedBytecode::legacy_placeholder(FQN);
+        let name = UnlinkedBytecode::legacy_placeholder("Math");
+        let other = UnlinkedBytecode::legacy_placeholder("Strings");
+        let mut code =
+            UnlinkedBytecode::new(&format!("{fqn}00{name}00{other}"), Default::default()).unwrap();
+
+        assert!(code.link(FQN, IcanAddress::repeat_byte(0xaa)));
+        assert!(!code.is_linked());
+        assert_eq!(code.placeholders().collect::<Vec<_>>(), [other.as_str()]);
+        let code = code.into_bytes().unwrap_err();
+
+        let mut code2 = code.clone();
+        assert!(code2.link("Strings", IcanAddress::repeat_byte(0xbb)));
+        let addr = |b: &str| b.repeat(ADDRESS_LEN);
+        assert_eq!(code2.to_string(), format!("0x{}00{}00{}", addr("aa"), addr("aa"), addr("bb")));
+    }
+
+    #[test]
+    fn link_references() {
+        let p = UnlinkedBytecode::placeholder(FQN);
+        let mut code = UnlinkedBytecode::new(&format!("6000{p}"), references(2)).unwrap();
+        assert_eq!(code.link_references().len(), 1);
+
+        // Plain names only match link references.
+        assert!(code.link("Math", IcanAddress::repeat_byte(0x11)));
+        assert!(code.link_references().is_empty());
+        assert!(code.is_linked());
+        assert_eq!(code.as_str(), format!("6000{}", "11".repeat(ADDRESS_LEN)));
+    }
+
+    #[test]
+    fn invalid() {
+        let p = UnlinkedBytecode::placeholder(FQN);
+        let err = |s: &str| s.parse::<UnlinkedBytecode>().unwrap_err();
+
+        assert_eq!(err("600"), LinkError::OddLength);
+        assert_eq!(err("60zz"), LinkError::InvalidCharacter { offset: 1 });
+        assert_eq!(err(&format!("6{p}0")), LinkError::InvalidPlaceholder { offset: 0 });
+        // 20-byte placeholders.
+        assert_eq!(
+            err(&format!("60__${}$__0000", "ab".repeat(17))),
+            LinkError::InvalidPlaceholder { offset: 1 }
+        );
+        assert_eq!(
+            err(&format!("__${}$__", "g".repeat(38))),
+            LinkError::InvalidPlaceholder { offset: 0 }
+        );
+
+        let e = UnlinkedBytecode::new(&format!("60{p}"), references(0)).unwrap_err();
+        assert_eq!(
+            e,
+            LinkError::InvalidLinkReference {
+                library: FQN.into(),
+                offsets: Offsets { start: 0, length: ADDRESS_LEN as u32 }
+            }
+        );
+    }
+
+    #[test]
+    fn serde() {
+        let p = UnlinkedBytecode::placeholder(FQN);
+        let code = UnlinkedBytecode::new(&format!("6000{p}"), references(2)).unwrap();
+        let json = serde_json::to_string(&code).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"object":"0x6000{p}","linkReferences":{{"src/Math.ylm":{{"Math":[{{"start":2,"length":22}}]}}}}}}"#
+            )
+        );
+        assert_eq!(serde_json::from_str::<UnlinkedBytecode>(&json).unwrap(), code);
+
+        let s: UnlinkedBytecode = serde_json::from_str(&format!("\"6000{p}\"")).unwrap();
+        assert_eq!(s.as_str(), code.as_str());
+        assert!(s.link_references().is_empty());
+    }
+}
@@ -0,0 +1,802 @@
+use crate::{
+    utils::{full_signature_raw, signature_raw},
+    Constructor, Error, Event, Fallback, Function, JsonAbi, Receive, StateMutability,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+use base_primitives::Bytes;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// The difference between two versions of a [`JsonAbi`], as returned by
+/// [`JsonAbi::diff`].
+///
+/// Items are matched by their signature, the preimage of their selector, so a
+/// change to an item's input types is reported as a removal and an addition.
+///
+/// The [`Display`](fmt::Display) implementation prints a human-readable
+/// report, and the serde implementations can be used to store the diff, for
+/// example in CI.
+///
+/// # Examples
+///
+/// ```
+/// use base_json_abi::JsonAbi;
+///
+/// let old = JsonAbi::parse([
+///     "function balanceOf(address owner) returns (uint256)",
+///     "function burn(uint256 amount)",
+/// ])?;
+/// let new = JsonAbi::parse([
+///     "function balanceOf(address owner) returns (uint128)",
+///     "function mint(address to, uint256 amount)",
+/// ])?;
+///
+/// let diff = old.diff(&new);
+/// assert!(diff.breaking);
+/// assert_eq!(
+///     diff.to_string(),
+///     "\
+/// functions:
+///   + function mint(address to, uint256 amount)
+///   - function burn(uint256 amount) (breaking)
+///   ~ balanceOf(address): output types changed (breaking)
+///       - function balanceOf(address owner) returns (uint256)
+///       + function balanceOf(address owner) returns (uint128)
+/// "
+/// );
+/// # Ok::<(), base_json_abi::parser::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AbiDiff {
+    /// Whether any of the changes can break existing clients.
+    pub breaking: bool,
+    /// The changes to the functions.
+    pub functions: ItemsDiff,
+    /// The changes to the events.
+    pub events: ItemsDiff,
+    /// The changes to the errors.
+    pub errors: ItemsDiff,
+    /// The changes to the constructor, fallback and receive functions.
+    /// Removing one of them is breaking, since clients that deploy the
+    /// contract or send it plain value transfers rely on them.
+    pub special_functions: ItemsDiff,
+    /// Functions or errors that share a selector but have different
+    /// signatures, where at least one of them was added or removed. Removed
+    /// items are included, since calls to them would silently reach the new
+    /// item.
+    pub collisions: Vec<Collision>,
+    /// Items in the new ABI that share a signature, so that decoding by
+    /// selector cannot tell them apart.
+    pub ambiguities: Vec<Collision>,
+}
+
+impl fmt::Display for AbiDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("no changes\n");
+        }
+        self.functions.fmt_section(f, "functions")?;
+        self.events.fmt_section(f, "events")?;
+        self.errors.fmt_section(f, "errors")?;
+        self.special_functions.fmt_section(f, "special functions")?;
+        for (collisions, title) in
+            [(&self.collisions, "selector collisions"), (&self.ambiguities, "ambiguous overloads")]
+        {
+            if !collisions.is_empty() {
+                writeln!(f, "{title}:")?;
+                for collision in collisions {
+                    writeln!(f, "  {collision}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl AbiDiff {
+    /// Returns `true` if there are no changes, collisions or ambiguities.
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+            && self.events.is_empty()
+            && self.errors.is_empty()
+            && self.special_functions.is_empty()
+            && self.collisions.is_empty()
+            && self.ambiguities.is_empty()
+    }
+}
+
+/// The changes to one kind of item in an [`AbiDiff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ItemsDiff {
+    /// The full signatures of the items that are only in the new ABI.
+    pub added: Vec<String>,
+    /// The full signatures of the items that are only in the old ABI.
+    pub removed: Vec<String>,
+    /// Whether removing these items is a breaking change.
+    pub removal_is_breaking: bool,
+    /// The items that are in both ABIs but differ.
+    pub changed: Vec<Change>,
+}
+
+impl ItemsDiff {
+    /// Returns `true` if no items were added, removed or changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Returns `true` if any of the changes can break existing clients.
+    pub fn is_breaking(&self) -> bool {
+        (self.removal_is_breaking && !self.removed.is_empty())
+            || self.changed.iter().any(|change| change.breaking)
+    }
+
+    fn fmt_section(&self, f: &mut fmt::Formatter<'_>, title: &str) -> fmt::Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "{title}:")?;
+        for added in &self.added {
+            writeln!(f, "  + {added}")?;
+        }
+        let breaking = if self.removal_is_breaking { " (breaking)" } else { "" };
+        for removed in &self.removed {
+            writeln!(f, "  - {removed}{breaking}")?;
+        }
+        for change in &self.changed {
+            write!(f, "{change}")?;
+        }
+        Ok(())
+    }
+
+    fn extend(&mut self, other: Self) {
+        self.added.extend(other.added);
+        self.removed.extend(other.removed);
+        self.changed.extend(other.changed);
+    }
+}
+
+/// An item that is in both ABIs of an [`AbiDiff`] but differs.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Change {
+    /// The signature of the item.
+    pub signature: String,
+    /// The full signature of the item in the old ABI.
+    pub old: String,
+    /// The full signature of the item in the new ABI.
+    pub new: String,
+    /// What changed.
+    pub kinds: Vec<ChangeKind>,
+    /// Whether any of the changes can break existing clients.
+    pub breaking: bool,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "  ~ {}: ", self.signature)?;
+        for (i, kind) in self.kinds.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            kind.fmt(f)?;
+        }
+        if self.breaking {
+            f.write_str(" (breaking)")?;
+        }
+        writeln!(f, "\n      - {}\n      + {}", self.old, self.new)
+    }
+}
+
+impl Change {
+    fn new(signature: String, old: String, new: String, kinds: Vec<ChangeKind>) -> Self {
+        let breaking = kinds.iter().any(ChangeKind::is_breaking);
+        Self { signature, old, new, kinds, breaking }
+    }
+}
+
+/// A kind of [`Change`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ChangeKind {
+    /// The output types of a function changed.
+    Outputs,
+    /// The state mutability of a function changed.
+    StateMutability {
+        /// The old state mutability.
+        old: StateMutability,
+        /// The new state mutability.
+        new: StateMutability,
+    },
+    /// Which parameters of an event are indexed changed.
+    Indexed,
+    /// Whether an event is anonymous changed.
+    Anonymous,
+    /// The parameter names or internal types changed. The encoding is the same.
+    Params,
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Outputs => f.write_str("output types changed"),
+            Self::StateMutability { old, new } => write!(
+                f,
+                "state mutability changed from {} to {}",
+                old.as_str().unwrap_or("nonpayable"),
+                new.as_str().unwrap_or("nonpayable")
+            ),
+            Self::Indexed => f.write_str("indexed parameters changed"),
+            Self::Anonymous => f.write_str("anonymous flag changed"),
+            Self::Params => f.write_str("parameter names or internal types changed"),
+        }
+    }
+}
+
+impl ChangeKind {
+    /// Returns `true` if this change can break existing clients.
+    ///
+    /// Only [`Params`](Self::Params) changes, and state mutability changes
+    /// that make a function less strict, are not breaking.
+    pub fn is_breaking(&self) -> bool {
+        match *self {
+            Self::StateMutability { old, new } => new < old,
+            Self::Params => false,
+            Self::Outputs | Self::Indexed | Self::Anonymous => true,
+        }
+    }
+}
+
+/// Items that share a selector, in an [`AbiDiff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Collision {
+    /// The shared selector, or topic 0 for events.
+    pub selector: Bytes,
+    /// The signatures of the items, or their full signatures for ambiguities.
+    pub signatures: Vec<String>,
+}
+
+impl fmt::Display for Collision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.selector)?;
+        for (i, signature) in self.signatures.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(signature)?;
+        }
+        Ok(())
+    }
+}
+
+impl JsonAbi {
+    /// Compares this ABI with a newer version of it.
+    ///
+    /// Ambiguities are collected from the new ABI as given, since
+    /// [deduplicating](Self::dedup) drops overloads that only differ in their
+    /// outputs. Both ABIs are then deduplicated before they are compared. See
+    /// [`AbiDiff`] for more details.
+    pub fn diff(&self, new: &Self) -> AbiDiff {
+        let mut ambiguities = find_ambiguities(new.functions());
+        ambiguities.extend(find_ambiguities(new.events()));
+        ambiguities.extend(find_ambiguities(new.errors()));
+
+        let mut old = self.clone();
+        old.dedup();
+        let mut new = new.clone();
+        new.dedup();
+
+        let functions = diff_items(old.functions(), new.functions(), true);
+        let events = diff_items(old.events(), new.events(), true);
+        let errors = diff_items(old.errors(), new.errors(), false);
+        let mut special_functions = diff_items(&old.constructor, &new.constructor, true);
+        special_functions.extend(diff_items(&old.fallback, &new.fallback, true));
+        special_functions.extend(diff_items(&old.receive, &new.receive, true));
+
+        let mut collisions = find_collisions(&old.functions, &new.functions);
+        collisions.extend(find_collisions(&old.errors, &new.errors));
+
+        let breaking = functions.is_breaking()
+            || events.is_breaking()
+            || errors.is_breaking()
+            || special_functions.is_breaking()
+            || !collisions.is_empty();
+        AbiDiff { breaking, functions, events, errors, special_functions, collisions, ambiguities }
+    }
+}
+
+/// An item that can be diffed.
+trait Item: PartialEq {
+    fn signature(&self) -> String;
+    fn full_signature(&self) -> String;
+    /// Returns the changes between two items with the same signature.
+    fn changes(&self, new: &Self) -> Vec<ChangeKind>;
+}
+
+/// An item that can be called or decoded by its selector.
+trait SelectorItem: Item {
+    fn selector(&self) -> Bytes;
+}
+
+impl Item for Function {
+    fn signature(&self) -> String {
+        self.signature()
+    }
+
+    fn full_signature(&self) -> String {
+        self.full_signature()
+    }
+
+    fn changes(&self, new: &Self) -> Vec<ChangeKind> {
+        let mut kinds = Vec::new();
+        let outputs = self.signature_with_outputs() != new.signature_with_outputs();
+        if outputs {
+            kinds.push(ChangeKind::Outputs);
+        }
+        if self.state_mutability != new.state_mutability {
+            kinds.push(ChangeKind::StateMutability {
+                old: self.state_mutability,
+                new: new.state_mutability,
+            });
+        }
+        if self.inputs != new.inputs || (!outputs && self.outputs != new.outputs) {
+            kinds.push(ChangeKind::Params);
+        }
+        kinds
+    }
+}
+
+impl SelectorItem for Function {
+    fn selector(&self) -> Bytes {
+        self.selector().to_vec().into()
+    }
+}
+
+impl Item for Event {
+    fn signature(&self) -> String {
+        self.signature()
+    }
+
+    fn full_signature(&self) -> String {
+        let signature = self.full_signature();
+        if self.anonymous {
+            signature + " anonymous"
+        } else {
+            signature
+        }
+    }
+
+    fn changes(&self, new: &Self) -> Vec<ChangeKind> {
+        let mut kinds = Vec::new();
+        let pairs = || self.inputs.iter().zip(&new.inputs);
+        if pairs().any(|(a, b)| a.indexed != b.indexed) {
+            kinds.push(ChangeKind::Indexed);
+        }
+        if self.anonymous != new.anonymous {
+            kinds.push(ChangeKind::Anonymous);
+        }
+        if pairs().any(|(a, b)| {
+            a.name != b.name || a.internal_type != b.internal_type || a.components != b.components
+        }) {
+            kinds.push(ChangeKind::Params);
+        }
+        kinds
+    }
+}
+
+impl SelectorItem for Event {
+    fn selector(&self) -> Bytes {
+        self.selector().to_vec().into()
+    }
+}
+
+impl Item for Error {
+    fn signature(&self) -> String {
+        self.signature()
+    }
+
+    fn full_signature(&self) -> String {
+        self.full_signature()
+    }
+
+    fn changes(&self, _new: &Self) -> Vec<ChangeKind> {
+        vec![ChangeKind::Params]
+    }
+}
+
+impl SelectorItem for Error {
+    fn selector(&self) -> Bytes {
+        self.selector().to_vec().into()
+    }
+}
+
+impl Item for Constructor {
+    fn signature(&self) -> String {
+        let mut signature = String::from("constructor");
+        signature_raw(&self.inputs, &mut signature);
+        signature
+    }
+
+    fn full_signature(&self) -> String {
+        let mut signature = String::from("constructor");
+        full_signature_raw(&self.inputs, &mut signature);
+        special_full_signature(signature, self.state_mutability)
+    }
+
+    fn changes(&self, new: &Self) -> Vec<ChangeKind> {
+        let mut kinds = mutability_changes(self.state_mutability, new.state_mutability);
+        if self.inputs != new.inputs {
+            kinds.push(ChangeKind::Params);
+        }
+        kinds
+    }
+}
+
+impl Item for Fallback {
+    fn signature(&self) -> String {
+        "fallback()".into()
+    }
+
+    fn full_signature(&self) -> String {
+        special_full_signature(self.signature(), self.state_mutability)
+    }
+
+    fn changes(&self, new: &Self) -> Vec<ChangeKind> {
+        mutability_changes(self.state_mutability, new.state_mutability)
+    }
+}
+
+impl Item for Receive {
+    fn signature(&self) -> String {
+        "receive()".into()
+    }
+
+    fn full_signature(&self) -> String {
+        special_full_signature(self.signature(), self.state_mutability)
+    }
+
+    fn changes(&self, new: &Self) -> Vec<ChangeKind> {
+        mutability_changes(self.state_mutability, new.state_mutability)
+    }
+}
+
+fn special_full_signature(mut signature: String, state_mutability: StateMutability) -> String {
+    if let Some(state_mutability) = state_mutability.as_str() {
+        signature.push(' ');
+        signature.push_str(state_mutability);
+    }
+    signature
+}
+
+fn mutability_changes(old: StateMutability, new: StateMutability) -> Vec<ChangeKind> {
+    if old == new {
+        Vec::new()
+    } else {
+        vec![ChangeKind::StateMutability { old, new }]
+    }
+}
+
+/// Groups the items by signature.
+fn by_signature<'a, T: Item>(
+    items: impl IntoIterator<Item = &'a T>,
+) -> BTreeMap<String, Vec<&'a T>> {
+    let mut map = BTreeMap::<_, Vec<_>>::new();
+    for item in items {
+        map.entry(Item::signature(item)).or_default().push(item);
+    }
+    map
+}
+
+fn diff_items<'a, T: Item + 'a>(
+    old: impl IntoIterator<Item = &'a T>,
+    new: impl IntoIterator<Item = &'a T>,
+    removal_is_breaking: bool,
+) -> ItemsDiff {
+    let mut diff = ItemsDiff { removal_is_breaking, ..Default::default() };
+    let mut old = by_signature(old);
+    let mut new = by_signature(new);
+    let signatures = old.keys().chain(new.keys()).cloned().collect::<BTreeSet<_>>();
+    for signature in signatures {
+        let mut old = old.remove(&signature).unwrap_or_default();
+        let mut new = new.remove(&signature).unwrap_or_default();
+        // Unchanged items first, then pair up the rest in order.
+        old.retain(|item| match new.iter().position(|new| new == item) {
+            Some(i) => {
+                new.remove(i);
+                false
+            }
+            None => true,
+        });
+        let paired = old.len().min(new.len());
+        for (old, new) in old.drain(..paired).zip(new.drain(..paired)) {
+            let change = Change::new(
+                signature.clone(),
+                Item::full_signature(old),
+                Item::full_signature(new),
+                old.changes(new),
+            );
+            diff.changed.push(change);
+        }
+        diff.removed.extend(old.into_iter().map(Item::full_signature));
+        diff.added.extend(new.into_iter().map(Item::full_signature));
+    }
+    diff
+}
+
+fn find_collisions<T: SelectorItem>(
+    old: &BTreeMap<String, Vec<T>>,
+    new: &BTreeMap<String, Vec<T>>,
+) -> Vec<Collision> {
+    let signatures = |items: &BTreeMap<String, Vec<T>>| -> BTreeSet<String> {
+        items.values().flatten().map(Item::signature).collect()
+    };
+    let (old_signatures, new_signatures) = (signatures(old), signatures(new));
+
+    let mut by_selector = BTreeMap::<_, BTreeSet<_>>::new();
+    for item in old.values().chain(new.values()).flatten() {
+        by_selector.entry(SelectorItem::selector(item)).or_default().insert(Item::signature(item));
+    }
+    by_selector
+        .into_iter()
+        // Collisions between items that are in both ABIs are not new.
+        .filter(|(_, signatures)| {
+            signatures.len() > 1
+                && signatures.iter().any(|signature| {
+                    !old_signatures.contains(signature) || !new_signatures.contains(signature)
+                })
+        })
+        .map(|(selector, signatures)| Collision {
+            selector,
+            signatures: signatures.into_iter().collect(),
+        })
+        .collect()
+}
+
+fn find_ambiguities<'a, T: SelectorItem + 'a>(
+    items: impl IntoIterator<Item = &'a T>,
+) -> Vec<Collision> {
+    let mut ambiguities = Vec::new();
+    for mut items in by_signature(items).into_values() {
+        // Exact duplicates are harmless.
+        let mut i = 0;
+        while i < items.len() {
+            if items[..i].contains(&items[i]) {
+                items.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+        if items.len() > 1 {
+            ambiguities.push(Collision {
+                selector: SelectorItem::selector(items[0]),
+                signatures: items.into_iter().map(Item::full_signature).collect(),
+            });
+        }
+    }
+    ambiguities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base_primitives::hex;
+
+    fn parse(items: &[&str]) -> JsonAbi {
+        JsonAbi::parse(items.iter().copied()).unwrap()
+    }
+
+    fn set_mutability(abi: &mut JsonAbi, name: &str, state_mutability: StateMutability) {
+        abi.functions.get_mut(name).unwrap()[0].state_mutability = state_mutability;
+    }
+
+    #[test]
+    fn unchanged() {
+        let abi = parse(&[
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "event Transfer(address indexed from, address indexed to, uint256 amount)",
+            "error InsufficientBalance(uint256 available, uint256 required)",
+        ]);
+        let diff = abi.diff(&abi);
+        assert!(diff.is_empty());
+        assert!(!diff.breaking);
+        assert_eq!(diff.to_string(), "no changes\n");
+
+        // Duplicates are ignored.
+        let mut duplicated = abi.clone();
+        duplicated
+            .functions
+            .get_mut("transfer")
+            .unwrap()
+            .push(abi.functions["transfer"][0].clone());
+        assert!(abi.diff(&duplicated).is_empty());
+    }
+
+    #[test]
+    fn functions() {
+        let mut old = parse(&[
+            "function burn(uint256 amount)",
+            "function deposit()",
+            "function owner() returns (address)",
+            "function totalSupply() returns (uint256)",
+            "function transfer(address to, uint256 amount) returns (bool)",
+        ]);
+        set_mutability(&mut old, "deposit", StateMutability::Payable);
+        set_mutability(&mut old, "owner", StateMutability::View);
+        let mut new = parse(&[
+            "function deposit()",
+            "function mint(address to, uint256 amount)",
+            "function owner() returns (address)",
+            "function totalSupply() returns (uint128)",
+            "function transfer(address recipient, uint256 amount) returns (bool success)",
+        ]);
+        set_mutability(&mut new, "owner", StateMutability::Payable);
+        let diff = old.diff(&new);
+        assert!(diff.breaking);
+        assert!(diff.events.is_empty() && diff.errors.is_empty());
+        assert_eq!(diff.functions.added, ["function mint(address to, uint256 amount)"]);
+        assert_eq!(diff.functions.removed, ["function burn(uint256 amount)"]);
+
+        let [deposit, owner, total_supply, transfer] = &diff.functions.changed[..] else {
+            panic!("{:?}", diff.functions.changed)
+        };
+        assert_eq!(
+            deposit.kinds,
+            [ChangeKind::StateMutability {
+                old: StateMutability::Payable,
+                new: StateMutability::NonPayable
+            }]
+        );
+        assert!(deposit.breaking);
+        assert_eq!(
+            owner.kinds,
+            [ChangeKind::StateMutability {
+                old: StateMutability::View,
+                new: StateMutability::Payable
+            }]
+        );
+        assert!(!owner.breaking);
+        assert_eq!(total_supply.kinds, [ChangeKind::Outputs]);
+        assert!(total_supply.breaking);
+        assert_eq!(transfer.signature, "transfer(address,uint256)");
+        assert_eq!(transfer.kinds, [ChangeKind::Params]);
+        assert!(!transfer.breaking);
+
+        // Removing a function is breaking on its own.
+        let diff = parse(&["function burn(uint256 amount)"]).diff(&JsonAbi::new());
+        assert!(diff.breaking);
+        assert!(diff.functions.changed.is_empty());
+        assert_eq!(diff.to_string(), "functions:\n  - function burn(uint256 amount) (breaking)\n");
+    }
+
+    #[test]
+    fn events_and_errors() {
+        let old = parse(&[
+            "event Approval(address indexed owner, address spender, uint256 amount)",
+            "event Transfer(address indexed from, address indexed to, uint256 amount)",
+            "error Unauthorized(address caller)",
+        ]);
+        let new = parse(&[
+            "event Approval(address indexed owner, address indexed spender, uint256 amount)",
+            "event Transfer(address indexed from, address indexed to, uint256 amount) anonymous",
+            "error Unauthorized(address account)",
+        ]);
+        let diff = old.diff(&new);
+        assert!(diff.breaking);
+        assert_eq!(diff.events.changed[0].kinds, [ChangeKind::Indexed]);
+        assert_eq!(diff.events.changed[1].kinds, [ChangeKind::Anonymous]);
+        assert!(diff.events.changed[1].new.ends_with(" anonymous"));
+        assert_eq!(diff.errors.changed[0].kinds, [ChangeKind::Params]);
+        assert!(!diff.errors.is_breaking());
+
+        // Removing an error is not breaking.
+        let diff = parse(&["error Unauthorized(address caller)"]).diff(&JsonAbi::new());
+        assert_eq!(diff.errors.removed, ["error Unauthorized(address caller)"]);
+        assert!(!diff.breaking);
+    }
+
+    #[test]
+    fn collisions_and_ambiguities() {
+        // Overloads that only differ in outputs share a selector.
+        let old = parse(&["function transfer(address to, uint256 amount)"]);
+        let new = parse(&[
+            "function transfer(address to, uint256 amount)",
+            "function transfer(address to, uint256 amount) returns (bool)",
+        ]);
+        let diff = old.diff(&new);
+        assert!(diff.collisions.is_empty());
+        assert_eq!(
+            diff.ambiguities,
+            [Collision {
+                selector: new.functions["transfer"][0].selector().to_vec().into(),
+                signatures: vec![
+                    "function transfer(address to, uint256 amount)".into(),
+                    "function transfer(address to, uint256 amount) returns (bool)".into(),
+                ],
+            }]
+        );
+        assert!(!diff.breaking);
+
+        // `collide31343()` and `collide53132()` share the selector 0xf7c904f5.
+        let old = parse(&["function collide31343()"]);
+        let new = parse(&["function collide53132()"]);
+        let diff = old.diff(&new);
+        let collision = Collision {
+            selector: hex!("f7c904f5").into(),
+            signatures: vec!["collide31343()".into(), "collide53132()".into()],
+        };
+        assert_eq!(diff.collisions, core::slice::from_ref(&collision));
+        assert!(diff.breaking);
+        assert!(diff.ambiguities.is_empty());
+        assert!(diff
+            .to_string()
+            .ends_with("selector collisions:\n  0xf7c904f5: collide31343(), collide53132()\n"));
+
+        // Collisions that are already in the old ABI are not reported.
+        let both = parse(&["function collide31343()", "function collide53132()"]);
+        assert!(both.diff(&both).is_empty());
+        assert!(!both.diff(&both).breaking);
+        assert_eq!(old.diff(&both).collisions, [collision.clone()]);
+        assert_eq!(both.diff(&old).collisions, [collision]);
+    }
+
+    #[test]
+    fn special_functions() {
+        let mut old = parse(&["constructor(uint256 supply)"]);
+        old.fallback = Some(Fallback { state_mutability: StateMutability::Payable });
+        old.receive = Some(Receive { state_mutability: StateMutability::Payable });
+
+        // Both break plain value transfers.
+        let mut new = old.clone();
+        new.fallback = Some(Fallback { state_mutability: StateMutability::NonPayable });
+        new.receive = None;
+        let diff = old.diff(&new);
+        assert!(diff.breaking);
+        assert_eq!(
+            diff.to_string(),
+            "\
+special functions:
+  - receive() payable (breaking)
+  ~ fallback(): state mutability changed from payable to nonpayable (breaking)
+      - fallback() payable
+      + fallback()
+"
+        );
+
+        let diff = new.diff(&old);
+        assert!(!diff.breaking);
+        assert_eq!(diff.special_functions.added, ["receive() payable"]);
+
+        let mut new = old.clone();
+        new.fallback = None;
+        new.constructor.as_mut().unwrap().inputs[0].name = "initialSupply".into();
+        let diff = old.diff(&new);
+        assert!(diff.breaking);
+        assert_eq!(diff.special_functions.removed, ["fallback() payable"]);
+        assert_eq!(diff.special_functions.changed[0].kinds, [ChangeKind::Params]);
+        assert!(!diff.special_functions.changed[0].breaking);
+
+        // New constructor arguments break deployments.
+        let new = JsonAbi {
+            constructor: parse(&["constructor(uint128 supply)"]).constructor,
+            ..old.clone()
+        };
+        let diff = old.diff(&new);
+        assert!(diff.breaking);
+        assert_eq!(diff.special_functions.added, ["constructor(uint128 supply)"]);
+        assert_eq!(diff.special_functions.removed, ["constructor(uint256 supply)"]);
+    }
+
+    #[test]
+    fn serde() {
+        let mut old = parse(&["function f() returns (uint256)"]);
+        set_mutability(&mut old, "f", StateMutability::View);
+        let mut new = old.clone();
+        set_mutability(&mut new, "f", StateMutability::Pure);
+        let diff = old.diff(&new);
+        let json = serde_json::to_string(&diff).unwrap();
+        assert!(json.contains(r#""kinds":[{"kind":"stateMutability","old":"view","new":"pure"}]"#));
+        assert_eq!(serde_json::from_str::<AbiDiff>(&json).unwrap(), diff);
+    }
+}
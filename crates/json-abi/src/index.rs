@@ -0,0 +1,158 @@
+use crate::{Error, Event, Function, JsonAbi};
+use alloc::{collections::BTreeMap, vec::Vec};
+use base_primitives::{Selector, B256};
+
+/// An index of the functions, errors and events of a [`JsonAbi`] by selector,
+/// for reverse lookups.
+///
+/// Building the index hashes every item once; lookups are then logarithmic
+/// instead of a linear scan over the ABI. The index borrows the ABI, so it
+/// cannot go stale while it is alive.
+///
+/// Lookups return `Err` with all the candidates if more than one item has the
+/// requested selector. This happens when a selector collides, or when events
+/// with the same signature differ only in which parameters are `indexed`.
+///
+/// # Examples
+///
+/// ```
+/// use base_json_abi::JsonAbi;
+/// use base_primitives::hex;
+///
+/// let abi = JsonAbi::parse(["function transfer(address to, uint256 amount) returns (bool)"])?;
+/// let index = abi.selector_index();
+/// let transfer = index.function_by_selector(hex!("4b40e901")).unwrap().unwrap();
+/// assert_eq!(transfer.name, "transfer");
+/// assert_eq!(index.function_by_selector([0; 4]), Ok(None));
+/// # Ok::<(), base_json_abi::parser::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SelectorIndex<'a> {
+    functions: BTreeMap<Selector, Vec<&'a Function>>,
+    errors: BTreeMap<Selector, Vec<&'a Error>>,
+    events: BTreeMap<B256, Vec<&'a Event>>,
+}
+
+impl<'a> SelectorIndex<'a> {
+    /// Indexes the given ABI. Anonymous events have no topic 0 and are not
+    /// indexed.
+    pub fn new(abi: &'a JsonAbi) -> Self {
+        let mut index = Self::default();
+        for function in abi.functions() {
+            index.functions.entry(function.selector()).or_default().push(function);
+        }
+        for error in abi.errors() {
+            index.errors.entry(error.selector()).or_default().push(error);
+        }
+        for event in abi.events().filter(|event| !event.anonymous) {
+            index.events.entry(event.selector()).or_default().push(event);
+        }
+        index
+    }
+
+    /// Returns the function with the given selector.
+    ///
+    /// Returns `Err` with all the candidates if the selector is ambiguous.
+    #[inline]
+    pub fn function_by_selector(
+        &self,
+        selector: impl Into<Selector>,
+    ) -> Result<Option<&'a Function>, &[&'a Function]> {
+        lookup(&self.functions, &selector.into())
+    }
+
+    /// Returns the error with the given selector.
+    ///
+    /// Returns `Err` with all the candidates if the selector is ambiguous.
+    #[inline]
+    pub fn error_by_selector(
+        &self,
+        selector: impl Into<Selector>,
+    ) -> Result<Option<&'a Error>, &[&'a Error]> {
+        lookup(&self.errors, &selector.into())
+    }
+
+    /// Returns the non-anonymous event with the given topic 0.
+    ///
+    /// Returns `Err` with all the candidates if the topic is ambiguous.
+    #[inline]
+    pub fn event_by_topic0(&self, topic0: B256) -> Result<Option<&'a Event>, &[&'a Event]> {
+        lookup(&self.events, &topic0)
+    }
+}
+
+impl JsonAbi {
+    /// Builds a [`SelectorIndex`] over this ABI's functions, errors and events.
+    ///
+    /// Keep the index around to look up many selectors.
+    #[inline]
+    pub fn selector_index(&self) -> SelectorIndex<'_> {
+        SelectorIndex::new(self)
+    }
+}
+
+fn lookup<'i, 'a, K: Ord, T>(
+    map: &'i BTreeMap<K, Vec<&'a T>>,
+    key: &K,
+) -> Result<Option<&'a T>, &'i [&'a T]> {
+    match map.get(key).map(Vec::as_slice) {
+        None => Ok(None),
+        Some(&[item]) => Ok(Some(item)),
+        Some(items) => Err(items),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base_primitives::hex;
+
+    #[test]
+    fn lookups() {
+        let abi = JsonAbi::parse([
+            "function balanceOf(address owner) returns (uint256)",
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "error InsufficientBalance(uint256 available, uint256 required)",
+            "event Transfer(address indexed from, address indexed to, uint256 amount)",
+            "event Anonymous(uint256 value) anonymous",
+        ])
+        .unwrap();
+        let index = abi.selector_index();
+
+        let balance_of = index.function_by_selector(hex!("1d7976f3")).unwrap().unwrap();
+        assert_eq!(balance_of.name, "balanceOf");
+        let transfer = &abi.functions["transfer"][0];
+        assert_eq!(index.function_by_selector(transfer.selector()), Ok(Some(transfer)));
+        assert_eq!(index.function_by_selector([0; 4]), Ok(None));
+
+        let error = &abi.errors["InsufficientBalance"][0];
+        assert_eq!(index.error_by_selector(error.selector()), Ok(Some(error)));
+        assert_eq!(index.error_by_selector(transfer.selector()), Ok(None));
+
+        let event = &abi.events["Transfer"][0];
+        assert_eq!(index.event_by_topic0(event.selector()), Ok(Some(event)));
+        let anonymous = &abi.events["Anonymous"][0];
+        assert_eq!(index.event_by_topic0(anonymous.selector()), Ok(None));
+    }
+
+    #[test]
+    fn ambiguous() {
+        // Events that differ only in `indexed`, and functions only in outputs.
+        let abi = JsonAbi::parse([
+            "event Transfer(address indexed from, address indexed to, uint256 amount)",
+            "event Transfer(address indexed from, address indexed to, uint256 indexed id)",
+            "function transfer(address to, uint256 amount)",
+            "function transfer(address to, uint256 amount) returns (bool)",
+        ])
+        .unwrap();
+        let index = abi.selector_index();
+
+        let transfer = &abi.events["Transfer"];
+        let candidates = index.event_by_topic0(transfer[0].selector()).unwrap_err();
+        assert_eq!(candidates, [&transfer[0], &transfer[1]]);
+
+        let transfer = &abi.functions["transfer"];
+        let candidates = index.function_by_selector(transfer[0].selector()).unwrap_err();
+        assert_eq!(candidates, [&transfer[0], &transfer[1]]);
+    }
+}
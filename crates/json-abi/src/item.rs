@@ -418,6 +418,15 @@ impl Error {
         signature(&self.name, &self.inputs, None)
     }
 
+    /// Returns this error's full signature including names of params:
+    /// `error $name($($inputs $names),*)`.
+    ///
+    /// This is a full human-readable string, including all parameter names.
+    #[inline]
+    pub fn full_signature(&self) -> String {
+        error_full_signature(&self.name, &self.inputs)
+    }
+
     /// Computes this error's selector: `sha3(self.signature())[..4]`
     #[inline]
     pub fn selector(&self) -> Selector {
@@ -33,6 +33,32 @@ use serde::{Deserialize, Serialize};
 mod abi;
 pub use abi::{ContractObject, IntoItems, Items, JsonAbi};
 
+mod diff;
+pub use diff::{AbiDiff, Change, ChangeKind, Collision, ItemsDiff};
+
+mod index;
+pub use index::SelectorIndex;
+
+mod bytecode;
+pub use bytecode::{LinkError, LinkReferences, Offsets, UnlinkedBytecode};
+
+#[cfg(feature = "serde_json")]
+mod artifact;
+#[cfg(feature = "serde_json")]
+pub use artifact::{
+    Bytecode, CombinedJson, ContractArtifact, DevDoc, DocItem, Metadata, MetadataCompiler,
+    MetadataOutput, MetadataSettings, MetadataSource, Optimizer, SettingsMetadata, StorageLayout,
+    StorageSlot, StorageType, UserDoc,
+};
+
+#[cfg(feature = "serde_json")]
+mod standard_json;
+#[cfg(feature = "serde_json")]
+pub use standard_json::{
+    CompilerError, OutputSelection, SecondarySourceLocation, Settings, Severity, Source,
+    SourceLocation, SourceOutput, StandardJsonInput, StandardJsonOutput,
+};
+
 mod item;
 pub use item::{AbiItem, Constructor, Error, Event, Fallback, Function, Receive};
 
@@ -0,0 +1,334 @@
+//! Ylem standard-JSON compiler input and output.
+//!
+//! See [`StandardJsonInput`] and [`StandardJsonOutput`] for more details.
+
+use crate::{ContractArtifact, Optimizer, SettingsMetadata};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use base_primitives::{IcanAddress, B256};
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The output selection: file name to contract name to the list of outputs.
+///
+/// `*` selects all files or contracts, and an empty contract name selects
+/// file-level outputs such as `ast`.
+pub type OutputSelection = BTreeMap<String, BTreeMap<String, Vec<String>>>;
+
+/// The input of `ylem --standard-json`.
+///
+/// # Examples
+///
+/// ```
+/// use base_json_abi::{Source, StandardJsonInput};
+///
+/// let mut input =
+///     StandardJsonInput::new([("src/Token.ylm".into(), Source::new("contract Token {}"))]);
+/// input.settings.optimizer.enabled = Some(true);
+/// input.settings.optimizer.runs = Some(200);
+///
+/// let json = serde_json::to_string(&input)?;
+/// assert!(json.starts_with(r#"{"language":"Ylem","sources":{"src/Token.ylm":{"content":"contract Token {}"}}"#));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StandardJsonInput {
+    /// The source language.
+    pub language: String,
+    /// The source files, indexed by their source unit name.
+    pub sources: BTreeMap<String, Source>,
+    /// The compiler settings.
+    #[serde(default)]
+    pub settings: Settings,
+}
+
+impl Default for StandardJsonInput {
+    #[inline]
+    fn default() -> Self {
+        Self { language: "Ylem".into(), sources: BTreeMap::new(), settings: Settings::default() }
+    }
+}
+
+impl StandardJsonInput {
+    /// Creates a new input for the given Ylem sources, with the
+    /// [default output selection](Settings::default_output_selection).
+    pub fn new(sources: impl IntoIterator<Item = (String, Source)>) -> Self {
+        let mut input = Self { sources: sources.into_iter().collect(), ..Default::default() };
+        input.settings.output_selection = Settings::default_output_selection();
+        input
+    }
+}
+
+/// A source file in [`StandardJsonInput`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Source {
+    /// The hash of the contents, used to verify contents fetched from `urls`.
+    #[serde(default, alias = "sha3", skip_serializing_if = "Option::is_none")]
+    pub keccak256: Option<B256>,
+    /// The literal contents of the file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The URLs to import the file from, if `content` is not given.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub urls: Vec<String>,
+}
+
+impl Source {
+    /// Creates a new source with the given contents.
+    #[inline]
+    pub fn new(content: impl Into<String>) -> Self {
+        Self { content: Some(content.into()), ..Default::default() }
+    }
+}
+
+/// The compiler settings in [`StandardJsonInput`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Settings {
+    /// Stops compilation after the given stage. Only `parsing` is supported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_after: Option<String>,
+    /// The import remappings, e.g. `lib/=node_modules/lib/`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remappings: Vec<String>,
+    /// The optimizer settings.
+    #[serde(default)]
+    pub optimizer: Optimizer,
+    /// The VM version to target.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evm_version: Option<String>,
+    /// Whether to compile through the IR-based code generator.
+    #[serde(default, rename = "viaIR", skip_serializing_if = "Option::is_none")]
+    pub via_ir: Option<bool>,
+    /// The metadata settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<SettingsMetadata>,
+    /// The addresses of the libraries to link: file name to library name to
+    /// address.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub libraries: BTreeMap<String, BTreeMap<String, IcanAddress>>,
+    /// The outputs to generate.
+    #[serde(default)]
+    pub output_selection: OutputSelection,
+    /// The debugging settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debug: Option<Value>,
+}
+
+impl Settings {
+    /// Returns the output selection needed to fill every field of
+    /// [`ContractArtifact`], plus the AST of every file.
+    pub fn default_output_selection() -> OutputSelection {
+        let contract = [
+            "abi",
+            "devdoc",
+            "userdoc",
+            "metadata",
+            "storageLayout",
+            "evm.bytecode",
+            "evm.deployedBytecode",
+            "evm.methodIdentifiers",
+        ];
+        let outputs = BTreeMap::from([
+            (String::new(), vec!["ast".to_string()]),
+            ("*".to_string(), contract.iter().map(ToString::to_string).collect()),
+        ]);
+        BTreeMap::from([("*".to_string(), outputs)])
+    }
+
+    /// Adds the address of a library to link, given its fully qualified name:
+    /// `<file>:<name>`.
+    ///
+    /// A name without a file is linked in every file, the same as an empty file
+    /// name.
+    pub fn link(&mut self, library_fqn: &str, address: IcanAddress) {
+        let (file, name) = library_fqn.rsplit_once(':').unwrap_or(("", library_fqn));
+        self.libraries.entry(file.into()).or_default().insert(name.into(), address);
+    }
+}
+
+/// The output of `ylem --standard-json`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StandardJsonOutput {
+    /// The errors, warnings and infos reported by the compiler.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<CompilerError>,
+    /// The source files, indexed by their source unit name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub sources: BTreeMap<String, SourceOutput>,
+    /// The contracts: file name to contract name to artifact.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub contracts: BTreeMap<String, BTreeMap<String, ContractArtifact>>,
+}
+
+impl StandardJsonOutput {
+    /// Returns `true` if any of the diagnostics is an error, in which case the
+    /// compilation failed.
+    pub fn has_errors(&self) -> bool {
+        self.errors.iter().any(CompilerError::is_error)
+    }
+
+    /// Returns an iterator over the diagnostics with the given severity.
+    pub fn diagnostics(&self, severity: Severity) -> impl Iterator<Item = &CompilerError> {
+        self.errors.iter().filter(move |e| e.severity == severity)
+    }
+
+    /// Returns the contract with the given fully qualified or plain name.
+    ///
+    /// Plain names return the first contract with that name, ordered by file.
+    pub fn find(&self, name: &str) -> Option<&ContractArtifact> {
+        match name.rsplit_once(':') {
+            Some((file, name)) => self.contracts.get(file)?.get(name),
+            None => self.contracts.values().find_map(|contracts| contracts.get(name)),
+        }
+    }
+
+    /// Returns an iterator over the contracts and their fully qualified names.
+    pub fn contracts(&self) -> impl Iterator<Item = (String, &ContractArtifact)> {
+        self.contracts.iter().flat_map(|(file, contracts)| {
+            contracts.iter().map(move |(name, contract)| (format!("{file}:{name}"), contract))
+        })
+    }
+}
+
+/// A source file in [`StandardJsonOutput`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceOutput {
+    /// The source ID, as used in source maps.
+    pub id: u32,
+    /// The AST of the file, if selected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ast: Option<Value>,
+}
+
+/// An error, warning or info reported by the compiler.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerError {
+    /// The location the diagnostic refers to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_location: Option<SourceLocation>,
+    /// Further locations, e.g. of conflicting declarations.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub secondary_source_locations: Vec<SecondarySourceLocation>,
+    /// The kind of diagnostic, e.g. `TypeError`, `ParserError` or `Warning`.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The component that reported the diagnostic, e.g. `general`.
+    pub component: String,
+    /// The severity of the diagnostic.
+    pub severity: Severity,
+    /// The unique code of the diagnostic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    /// The message.
+    pub message: String,
+    /// The message formatted with the source location and a code snippet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formatted_message: Option<String>,
+}
+
+impl fmt::Display for CompilerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(formatted) = &self.formatted_message {
+            return f.write_str(formatted.trim_end());
+        }
+        write!(f, "{}", self.ty)?;
+        if let Some(code) = &self.error_code {
+            write!(f, " ({code})")?;
+        }
+        write!(f, ": {}", self.message)?;
+        if let Some(location) = &self.source_location {
+            write!(f, "\n --> {location}")?;
+        }
+        for secondary in &self.secondary_source_locations {
+            write!(f, "\n{}", secondary.message)?;
+            if let Some(location) = &secondary.location {
+                write!(f, "\n --> {location}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CompilerError {}
+
+impl CompilerError {
+    /// Returns `true` if this is an error, rather than a warning or info.
+    #[inline]
+    pub const fn is_error(&self) -> bool {
+        self.severity.is_error()
+    }
+}
+
+/// The severity of a [`CompilerError`].
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// An error. The compilation failed.
+    #[default]
+    Error,
+    /// A warning.
+    Warning,
+    /// Informational.
+    Info,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Severity {
+    /// Returns the string representation of the severity.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Info => "info",
+        }
+    }
+
+    /// Returns `true` if this is [`Severity::Error`].
+    #[inline]
+    pub const fn is_error(self) -> bool {
+        matches!(self, Self::Error)
+    }
+}
+
+/// A range in a source file that a [`CompilerError`] refers to.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SourceLocation {
+    /// The source unit name of the file.
+    pub file: String,
+    /// The byte offset of the start of the range, or -1 if unknown.
+    pub start: i32,
+    /// The byte offset of the end of the range, exclusive, or -1 if unknown.
+    pub end: i32,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}..{}", self.file, self.start, self.end)
+    }
+}
+
+/// A secondary location of a [`CompilerError`], with its own message.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SecondarySourceLocation {
+    /// The location, if known.
+    #[serde(flatten)]
+    pub location: Option<SourceLocation>,
+    /// The message, e.g. "The previous declaration is here:".
+    #[serde(default)]
+    pub message: String,
+}
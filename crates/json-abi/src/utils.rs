@@ -130,6 +130,16 @@ pub(crate) fn full_signature_raw(params: &[Param], preimage: &mut String) {
     full_signature!(params, preimage);
 }
 
+/// `error $name($($inputs names),*)`
+pub(crate) fn error_full_signature(name: &str, inputs: &[Param]) -> String {
+    let mut preimage =
+        String::with_capacity("error ".len() + name.len() + 2 + inputs.len() * PARAM);
+    preimage.push_str("error ");
+    preimage.push_str(name);
+    full_signature_raw(inputs, &mut preimage);
+    preimage
+}
+
 /// `$name($($inputs),*)`
 pub(crate) fn event_signature(name: &str, inputs: &[EventParam]) -> String {
     let mut preimage = String::with_capacity(name.len() + 2 + inputs.len() * PARAM);
@@ -423,6 +433,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_full_signature() {
+        assert_eq!(error_full_signature("foo", &[]), "error foo()");
+        assert_eq!(
+            error_full_signature("foo", &[param2("uint256", "a"), param2("address", "")]),
+            "error foo(uint256 a, address)"
+        );
+    }
+
     #[test]
     fn test_item_parse() {
         assert_eq!(parse_sig::<true>("foo()"), Ok(("foo".into(), vec![], vec![], false)));
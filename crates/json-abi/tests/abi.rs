@@ -289,9 +289,53 @@ fn get_solc_version() -> Option<(u16, u16, u16)> {
 #[cfg(all(feature = "std", feature = "serde_json"))]
 fn parse_unlinked_contract() {
     const TESTDATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/testdata");
-    // unlinked placeholder __$7233c33f2e1e35848c685b0eb24649959e$__
+    // 20-byte unlinked placeholder __$7233c33f2e1e35848c685b0eb24649959e$__
     let content = fs::read_to_string(Path::new(TESTDATA_PATH).join("UnlinkedNouns.json")).unwrap();
     let res = serde_json::from_str::<base_json_abi::ContractObject>(&content);
     let err = res.unwrap_err();
-    assert!(err.to_string().contains("expected bytecode, found unlinked bytecode with placeholder: 7233c33f2e1e35848c685b0eb24649959e"));
+    assert!(
+        err.to_string().contains("invalid library placeholder in bytecode at byte 2536"),
+        "{err}"
+    );
+}
+
+#[test]
+#[cfg_attr(miri, ignore = "no fs")]
+#[cfg(all(feature = "std", feature = "serde_json"))]
+fn link_unlinked_contract() {
+    use base_json_abi::{ContractObject, UnlinkedBytecode};
+    use base_primitives::IcanAddress;
+
+    const TESTDATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/testdata");
+    let content = fs::read_to_string(Path::new(TESTDATA_PATH).join("UnlinkedYlem.json")).unwrap();
+    let mut contract = serde_json::from_str::<ContractObject>(&content).unwrap();
+    assert!(contract.abi.is_some());
+    assert!(!contract.is_linked());
+    assert_eq!(contract.bytecode, None);
+    assert_eq!(contract.deployed_bytecode, None);
+
+    let math = UnlinkedBytecode::placeholder("src/Math.ylm:Math");
+    let strings = UnlinkedBytecode::legacy_placeholder("Strings");
+    let bytecode = contract.unlinked_bytecode.as_ref().unwrap();
+    assert_eq!(bytecode.placeholders().collect::<Vec<_>>(), [math.as_str(), math.as_str()]);
+    assert_eq!(bytecode.link_references()["src/Math.ylm"]["Math"].len(), 2);
+    let deployed = contract.unlinked_deployed_bytecode.as_ref().unwrap();
+    assert_eq!(deployed.placeholders().collect::<Vec<_>>(), [strings.as_str()]);
+
+    // Serializes back into a form that deserializes to the same object.
+    let json = serde_json::to_string(&contract).unwrap();
+    assert_eq!(serde_json::from_str::<ContractObject>(&json).unwrap(), contract);
+
+    let math_address = IcanAddress::repeat_byte(0x11);
+    assert!(contract.link("src/Math.ylm:Math", math_address));
+    assert!(!contract.link("src/Math.ylm:Math", math_address));
+    let bytecode = contract.bytecode.as_ref().unwrap();
+    assert_eq!(contract.unlinked_bytecode, None);
+    assert_eq!(bytecode.windows(22).filter(|w| *w == math_address.as_slice()).count(), 2);
+    assert!(!contract.is_linked());
+
+    assert!(contract.link("src/Strings.ylm:Strings", IcanAddress::repeat_byte(0x22)));
+    assert!(contract.is_linked());
+    let deployed = contract.deployed_bytecode.as_ref().unwrap();
+    assert!(deployed.windows(22).any(|w| w == [0x22; 22]));
 }
@@ -0,0 +1,142 @@
+use base_json_abi::{
+    CombinedJson, ContractArtifact, ContractObject, Metadata, Offsets, UnlinkedBytecode,
+};
+use base_primitives::{cAddress, hex, Selector};
+use std::collections::BTreeMap;
+
+const STANDARD_JSON: &str = include_str!("../testdata/StandardJsonContract.json");
+const COMBINED_JSON: &str = include_str!("../testdata/CombinedJson.json");
+
+fn method_identifiers() -> BTreeMap<String, Selector> {
+    BTreeMap::from([
+        ("balanceOf(address)".into(), Selector::new(hex!("1d7976f3"))),
+        ("transfer(address,uint256)".into(), Selector::new(hex!("4b40e901"))),
+    ])
+}
+
+fn assert_token(token: &ContractArtifact) {
+    let abi = token.abi.as_ref().unwrap();
+    assert_eq!(abi.functions().count(), 2);
+    assert_eq!(
+        abi.functions().map(|f| (f.signature(), f.selector())).collect::<BTreeMap<_, _>>(),
+        token.method_identifiers
+    );
+    assert_eq!(token.method_identifiers, method_identifiers());
+
+    let metadata = token.metadata.as_ref().unwrap();
+    assert_eq!(metadata.compiler.version, "1.1.2+commit.27f2a9a6");
+    assert_eq!(metadata.language, "Ylem");
+    assert_eq!(metadata.compilation_target().as_deref(), Some("src/Token.ylm:Token"));
+    assert_eq!(metadata.settings.optimizer.enabled, Some(true));
+    assert_eq!(metadata.settings.optimizer.runs, Some(200));
+    assert_eq!(metadata.settings.evm_version.as_deref(), Some("istanbul"));
+    assert_eq!(
+        metadata.settings.libraries["src/Math.ylm:Math"],
+        cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5")
+    );
+    assert_eq!(&metadata.output.abi, abi);
+    assert_eq!(metadata.sources.len(), 2);
+    assert_eq!(metadata.sources["src/Token.ylm"].license.as_deref(), Some("MIT"));
+    assert_eq!(metadata.sources["src/Token.ylm"].urls.len(), 2);
+    let raw_metadata = token.raw_metadata.as_deref().unwrap();
+    assert!(raw_metadata.starts_with(r#"{"compiler":{"version":"1.1.2+commit.27f2a9a6"}"#));
+    assert_eq!(serde_json::from_str::<Metadata>(raw_metadata).unwrap(), *metadata);
+
+    let devdoc = token.devdoc.as_ref().unwrap();
+    assert_eq!(devdoc.title.as_deref(), Some("Token"));
+    assert_eq!(devdoc.author.as_deref(), Some("Core Blockchain"));
+    let transfer = &devdoc.methods["transfer(address,uint256)"];
+    assert_eq!(transfer.params["to"], "The recipient.");
+    assert_eq!(transfer.returns["_0"], "Always true.");
+    assert_eq!(devdoc.errors["InsufficientBalance(uint256,uint256)"].len(), 1);
+    assert_eq!(
+        devdoc.state_variables["totalSupply"].return_.as_deref(),
+        Some("The number of tokens.")
+    );
+    assert_eq!(devdoc.other["custom:security"], "security@coreblockchain.net");
+    assert_eq!(metadata.output.devdoc.as_ref(), Some(devdoc));
+
+    let userdoc = token.userdoc.as_ref().unwrap();
+    assert_eq!(userdoc.notice.as_deref(), Some("Send tokens around."));
+    assert_eq!(userdoc.methods["constructor"].notice.as_deref(), Some("Mints the supply."));
+
+    let layout = token.storage_layout.as_ref().unwrap();
+    assert_eq!(layout.storage.len(), 4);
+    let paused = layout.get("paused").unwrap();
+    assert_eq!((paused.slot.as_str(), paused.offset), ("2", 22));
+    assert_eq!(layout.types[&paused.ty].label, "bool");
+    let balances = &layout.types[&layout.get("balances").unwrap().ty];
+    assert_eq!(balances.encoding, "mapping");
+    assert_eq!(balances.key.as_deref(), Some("t_address"));
+    assert_eq!(layout.types["t_address"].number_of_bytes, "22");
+
+    let math = UnlinkedBytecode::placeholder("src/Math.ylm:Math");
+    let bytecode = token.bytecode.as_ref().unwrap();
+    assert_eq!(bytecode.object.placeholders().collect::<Vec<_>>(), [math.as_str()]);
+    assert_eq!(bytecode.bytes(), None);
+    assert_eq!(bytecode.source_map.as_deref(), Some("59:1200:0:-:0;;;;;;"));
+    let deployed = token.deployed_bytecode.as_ref().unwrap();
+    assert_eq!(deployed.object.placeholders().collect::<Vec<_>>(), [math.as_str()]);
+    assert_eq!(deployed.source_map.as_deref(), Some("59:1200:0:-:0;;;;"));
+}
+
+#[test]
+fn standard_json_contract() {
+    let token: ContractArtifact = serde_json::from_str(STANDARD_JSON).unwrap();
+    assert_token(&token);
+
+    let bytecode = token.bytecode.as_ref().unwrap();
+    assert_eq!(bytecode.opcodes.as_deref(), Some("PUSH1 0x80 PUSH1 0x40 MSTORE"));
+    assert_eq!(
+        bytecode.object.link_references()["src/Math.ylm"]["Math"],
+        [Offsets { start: 46, length: 22 }]
+    );
+    let deployed = token.deployed_bytecode.as_ref().unwrap();
+    assert_eq!(deployed.immutable_references["5"], [Offsets { start: 61, length: 32 }]);
+
+    let json = serde_json::to_string(&token).unwrap();
+    assert_eq!(serde_json::from_str::<ContractArtifact>(&json).unwrap(), token);
+}
+
+#[test]
+fn combined_json() {
+    let output: CombinedJson = serde_json::from_str(COMBINED_JSON).unwrap();
+    assert_eq!(output.version, "1.1.2+commit.27f2a9a6.Linux.g++");
+    assert_eq!(output.source_list, ["src/Math.ylm", "src/Token.ylm"]);
+    assert_eq!(output.contracts.len(), 2);
+
+    let token = output.find("Token").unwrap();
+    assert_eq!(output.find("src/Token.ylm:Token"), Some(token));
+    assert_eq!(output.find("Other"), None);
+    assert_token(token);
+    // Combined JSON has no link references; placeholders are found by scanning.
+    assert!(token.bytecode.as_ref().unwrap().object.link_references().is_empty());
+
+    let math = output.find("Math").unwrap();
+    assert!(math.abi.as_ref().unwrap().is_empty());
+    assert_eq!(math.metadata, None);
+    assert_eq!(math.raw_metadata, None);
+    assert!(math.storage_layout.as_ref().unwrap().types.is_empty());
+    assert!(math.bytecode.as_ref().unwrap().bytes().is_some());
+}
+
+#[test]
+fn link_artifact() {
+    let mut token: ContractArtifact = serde_json::from_str(STANDARD_JSON).unwrap();
+    let object = ContractObject::from(token.clone());
+    assert_eq!(object.abi, token.abi);
+    assert!(!object.is_linked());
+
+    let address = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+    assert!(token.link("src/Math.ylm:Math", address));
+    for code in [&token.bytecode, &token.deployed_bytecode] {
+        let code = code.as_ref().unwrap();
+        assert!(code.object.link_references().is_empty());
+        let bytes = code.bytes().unwrap();
+        assert_eq!(bytes.windows(22).filter(|w| *w == address.as_slice()).count(), 1);
+    }
+
+    let object = ContractObject::from(token);
+    assert!(object.is_linked());
+    assert!(object.bytecode.is_some() && object.deployed_bytecode.is_some());
+}
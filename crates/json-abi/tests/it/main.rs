@@ -3,7 +3,11 @@ mod macros;
 
 mod abi_items;
 mod abis;
+#[cfg(feature = "serde_json")]
+mod artifact;
 mod event_params;
 mod params;
+#[cfg(feature = "serde_json")]
+mod standard_json;
 mod state_mutability;
 mod test;
@@ -437,3 +437,20 @@ fn param_tuple_with_nested_tuple_arrays() {
 
     assert_json_eq!(s, serde_json::to_string(&deserialized).unwrap().as_str());
 }
+
+#[test]
+fn param_fixed() {
+    let s = r#"{
+            "name": "price",
+            "type": "ufixed128x18",
+            "internalType": "ufixed128x18"
+        }"#;
+
+    let deserialized: Param = serde_json::from_str(s).unwrap();
+    assert_eq!(deserialized.ty, "ufixed128x18");
+    assert_eq!(deserialized.selector_type(), "ufixed128x18");
+    assert_json_eq!(s, serde_json::to_string(&deserialized).unwrap().as_str());
+
+    let function = base_json_abi::Function::parse("quote(ufixed, fixed64x4[])").unwrap();
+    assert_eq!(function.signature(), "quote(ufixed128x18,fixed64x4[])");
+}
@@ -0,0 +1,136 @@
+use base_json_abi::{
+    CompilerError, Settings, Severity, Source, SourceLocation, StandardJsonInput,
+    StandardJsonOutput,
+};
+use base_primitives::{b256, cAddress};
+
+const INPUT: &str = include_str!("../testdata/StandardJsonInput.json");
+const OUTPUT: &str = include_str!("../testdata/StandardJsonOutput.json");
+
+#[test]
+fn input() {
+    let input: StandardJsonInput = serde_json::from_str(INPUT).unwrap();
+    assert_eq!(input.language, "Ylem");
+    assert_eq!(input.sources.len(), 2);
+    assert!(input.sources["src/Math.ylm"].content.as_ref().unwrap().contains("library Math"));
+    let token = &input.sources["src/Token.ylm"];
+    assert_eq!(token.content, None);
+    assert_eq!(
+        token.keccak256,
+        Some(b256!("7de236ee77968caf8ab156911304e6dca7993a36543f5eac57f01741893374cb"))
+    );
+    assert_eq!(token.urls.len(), 2);
+
+    let settings = &input.settings;
+    assert_eq!(settings.remappings, ["lib/=lib/"]);
+    assert_eq!(settings.optimizer.enabled, Some(true));
+    assert_eq!(settings.optimizer.runs, Some(200));
+    assert_eq!(settings.evm_version.as_deref(), Some("istanbul"));
+    assert_eq!(settings.via_ir, Some(false));
+    let metadata = settings.metadata.as_ref().unwrap();
+    assert_eq!(metadata.use_literal_content, Some(true));
+    assert_eq!(metadata.bytecode_hash.as_deref(), Some("ipfs"));
+    assert_eq!(
+        settings.libraries["src/Math.ylm"]["Math"],
+        cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5")
+    );
+    assert_eq!(settings.output_selection["*"][""], ["ast"]);
+    assert_eq!(settings.output_selection["*"]["*"].len(), 5);
+
+    // Round-trips through the fixture exactly.
+    assert_eq!(
+        serde_json::to_value(&input).unwrap(),
+        serde_json::from_str::<serde_json::Value>(INPUT).unwrap()
+    );
+}
+
+#[test]
+fn new_input() {
+    let mut input =
+        StandardJsonInput::new([("src/Math.ylm".into(), Source::new("library Math {}"))]);
+    input
+        .settings
+        .link("src/Math.ylm:Math", cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"));
+    input.settings.link("Strings", cAddress!("ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"));
+    assert_eq!(input.settings.output_selection, Settings::default_output_selection());
+    assert_eq!(input.settings.libraries.len(), 2);
+    assert!(input.settings.libraries[""].contains_key("Strings"));
+
+    let json = serde_json::to_string(&input).unwrap();
+    assert!(json.contains(
+        r#""libraries":{"":{"Strings":"ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"},"src/Math.ylm":{"Math":"cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"}}"#
+    ));
+    assert_eq!(serde_json::from_str::<StandardJsonInput>(&json).unwrap(), input);
+}
+
+#[test]
+fn output() {
+    let output: StandardJsonOutput = serde_json::from_str(OUTPUT).unwrap();
+    assert!(output.has_errors());
+    assert_eq!(output.errors.len(), 3);
+    assert_eq!(output.diagnostics(Severity::Warning).count(), 1);
+    assert_eq!(output.diagnostics(Severity::Info).count(), 1);
+
+    let errors = output.diagnostics(Severity::Error).collect::<Vec<_>>();
+    let [error] = errors[..] else { panic!("{errors:?}") };
+    assert_eq!(error.ty, "DeclarationError");
+    assert_eq!(error.error_code.as_deref(), Some("2333"));
+    assert_eq!(
+        error.source_location,
+        Some(SourceLocation { file: "src/Token.ylm".into(), start: 210, end: 240 })
+    );
+    let secondary = &error.secondary_source_locations[0];
+    assert_eq!(secondary.message, "The previous declaration is here:");
+    assert_eq!(secondary.location.as_ref().unwrap().start, 98);
+    assert_eq!(
+        error.to_string(),
+        "DeclarationError (2333): Identifier already declared.\n \
+         --> src/Token.ylm:210..240\n\
+         The previous declaration is here:\n \
+         --> src/Token.ylm:98..120"
+    );
+
+    let warning = &output.errors[0];
+    assert!(!warning.is_error());
+    assert_eq!(warning.severity.to_string(), "warning");
+    assert!(warning
+        .to_string()
+        .starts_with("Warning: Unused local variable.\n --> src/Token.ylm:12:9:"));
+    assert!(!warning.to_string().ends_with('\n'));
+
+    let info = &output.errors[2];
+    assert_eq!(info.source_location, None);
+    assert_eq!(info.to_string(), "Info: Contract code size is close to the limit.");
+
+    assert_eq!(output.sources["src/Math.ylm"].id, 0);
+    assert!(output.sources["src/Math.ylm"].ast.is_some());
+    assert_eq!(output.sources["src/Token.ylm"].ast, None);
+
+    let token = output.find("Token").unwrap();
+    assert_eq!(output.find("src/Token.ylm:Token"), Some(token));
+    assert_eq!(output.find("src/Math.ylm:Token"), None);
+    assert_eq!(token.method_identifiers.len(), 2);
+    assert!(token.metadata.is_some());
+    assert!(output.find("Math").unwrap().bytecode.as_ref().unwrap().bytes().is_some());
+    assert_eq!(
+        output.contracts().map(|(name, _)| name).collect::<Vec<_>>(),
+        ["src/Math.ylm:Math", "src/Token.ylm:Token"]
+    );
+
+    let json = serde_json::to_string(&output).unwrap();
+    assert_eq!(serde_json::from_str::<StandardJsonOutput>(&json).unwrap(), output);
+}
+
+#[test]
+fn successful_output() {
+    let output: StandardJsonOutput = serde_json::from_str(
+        r#"{"errors":[{"component":"general","message":"m","severity":"warning","type":"Warning"}]}"#,
+    )
+    .unwrap();
+    assert!(!output.has_errors());
+    assert!(output.contracts.is_empty());
+
+    // Unknown severities are rejected.
+    let error = r#"{"component":"general","message":"m","severity":"fatal","type":"Error"}"#;
+    assert!(serde_json::from_str::<CompilerError>(error).is_err());
+}
@@ -1,23 +1,31 @@
-use crate::{aliases::U160, utils::sha3, FixedBytes, IcanAddress};
+use super::ican_address::ican_checksum;
+use crate::{aliases::U160, utils::sha3, FixedBytes, IcanAddress, Network};
 use core::{
     borrow::Borrow,
     fmt::{self, Display},
     str,
 };
-use ruint::aliases::U256;
 
-const MAINNET: u64 = 203;
-const DEVIN: u64 = 171;
-const PRIVATE: u64 = 206;
-
-/// Error type for address checksum validation.
+/// Error type for address checksum and network validation.
 #[derive(Debug, Copy, Clone)]
 pub enum AddressError {
     /// Error while decoding hex.
     Hex(hex::FromHexError),
 
-    /// Invalid ERC-55 checksum.
+    /// Invalid ICAN checksum.
     InvalidChecksum,
+
+    /// The network id is not known. See [`Network::register`].
+    UnknownNetwork(u64),
+
+    /// The ICAN prefix byte does not belong to any known network.
+    UnknownNetworkPrefix(u8),
+
+    /// A different network with the same id is already known.
+    NetworkAlreadyRegistered(u64),
+
+    /// A different network with the same ICAN prefix byte is already known.
+    NetworkPrefixAlreadyRegistered(u8),
 }
 
 impl From<hex::FromHexError> for AddressError {
@@ -33,7 +41,11 @@ impl std::error::Error for AddressError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::Hex(err) => Some(err),
-            Self::InvalidChecksum => None,
+            Self::InvalidChecksum
+            | Self::UnknownNetwork(_)
+            | Self::UnknownNetworkPrefix(_)
+            | Self::NetworkAlreadyRegistered(_)
+            | Self::NetworkPrefixAlreadyRegistered(_) => None,
         }
     }
 }
@@ -43,6 +55,16 @@ impl fmt::Display for AddressError {
         match self {
             Self::Hex(err) => err.fmt(f),
             Self::InvalidChecksum => f.write_str("Bad address checksum"),
+            Self::UnknownNetwork(id) => write!(f, "Unknown network id {id}"),
+            Self::UnknownNetworkPrefix(prefix) => {
+                write!(f, "Unknown network prefix {prefix:02x}")
+            }
+            Self::NetworkAlreadyRegistered(id) => {
+                write!(f, "A different network with id {id} is already registered")
+            }
+            Self::NetworkPrefixAlreadyRegistered(prefix) => {
+                write!(f, "A different network with prefix {prefix:02x} is already registered")
+            }
         }
     }
 }
@@ -121,6 +143,10 @@ impl Address {
 
     /// Encodes an Core address to Ican Address
     ///
+    /// Network ids that are not [known](Network::from_id) are encoded with the
+    /// [private network](Network::PRIVATE) prefix, like go-core does. Use
+    /// [`try_to_ican`](Self::try_to_ican) to reject them instead.
+    ///
     /// # Examples
     /// ```
     /// # use base_primitives::{address, Address};
@@ -135,31 +161,39 @@ impl Address {
     #[inline]
     #[must_use]
     pub fn to_ican(&self, network_id: u64) -> IcanAddress {
-        let network_prefix = match network_id {
-            1 => MAINNET,
-            3 => DEVIN,
-            _ => PRIVATE,
-        };
-        let network_prefix = U256::from(network_prefix);
+        let network = Network::from_id(network_id).unwrap_or(Network::PRIVATE);
+        self.to_ican_with(network)
+    }
 
-        let mut value = [0; 32];
-        value[12..].copy_from_slice(&self.0 .0);
-        let value_new = U256::from_be_bytes(value);
-        let value = (value_new << 16) + (network_prefix << 8);
-        let mut v = value;
-        let mut s = U256::from(0);
-        let mut x = U256::from(1);
-        for _ in 0..44 {
-            let t = v & U256::from(0x0f);
-            s += t * x;
-            x *= U256::from(10) + U256::from(90) * (t / U256::from(10));
-            v >>= 4;
-        }
-        s %= U256::from(97);
-        s = U256::from(98) - s;
-        s = (s % U256::from(10)) + (s / U256::from(10)) * U256::from(16);
-        let result: U256 = value_new + (s << 160) + (network_prefix << 168);
-        IcanAddress::from_word(result.into())
+    /// Encodes an Core address to Ican Address, returning an error if the
+    /// network id is not known.
+    ///
+    /// # Examples
+    /// ```
+    /// # use base_primitives::{address, Address, AddressError};
+    /// let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// assert_eq!(address.try_to_ican(1)?, address.to_ican(1));
+    /// assert!(address.try_to_ican(42).is_err());
+    /// # Ok::<_, AddressError>(())
+    /// ```
+    #[inline]
+    pub fn try_to_ican(&self, network_id: u64) -> Result<IcanAddress, AddressError> {
+        Network::try_from_id(network_id).map(|network| self.to_ican_with(network))
+    }
+
+    /// Encodes an Core address to Ican Address on the given network.
+    #[inline]
+    #[must_use]
+    pub fn to_ican_with(&self, network: Network) -> IcanAddress {
+        self.to_ican_with_prefix(network.prefix())
+    }
+
+    pub(crate) fn to_ican_with_prefix(self, prefix: u8) -> IcanAddress {
+        let mut bytes = [0; 22];
+        bytes[0] = prefix;
+        bytes[2..].copy_from_slice(self.as_slice());
+        bytes[1] = ican_checksum(&bytes);
+        IcanAddress::new(bytes)
     }
 
     /// Computes the `CREATE2` address
@@ -240,9 +274,18 @@ mod tests {
             let expected: IcanAddress = expected.parse().unwrap();
             let parsed = parsed.to_ican(network_id);
             assert_eq!(parsed, expected);
+            assert_eq!(parsed.try_network_id().unwrap(), network_id);
         }
     }
 
+    #[test]
+    fn unknown_network() {
+        let address = Address::repeat_byte(0x11);
+        assert!(matches!(address.try_to_ican(42), Err(AddressError::UnknownNetwork(42))));
+        assert_eq!(address.to_ican(42), address.to_ican_with(Network::PRIVATE));
+        assert_eq!(address.to_ican(42).as_slice()[0], 0xce);
+    }
+
     // #[test]
     // fn test_raw_public_key_to_address() {
     //     let addr = "0Ac1dF02185025F65202660F8167210A80dD5086".parse::<Address>().unwrap();
@@ -0,0 +1,64 @@
+use super::FixedBytes;
+use borsh::{io, BorshDeserialize, BorshSerialize};
+
+impl<const N: usize> BorshSerialize for FixedBytes<N> {
+    #[inline]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl<const N: usize> BorshDeserialize for FixedBytes<N> {
+    #[inline]
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        <[u8; N]>::deserialize_reader(reader).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cAddress, Bloom, IcanAddress};
+
+    macro_rules! test_encode_decode_borsh {
+        ($test_name:ident, $type:ty, [$( $value:expr ),*]) => {
+            #[test]
+            fn $test_name() {
+                $(
+                    let expected: $type = $value;
+                    let encoded = borsh::to_vec(&expected).unwrap();
+                    assert_eq!(encoded, expected.as_slice(), "Failed for value: {:?}", $value);
+                    let actual: $type = borsh::from_slice(&encoded).unwrap();
+                    assert_eq!(expected, actual, "Failed for value: {:?}", $value);
+                )*
+            }
+        };
+    }
+
+    test_encode_decode_borsh!(
+        test_encode_decode_fixed_bytes32,
+        FixedBytes<32>,
+        [fixed_bytes!("a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2")]
+    );
+
+    test_encode_decode_borsh!(
+        test_encode_decode_fixed_bytes4,
+        FixedBytes<4>,
+        [fixed_bytes!("01234567")]
+    );
+
+    test_encode_decode_borsh!(
+        test_encode_decode_ican_address,
+        IcanAddress,
+        [cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"), IcanAddress::ZERO]
+    );
+
+    test_encode_decode_borsh!(test_encode_decode_bloom, Bloom, [Bloom::repeat_byte(0x42)]);
+
+    #[test]
+    fn test_decode_wrong_length() {
+        assert!(borsh::from_slice::<FixedBytes<4>>(&[1, 2, 3]).is_err());
+        assert!(borsh::from_slice::<FixedBytes<4>>(&[1, 2, 3, 4, 5]).is_err());
+        assert!(borsh::from_slice::<IcanAddress>(&[0; 21]).is_err());
+    }
+}
@@ -1,17 +1,17 @@
-use crate::{Address, FixedBytes, Selector};
+use crate::{FixedBytes, IcanAddress, Selector};
 use core::borrow::Borrow;
 
 wrap_fixed_bytes! {
-    /// An Core ABI function pointer, 24 bytes in length.
+    /// An Core ABI function pointer, 26 bytes in length.
     ///
-    /// An address (20 bytes), followed by a function selector (4 bytes).
-    /// Encoded identical to `bytes24`.
-    pub struct Function<24>;
+    /// An ICAN address (22 bytes), followed by a function selector (4 bytes).
+    /// Encoded identical to `bytes26`.
+    pub struct Function<26>;
 }
 
 impl<A, S> From<(A, S)> for Function
 where
-    A: Borrow<[u8; 20]>,
+    A: Borrow<[u8; 22]>,
     S: Borrow<[u8; 4]>,
 {
     #[inline]
@@ -21,26 +21,26 @@ where
 }
 
 impl Function {
-    /// Creates an Core function from an EVM word's lower 24 bytes
-    /// (`word[..24]`).
+    /// Creates an Core function from an EVM word's lower 26 bytes
+    /// (`word[..26]`).
     ///
-    /// Note that this is different from `Address::from_word`, which uses the
-    /// upper 20 bytes.
+    /// Note that this is different from `IcanAddress::from_word`, which uses
+    /// the upper 22 bytes.
     #[inline]
     #[must_use]
     pub fn from_word(word: FixedBytes<32>) -> Self {
-        Self(FixedBytes(word[..24].try_into().unwrap()))
+        Self(FixedBytes(word[..26].try_into().unwrap()))
     }
 
     /// Right-pads the function to 32 bytes (EVM word size).
     ///
-    /// Note that this is different from `Address::into_word`, which left-pads
-    /// the address.
+    /// Note that this is different from `IcanAddress::into_word`, which
+    /// left-pads the address.
     #[inline]
     #[must_use]
     pub fn into_word(&self) -> FixedBytes<32> {
         let mut word = [0; 32];
-        word[..24].copy_from_slice(self.as_slice());
+        word[..26].copy_from_slice(self.as_slice());
         FixedBytes(word)
     }
 
@@ -48,25 +48,25 @@ impl Function {
     #[inline]
     pub fn from_address_and_selector<A, S>(address: A, selector: S) -> Self
     where
-        A: Borrow<[u8; 20]>,
+        A: Borrow<[u8; 22]>,
         S: Borrow<[u8; 4]>,
     {
-        let mut bytes = [0; 24];
-        bytes[..20].copy_from_slice(address.borrow());
-        bytes[20..].copy_from_slice(selector.borrow());
+        let mut bytes = [0; 26];
+        bytes[..22].copy_from_slice(address.borrow());
+        bytes[22..].copy_from_slice(selector.borrow());
         Self(FixedBytes(bytes))
     }
 
     /// Returns references to the address and selector of the function.
     #[inline]
-    pub fn as_address_and_selector(&self) -> (&Address, &Selector) {
-        // SAFETY: Function (24) = Address (20) + Selector (4)
-        unsafe { (&*self.as_ptr().cast(), &*self.as_ptr().add(20).cast()) }
+    pub fn as_address_and_selector(&self) -> (&IcanAddress, &Selector) {
+        // SAFETY: Function (26) = IcanAddress (22) + Selector (4)
+        unsafe { (&*self.as_ptr().cast(), &*self.as_ptr().add(22).cast()) }
     }
 
     /// Returns the address and selector of the function.
     #[inline]
-    pub fn to_address_and_selector(&self) -> (Address, Selector) {
+    pub fn to_address_and_selector(&self) -> (IcanAddress, Selector) {
         let (a, s) = self.as_address_and_selector();
         (*a, *s)
     }
@@ -81,17 +81,21 @@ mod tests {
     fn function_parts() {
         let f = Function::new(hex!(
             "
-            ffffffffffffffffffffffffffffffffffffffff
+            cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5
             12345678
         "
         ));
 
         let (a1, s1) = f.as_address_and_selector();
-        assert_eq!(a1, hex!("ffffffffffffffffffffffffffffffffffffffff"));
+        assert_eq!(a1, hex!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"));
         assert_eq!(s1, &hex!("12345678"));
 
         let (a2, s2) = f.to_address_and_selector();
         assert_eq!(a2, *a1);
         assert_eq!(s2, *s1);
+
+        assert_eq!(Function::from((a2, s2)), f);
+        assert_eq!(Function::from_word(f.into_word()), f);
+        assert_eq!(f.into_word()[26..], [0; 6]);
     }
 }
@@ -1,12 +1,14 @@
-use crate::{sha3, Address, FixedBytes};
-use core::{borrow::Borrow, fmt, panic, str};
+use crate::{sha3, Address, AddressError, FixedBytes, Network};
+use core::{borrow::Borrow, fmt, str};
+use hex::FromHex;
 use libgoldilocks::{SigningKey, VerifyingKey};
 use ruint::aliases::U176;
 
 #[cfg(feature = "serde")]
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 wrap_fixed_bytes!(
+    serde: custom,
     extra_derives: [],
     pub struct IcanAddress<22>;
 );
@@ -38,6 +40,55 @@ impl fmt::Display for IcanAddress {
     }
 }
 
+/// The ways an [`IcanAddress`] can be shown to users.
+///
+/// See [`IcanAddress::display`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum IcanFormat {
+    /// Lowercase hex, the same as [`Display`](fmt::Display):
+    /// `cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5`.
+    #[default]
+    Plain,
+    /// Uppercase hex: `CB82A5FD22B9BEE8B8AB877C86E0A2C21765E1D5BFC5`.
+    Uppercase,
+    /// Uppercase hex in groups of four, like IBANs are printed:
+    /// `CB82 A5FD 22B9 BEE8 B8AB 877C 86E0 A2C2 1765 E1D5 BFC5`.
+    Grouped,
+    /// Middle-out compression, the same as `{:#}`: `cb82a5…bfc5`.
+    ///
+    /// This form cannot be parsed back into an address.
+    Short,
+}
+
+/// Helper type returned by [`IcanAddress::display`] that formats an address
+/// with the chosen [`IcanFormat`].
+#[derive(Clone, Copy, Debug)]
+pub struct IcanDisplay<'a> {
+    address: &'a IcanAddress,
+    format: IcanFormat,
+}
+
+impl fmt::Display for IcanDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.format {
+            IcanFormat::Plain => write!(f, "{}", self.address),
+            IcanFormat::Short => write!(f, "{:#}", self.address),
+            IcanFormat::Uppercase => {
+                self.address.iter().try_for_each(|byte| write!(f, "{byte:02X}"))
+            }
+            IcanFormat::Grouped => {
+                for (i, group) in self.address.chunks(2).enumerate() {
+                    if i != 0 {
+                        f.write_str(" ")?;
+                    }
+                    write!(f, "{:02X}{:02X}", group[0], group[1])?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
 impl IcanAddress {
     /// Creates an Core address from an EVM word's upper 20 bytes
     /// (`word[12..]`).
@@ -103,12 +154,12 @@ impl IcanAddress {
     ///
     /// ```
     /// # use base_primitives::{cAddress, IcanAddress};
-    /// let sender = cAddress!("cb00b20a608c624Ca5003905aA834De7156C68b2E1d0");
+    /// let sender = cAddress!("cb93b20a608c624Ca5003905aA834De7156C68b2E1d0");
     ///
-    /// let expected = cAddress!("cb13e6ff992542059347e59e8e393af8adefa71fd4e6");
+    /// let expected = cAddress!("cb81ac2317d1c154c8f8f162f19b1acc92aa5751230e");
     /// assert_eq!(sender.create(0), expected);
     ///
-    /// let expected = cAddress!("cb21b71cb5f6596d0f00925879048271562115bf9e84");
+    /// let expected = cAddress!("cb60ed5352aab7baf8c4a93a36024efd2e3693328c29");
     /// assert_eq!(sender.create(1), expected);
     /// ```
     #[cfg(feature = "rlp")]
@@ -139,7 +190,7 @@ impl IcanAddress {
         nonce.encode(&mut &mut out[24..]);
 
         let hash = sha3(&out[..len]);
-        Address::from_word(hash).to_ican(self.network_id())
+        Address::from_word(hash).to_ican_with_prefix(self.prefix())
     }
 
     /// Computes the `CREATE2` address of a smart contract as specified in
@@ -214,16 +265,156 @@ impl IcanAddress {
         bytes[23..55].copy_from_slice(salt);
         bytes[55..87].copy_from_slice(init_code_hash);
         let hash = sha3(bytes);
-        Address::from_word(hash).to_ican(self.network_id())
+        Address::from_word(hash).to_ican_with_prefix(self.prefix())
+    }
+
+    /// Parses an ICAN address from a hex string, with or without the `0x`
+    /// prefix, and [validates](Self::validate) its checksum and network.
+    ///
+    /// Unlike [`FromStr`](core::str::FromStr), which only checks the length and
+    /// the hex encoding, this rejects mistyped addresses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use base_primitives::{AddressError, IcanAddress};
+    /// let address = IcanAddress::parse_checked("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5")?;
+    /// assert_eq!(address.network_id(), 1);
+    ///
+    /// // last digit changed
+    /// let err = IcanAddress::parse_checked("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc4");
+    /// assert!(matches!(err, Err(AddressError::InvalidChecksum)));
+    /// # Ok::<_, AddressError>(())
+    /// ```
+    pub fn parse_checked(s: &str) -> Result<Self, AddressError> {
+        let address = Self::from_hex(s)?;
+        address.validate()?;
+        Ok(address)
+    }
+
+    /// Parses an ICAN address as entered or copied by a user, and
+    /// [validates](Self::validate) it.
+    ///
+    /// All whitespace is ignored and hex digits may be in any case, so every
+    /// [`IcanFormat`] except [`Short`](IcanFormat::Short) is accepted, as well
+    /// as an optional `0x` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use base_primitives::{cAddress, AddressError, IcanAddress};
+    /// let expected = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+    /// for s in [
+    ///     "cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5",
+    ///     "CB82A5FD22B9BEE8B8AB877C86E0A2C21765E1D5BFC5",
+    ///     "CB82 A5FD 22B9 BEE8 B8AB 877C 86E0 A2C2 1765 E1D5 BFC5",
+    ///     " cb82 a5fd\t22b9 bee8\nb8ab 877c 86e0 a2c2 1765 e1d5 bfc5 ",
+    /// ] {
+    ///     assert_eq!(IcanAddress::parse_formatted(s)?, expected);
+    /// }
+    /// # Ok::<_, AddressError>(())
+    /// ```
+    pub fn parse_formatted(s: &str) -> Result<Self, AddressError> {
+        let address = Self::from_formatted(s)?;
+        address.validate()?;
+        Ok(address)
+    }
+
+    /// Parses an address like [`parse_formatted`](Self::parse_formatted),
+    /// without validating it.
+    fn from_formatted(s: &str) -> Result<Self, AddressError> {
+        // "0x" + 44 hex digits
+        let mut buf = [0u8; 46];
+        let mut len = 0;
+        for (index, c) in s.char_indices().filter(|(_, c)| !c.is_whitespace()) {
+            if !c.is_ascii() {
+                return Err(hex::FromHexError::InvalidHexCharacter { c, index }.into());
+            }
+            if len == buf.len() {
+                return Err(hex::FromHexError::InvalidStringLength.into());
+            }
+            buf[len] = c as u8;
+            len += 1;
+        }
+        Self::from_hex(&buf[..len]).map_err(Into::into)
+    }
+
+    /// Returns an object that formats the address with the given
+    /// [`IcanFormat`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use base_primitives::{cAddress, IcanFormat};
+    /// let address = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+    /// assert_eq!(
+    ///     address.display(IcanFormat::Grouped).to_string(),
+    ///     "CB82 A5FD 22B9 BEE8 B8AB 877C 86E0 A2C2 1765 E1D5 BFC5"
+    /// );
+    /// assert_eq!(
+    ///     address.display(IcanFormat::Uppercase).to_string(),
+    ///     "CB82A5FD22B9BEE8B8AB877C86E0A2C21765E1D5BFC5"
+    /// );
+    /// assert_eq!(address.display(IcanFormat::Short).to_string(), "cb82a5…bfc5");
+    /// ```
+    #[inline]
+    pub const fn display(&self, format: IcanFormat) -> IcanDisplay<'_> {
+        IcanDisplay { address: self, format }
+    }
+
+    /// Validates the checksum digits and the network prefix of the address.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AddressError::InvalidChecksum`] if the checksum digits do not
+    /// match the rest of the address, and
+    /// [`AddressError::UnknownNetworkPrefix`] if the prefix does not belong to
+    /// a [known network](Network).
+    pub fn validate(&self) -> Result<(), AddressError> {
+        if !self.has_valid_checksum() {
+            return Err(AddressError::InvalidChecksum);
+        }
+        self.network().map(drop)
+    }
+
+    /// Returns `true` if the checksum digits match the prefix and the address,
+    /// regardless of whether the prefix belongs to a known network.
+    #[inline]
+    pub const fn has_valid_checksum(&self) -> bool {
+        ican_checksum(&self.0 .0) == self.0 .0[1]
+    }
+
+    /// Returns the ICAN prefix byte of the address, which identifies its
+    /// network.
+    #[inline]
+    pub const fn prefix(&self) -> u8 {
+        self.0 .0[0]
+    }
+
+    /// Returns the [`Network`] this address belongs to, based on its prefix.
+    #[inline]
+    pub fn network(&self) -> Result<Network, AddressError> {
+        Network::try_from_prefix(self.prefix())
+    }
+
+    /// Gets the network_id from the address.
+    #[inline]
+    pub fn try_network_id(&self) -> Result<u64, AddressError> {
+        self.network().map(|network| network.id())
     }
 
-    /// Gets the network_id from the address
+    /// Gets the network_id from the address.
+    ///
+    /// # Panics
+    ///
+    /// If the prefix does not belong to a known network. Use
+    /// [`try_network_id`](Self::try_network_id) for a fallible version.
+    #[inline]
+    #[track_caller]
     pub fn network_id(&self) -> u64 {
-        match self.0 .0[0] {
-            203 => 1,
-            171 => 3,
-            206 => 1337,
-            _ => panic!("Invalid Checksum Address"),
+        match self.try_network_id() {
+            Ok(id) => id,
+            Err(e) => panic!("{e}"),
         }
     }
 
@@ -252,6 +443,229 @@ impl IcanAddress {
         Self::from_public_key(private_key.verifying_key(), network_id)
     }
 }
+
+/// Serializes the address as plain lowercase hex without the `0x` prefix, the
+/// same as go-core. Use the adapters in [`serde_ican`] to choose another form.
+///
+/// Deserialization accepts every form the adapters produce, and rejects
+/// addresses with invalid checksum digits. See [`serde_ican`] for details.
+#[cfg(feature = "serde")]
+impl Serialize for IcanAddress {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_ican::plain::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for IcanAddress {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_ican::deserialize(deserializer)
+    }
+}
+
+/// Serde adapters that choose how an [`IcanAddress`] field is serialized, for
+/// use with `#[serde(with = "...")]`.
+///
+/// In human-readable formats, each adapter writes the address in its own form
+/// and all of them read any of these forms back: with or without the `0x`
+/// prefix, in any case, and with whitespace between the digits. Other formats
+/// use the raw 22 bytes.
+///
+/// The checksum digits of deserialized addresses are
+/// [validated](IcanAddress::has_valid_checksum), except for the zero address,
+/// which is used as a placeholder. The network prefix is not checked, so that
+/// addresses of networks unknown to this crate can still be read.
+///
+/// # Examples
+///
+/// ```
+/// use base_primitives::{cAddress, IcanAddress};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Transfer {
+///     from: IcanAddress,
+///     #[serde(with = "base_primitives::serde_ican::prefixed")]
+///     to: IcanAddress,
+/// }
+///
+/// let transfer = Transfer {
+///     from: cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"),
+///     to: cAddress!("ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"),
+/// };
+/// let json = serde_json::to_string(&transfer)?;
+/// assert_eq!(
+///     json,
+///     r#"{"from":"cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5","to":"0xab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"}"#
+/// );
+/// assert_eq!(serde_json::from_str::<Transfer>(&json)?, transfer);
+///
+/// // The last digit of the checksum is wrong.
+/// let json = r#"{"from":"cb83a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5","to":"0xab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"}"#;
+/// assert!(serde_json::from_str::<Transfer>(json).is_err());
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_ican {
+    use super::{IcanAddress, IcanFormat};
+    use core::fmt;
+    use serde::{de, Deserializer, Serializer};
+
+    /// Serializes as plain lowercase hex: `cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5`.
+    ///
+    /// This is the default form.
+    pub mod plain {
+        use super::*;
+
+        pub use super::deserialize;
+
+        /// Serializes the address as plain lowercase hex.
+        #[inline]
+        pub fn serialize<S: Serializer>(
+            address: &IcanAddress,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serialize_with(address, serializer, address.display(IcanFormat::Plain))
+        }
+    }
+
+    /// Serializes as `0x`-prefixed lowercase hex:
+    /// `0xcb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5`.
+    pub mod prefixed {
+        use super::*;
+
+        pub use super::deserialize;
+
+        /// Serializes the address as `0x`-prefixed lowercase hex.
+        #[inline]
+        pub fn serialize<S: Serializer>(
+            address: &IcanAddress,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serialize_with(address, serializer, format_args!("0x{address}"))
+        }
+    }
+
+    /// Serializes as uppercase hex: `CB82A5FD22B9BEE8B8AB877C86E0A2C21765E1D5BFC5`.
+    pub mod uppercase {
+        use super::*;
+
+        pub use super::deserialize;
+
+        /// Serializes the address as uppercase hex.
+        #[inline]
+        pub fn serialize<S: Serializer>(
+            address: &IcanAddress,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serialize_with(address, serializer, address.display(IcanFormat::Uppercase))
+        }
+    }
+
+    /// Serializes as uppercase hex in groups of four:
+    /// `CB82 A5FD 22B9 BEE8 B8AB 877C 86E0 A2C2 1765 E1D5 BFC5`.
+    pub mod grouped {
+        use super::*;
+
+        pub use super::deserialize;
+
+        /// Serializes the address as uppercase hex in groups of four.
+        #[inline]
+        pub fn serialize<S: Serializer>(
+            address: &IcanAddress,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serialize_with(address, serializer, address.display(IcanFormat::Grouped))
+        }
+    }
+
+    fn serialize_with<S: Serializer>(
+        address: &IcanAddress,
+        serializer: S,
+        s: impl fmt::Display,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&s)
+        } else {
+            serializer.serialize_bytes(address.as_slice())
+        }
+    }
+
+    /// Deserializes an address in any of the forms above, and validates its
+    /// checksum.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<IcanAddress, D::Error> {
+        struct IcanVisitor;
+
+        impl<'de> de::Visitor<'de> for IcanVisitor {
+            type Value = IcanAddress;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("an ICAN address with valid checksum digits")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                IcanAddress::from_formatted(v).and_then(check).map_err(E::custom)
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                let address = IcanAddress::try_from(v)
+                    .map_err(|_| E::invalid_length(v.len(), &"exactly 22 bytes"))?;
+                check(address).map_err(E::custom)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(IcanVisitor)
+        } else {
+            deserializer.deserialize_bytes(IcanVisitor)
+        }
+    }
+
+    fn check(address: IcanAddress) -> Result<IcanAddress, super::AddressError> {
+        if address.is_zero() || address.has_valid_checksum() {
+            Ok(address)
+        } else {
+            Err(super::AddressError::InvalidChecksum)
+        }
+    }
+}
+
+/// Computes the ICAN checksum of `bytes`, ignoring the current checksum byte.
+///
+/// The address is read as the number `address ++ prefix ++ 00`, one nibble at
+/// a time starting from the least significant one, where letters count as two
+/// decimal digits like in IBAN. The checksum is `98 - (number mod 97)`, stored
+/// as two decimal digits.
+pub(crate) const fn ican_checksum(bytes: &[u8; 22]) -> u8 {
+    let mut s = 0u64;
+    let mut x = 1u64;
+    let mut i = 0;
+    while i < 44 {
+        let t = match i {
+            0 | 1 => 0,
+            2 => bytes[0] & 0x0f,
+            3 => bytes[0] >> 4,
+            _ => {
+                let byte = bytes[21 - (i - 4) / 2];
+                if i % 2 == 0 {
+                    byte & 0x0f
+                } else {
+                    byte >> 4
+                }
+            }
+        } as u64;
+        s = (s + t * x) % 97;
+        x = x * if t < 10 { 10 } else { 100 } % 97;
+        i += 1;
+    }
+    let s = 98 - s;
+    (((s / 10) << 4) | (s % 10)) as u8
+}
+
 #[cfg(test)]
 mod tests {
     use hex::FromHex;
@@ -332,6 +746,177 @@ mod tests {
         }
     }
 
+    #[test]
+    fn network() {
+        let mainnet =
+            "cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5".parse::<IcanAddress>().unwrap();
+        assert_eq!(mainnet.network().unwrap(), Network::MAINNET);
+        assert_eq!(mainnet.try_network_id().unwrap(), 1);
+        assert_eq!(mainnet.network_id(), 1);
+
+        let devin = "ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5".parse::<IcanAddress>().unwrap();
+        assert_eq!(devin.try_network_id().unwrap(), 3);
+
+        let unknown =
+            "0000a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5".parse::<IcanAddress>().unwrap();
+        assert!(matches!(unknown.try_network_id(), Err(AddressError::UnknownNetworkPrefix(0))));
+
+        // contract addresses keep the sender's prefix, even if it is unknown
+        assert_eq!(unknown.create2([0; 32], [0; 32]).prefix(), 0);
+        #[cfg(feature = "rlp")]
+        assert_eq!(unknown.create(0).prefix(), 0);
+        assert_eq!(devin.create2([0; 32], [0; 32]).prefix(), 0xab);
+    }
+
+    #[test]
+    fn parse_checked() {
+        for s in [
+            "cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5",
+            "0xcb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5",
+            "ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5",
+            "cb88632ed69c17d318372233bcbac849317f4de784e2",
+            "ab792215c43fc213c02182c8389f2bc32408e2c50922",
+        ] {
+            let address = IcanAddress::parse_checked(s).unwrap();
+            assert!(address.has_valid_checksum());
+            assert_eq!(address, s.parse::<IcanAddress>().unwrap());
+            assert_eq!(address.to_address().to_ican(address.network_id()), address);
+        }
+
+        for s in [
+            // checksum digits changed
+            "cb83a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5",
+            // address changed
+            "cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc4",
+            // prefix changed
+            "ab82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5",
+        ] {
+            assert!(matches!(IcanAddress::parse_checked(s), Err(AddressError::InvalidChecksum)));
+        }
+
+        // valid checksum, unknown network
+        assert!(matches!(
+            IcanAddress::parse_checked("0041a48388222c7ee7daefde5d0b9c99319995c4a990"),
+            Err(AddressError::UnknownNetworkPrefix(0))
+        ));
+        assert!(matches!(
+            IcanAddress::parse_checked("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bf"),
+            Err(AddressError::Hex(_))
+        ));
+    }
+
+    #[test]
+    fn formats() {
+        let address =
+            IcanAddress::parse_checked("ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5").unwrap();
+        let formats = [
+            (IcanFormat::Plain, "ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"),
+            (IcanFormat::Uppercase, "AB03A5FD22B9BEE8B8AB877C86E0A2C21765E1D5BFC5"),
+            (IcanFormat::Grouped, "AB03 A5FD 22B9 BEE8 B8AB 877C 86E0 A2C2 1765 E1D5 BFC5"),
+            (IcanFormat::Short, "ab03a5…bfc5"),
+        ];
+        for (format, expected) in formats {
+            let s = address.display(format).to_string();
+            assert_eq!(s, expected);
+            if format != IcanFormat::Short {
+                assert_eq!(IcanAddress::parse_formatted(&s).unwrap(), address);
+            }
+        }
+
+        assert_eq!(
+            IcanAddress::parse_formatted(
+                "0x AB03 A5FD 22B9 BEE8 B8AB 877C 86E0 A2C2 1765 E1D5 BFC5"
+            )
+            .unwrap(),
+            address
+        );
+        assert!(matches!(
+            IcanAddress::parse_formatted("AB04 A5FD 22B9 BEE8 B8AB 877C 86E0 A2C2 1765 E1D5 BFC5"),
+            Err(AddressError::InvalidChecksum)
+        ));
+        assert!(matches!(
+            IcanAddress::parse_formatted(
+                "AB03 A5FD 22B9 BEE8 B8AB 877C 86E0 A2C2 1765 E1D5 BFC5 00"
+            ),
+            Err(AddressError::Hex(hex::FromHexError::InvalidStringLength))
+        ));
+        assert!(matches!(
+            IcanAddress::parse_formatted("AB03 A5FD 22B9 BEE8 B8AB 877C 86E0 A2C2 1765 E1D5 BFC…"),
+            Err(AddressError::Hex(hex::FromHexError::InvalidHexCharacter { c: '…', .. }))
+        ));
+        assert!(IcanAddress::parse_formatted("ab03a5…bfc5").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Fields {
+            default: IcanAddress,
+            #[serde(with = "serde_ican::prefixed")]
+            prefixed: IcanAddress,
+            #[serde(with = "serde_ican::uppercase")]
+            uppercase: IcanAddress,
+            #[serde(with = "serde_ican::grouped")]
+            grouped: IcanAddress,
+        }
+
+        let address =
+            IcanAddress::parse_checked("ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5").unwrap();
+        let fields =
+            Fields { default: address, prefixed: address, uppercase: address, grouped: address };
+        let json = serde_json::to_value(&fields).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "default": "ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5",
+                "prefixed": "0xab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5",
+                "uppercase": "AB03A5FD22B9BEE8B8AB877C86E0A2C21765E1D5BFC5",
+                "grouped": "AB03 A5FD 22B9 BEE8 B8AB 877C 86E0 A2C2 1765 E1D5 BFC5",
+            })
+        );
+        assert_eq!(serde_json::from_value::<Fields>(json).unwrap(), fields);
+
+        // Every field accepts every form.
+        for s in [
+            "ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5",
+            "0xab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5",
+            "0XAB03A5FD22B9BEE8B8AB877C86E0A2C21765E1D5BFC5",
+            "AB03 A5FD 22B9 BEE8 B8AB 877C 86E0 A2C2 1765 E1D5 BFC5",
+        ] {
+            let json = serde_json::json!({
+                "default": s,
+                "prefixed": s,
+                "uppercase": s,
+                "grouped": s,
+            });
+            assert_eq!(serde_json::from_value::<Fields>(json).unwrap(), fields, "{s}");
+        }
+
+        // The checksum is validated, but the network is not.
+        let err = serde_json::from_value::<IcanAddress>(serde_json::json!(
+            "ab00a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"
+        ))
+        .unwrap_err();
+        assert_eq!(err.to_string(), AddressError::InvalidChecksum.to_string());
+        let unknown =
+            IcanAddress::from_hex("4246a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5").unwrap();
+        assert!(unknown.has_valid_checksum());
+        assert_eq!(
+            serde_json::from_value::<IcanAddress>(serde_json::json!(unknown)).unwrap(),
+            unknown
+        );
+
+        // The zero address is accepted as is.
+        let zero = serde_json::json!("0x00000000000000000000000000000000000000000000");
+        assert_eq!(serde_json::from_value::<IcanAddress>(zero).unwrap(), IcanAddress::ZERO);
+
+        // Binary formats use the raw bytes.
+        let bin = bincode::serialize(&address).unwrap();
+        assert_eq!(bin[bin.len() - 22..], address[..]);
+        assert_eq!(bincode::deserialize::<IcanAddress>(&bin).unwrap(), address);
+    }
+
     #[test]
     fn from_raw_public_key() {
         let pubkey = hex::decode("315484db568379ce94f9c894e3e6e4c7ee216676b713ca892d9b26746ae902a772e217a6a8bb493ce2bb313cf0cb66e76765d4c45ec6b68600").unwrap();
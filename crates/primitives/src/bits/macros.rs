@@ -4,6 +4,9 @@
 /// This functionally creates a new named `FixedBytes` that cannot be
 /// type-confused for another named `FixedBytes`.
 ///
+/// Pass `serde: custom,` before `extra_derives` to skip the serde
+/// implementations, which are otherwise delegated to `FixedBytes`.
+///
 /// # Examples
 ///
 /// ```
@@ -28,6 +31,7 @@ macro_rules! wrap_fixed_bytes {
     };
 
     (
+        $(serde: $serde:ident,)?
         extra_derives: [$($extra_derives:path),* $(,)?],
         $(#[$attrs:meta])*
         $vis:vis struct $name:ident<$n:literal>;
@@ -176,10 +180,12 @@ macro_rules! wrap_fixed_bytes {
 
         $crate::impl_fb_traits!($name, $n);
         $crate::impl_rlp!($name, $n);
-        $crate::impl_serde!($name);
+        $crate::impl_serde!($name $(, $serde)?);
         $crate::impl_allocative!($name);
         $crate::impl_arbitrary!($name, $n);
         $crate::impl_ssz_fixed_len!($name, $n);
+        $crate::impl_borsh!($name);
+        $crate::impl_scale!($name, $n);
         $crate::impl_rand!($name);
 
         impl $name {
@@ -586,6 +592,9 @@ macro_rules! impl_allocative {
 #[macro_export]
 #[cfg(feature = "serde")]
 macro_rules! impl_serde {
+    // The type implements serde itself.
+    ($t:ty, custom) => {};
+
     ($t:ty) => {
         impl $crate::private::serde::Serialize for $t {
             #[inline]
@@ -609,7 +618,7 @@ macro_rules! impl_serde {
 #[macro_export]
 #[cfg(not(feature = "serde"))]
 macro_rules! impl_serde {
-    ($t:ty) => {};
+    ($t:ty $(, $serde:ident)?) => {};
 }
 
 #[doc(hidden)]
@@ -724,6 +733,98 @@ macro_rules! impl_ssz_fixed_len {
     ($t:ty, $n:literal) => {};
 }
 
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "borsh")]
+macro_rules! impl_borsh {
+    ($t:ty) => {
+        impl $crate::private::borsh::BorshSerialize for $t {
+            #[inline]
+            fn serialize<W: $crate::private::borsh::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> $crate::private::borsh::io::Result<()> {
+                $crate::private::borsh::BorshSerialize::serialize(&self.0, writer)
+            }
+        }
+
+        impl $crate::private::borsh::BorshDeserialize for $t {
+            #[inline]
+            fn deserialize_reader<R: $crate::private::borsh::io::Read>(
+                reader: &mut R,
+            ) -> $crate::private::borsh::io::Result<Self> {
+                $crate::private::borsh::BorshDeserialize::deserialize_reader(reader).map(Self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "borsh"))]
+macro_rules! impl_borsh {
+    ($t:ty) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "scale")]
+macro_rules! impl_scale {
+    ($t:ty, $n:literal) => {
+        impl $crate::private::parity_scale_codec::Encode for $t {
+            #[inline]
+            fn size_hint(&self) -> usize {
+                $n
+            }
+
+            #[inline]
+            fn encode_to<O: $crate::private::parity_scale_codec::Output + ?Sized>(
+                &self,
+                dest: &mut O,
+            ) {
+                $crate::private::parity_scale_codec::Encode::encode_to(&self.0, dest)
+            }
+
+            #[inline]
+            fn encoded_size(&self) -> usize {
+                $n
+            }
+        }
+
+        impl $crate::private::parity_scale_codec::EncodeLike for $t {}
+
+        impl $crate::private::parity_scale_codec::Decode for $t {
+            #[inline]
+            fn decode<I: $crate::private::parity_scale_codec::Input>(
+                input: &mut I,
+            ) -> Result<Self, $crate::private::parity_scale_codec::Error> {
+                $crate::private::parity_scale_codec::Decode::decode(input).map(Self)
+            }
+
+            #[inline]
+            fn encoded_fixed_size() -> Option<usize> {
+                Some($n)
+            }
+        }
+
+        impl $crate::private::parity_scale_codec::MaxEncodedLen for $t {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                $n
+            }
+        }
+
+        impl $crate::private::parity_scale_codec::ConstEncodedLen for $t {}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "scale"))]
+macro_rules! impl_scale {
+    ($t:ty, $n:literal) => {};
+}
+
 macro_rules! fixed_bytes_macros {
     ($d:tt $($(#[$attr:meta])* macro $name:ident($ty:ident $($rest:tt)*);)*) => {$(
         /// Converts a sequence of string literals containing hex-encoded data
@@ -765,7 +866,6 @@ macro_rules! fixed_bytes_macros {
 
 fixed_bytes_macros! { $
     macro address(Address);
-    macro cAddress(IcanAddress);
 
     macro b64(B64);
 
@@ -782,6 +882,51 @@ fixed_bytes_macros! { $
     macro fixed_bytes(FixedBytes<0>); // <0> is just for the doctest
 }
 
+/// Converts a sequence of string literals containing hex-encoded data into a
+/// new [`IcanAddress`][crate::IcanAddress] at compile time.
+///
+/// If the input is empty, a zero-initialized address is returned.
+///
+/// Note that the strings cannot be prefixed with `0x`.
+///
+/// The checksum digits of the address are verified at compile time; the
+/// network prefix is not, as custom networks are only known at runtime. See
+/// [`IcanAddress::validate`](crate::IcanAddress::validate).
+///
+/// See [`hex!`](crate::hex!) for more information.
+///
+/// # Examples
+///
+/// ```
+/// use base_primitives::{cAddress, IcanAddress};
+///
+/// const ZERO: IcanAddress = cAddress!();
+/// assert_eq!(ZERO, IcanAddress::ZERO);
+///
+/// const ADDRESS: IcanAddress = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+/// assert_eq!(ADDRESS.network_id(), 1);
+/// ```
+///
+/// Addresses with invalid checksums do not compile:
+///
+/// ```compile_fail
+/// use base_primitives::{cAddress, IcanAddress};
+///
+/// const ADDRESS: IcanAddress = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc4");
+/// ```
+#[macro_export]
+macro_rules! cAddress {
+    () => {
+        $crate::IcanAddress::ZERO
+    };
+
+    ($($s:literal)+) => {{
+        const ADDRESS: $crate::IcanAddress = $crate::IcanAddress::new($crate::hex!($($s)+));
+        const _: () = assert!(ADDRESS.has_valid_checksum(), "invalid ICAN address checksum");
+        ADDRESS
+    }};
+}
+
 /// Converts a sequence of string literals containing hex-encoded data into a
 /// new [`Bytes`][crate::Bytes] at compile time.
 ///
@@ -7,7 +7,12 @@ mod address;
 pub use address::{Address, AddressError};
 
 mod ican_address;
-pub use ican_address::IcanAddress;
+#[cfg(feature = "serde")]
+pub use ican_address::serde_ican;
+pub use ican_address::{IcanAddress, IcanDisplay, IcanFormat};
+
+mod network;
+pub use network::Network;
 
 mod bloom;
 pub use bloom::{Bloom, BloomInput, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES};
@@ -26,3 +31,9 @@ mod serde;
 
 #[cfg(feature = "ssz")]
 mod ssz;
+
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "scale")]
+mod scale;
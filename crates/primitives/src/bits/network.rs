@@ -0,0 +1,205 @@
+use super::AddressError;
+
+/// The networks that are always known, regardless of the registry contents.
+const BUILTIN: [Network; 3] = [Network::MAINNET, Network::DEVIN, Network::PRIVATE];
+
+/// A Core network, identified by its network id and the ICAN prefix byte that
+/// addresses on this network start with.
+///
+/// The built-in networks are [mainnet](Network::MAINNET), [devin](Network::DEVIN)
+/// and the default [private network](Network::PRIVATE). Additional networks
+/// can be made known to the crate with [`register`](Network::register), after
+/// which address parsing, conversion and `CREATE`/`CREATE2` computation will
+/// recognize them.
+///
+/// # Examples
+///
+/// ```
+/// use base_primitives::Network;
+///
+/// assert_eq!(Network::from_id(1), Some(Network::MAINNET));
+/// assert_eq!(Network::from_prefix(0xab), Some(Network::DEVIN));
+/// assert!(Network::try_from_prefix(0x42).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Network {
+    id: u64,
+    prefix: u8,
+}
+
+impl Network {
+    /// Core mainnet. Addresses start with `cb`.
+    pub const MAINNET: Self = Self::new(1, 0xcb);
+
+    /// Devin testnet. Addresses start with `ab`.
+    pub const DEVIN: Self = Self::new(3, 0xab);
+
+    /// The default private network. Addresses start with `ce`.
+    ///
+    /// Any network id that is not registered is encoded with this prefix by
+    /// [`Address::to_ican`](crate::Address::to_ican), matching go-core.
+    pub const PRIVATE: Self = Self::new(1337, 0xce);
+
+    /// Creates a new network description.
+    ///
+    /// This does not register the network; see [`register`](Self::register).
+    #[inline]
+    pub const fn new(id: u64, prefix: u8) -> Self {
+        Self { id, prefix }
+    }
+
+    /// Returns the network id.
+    #[inline]
+    pub const fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the ICAN prefix byte.
+    #[inline]
+    pub const fn prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    /// Returns the known network with the given id, if any.
+    #[inline]
+    pub fn from_id(id: u64) -> Option<Self> {
+        Self::find(|network| network.id == id)
+    }
+
+    /// Returns the known network with the given id.
+    #[inline]
+    pub fn try_from_id(id: u64) -> Result<Self, AddressError> {
+        Self::from_id(id).ok_or(AddressError::UnknownNetwork(id))
+    }
+
+    /// Returns the known network whose addresses start with the given prefix
+    /// byte, if any.
+    #[inline]
+    pub fn from_prefix(prefix: u8) -> Option<Self> {
+        Self::find(|network| network.prefix == prefix)
+    }
+
+    /// Returns the known network whose addresses start with the given prefix
+    /// byte.
+    #[inline]
+    pub fn try_from_prefix(prefix: u8) -> Result<Self, AddressError> {
+        Self::from_prefix(prefix).ok_or(AddressError::UnknownNetworkPrefix(prefix))
+    }
+
+    /// Registers this network, making it known to all lookups in this process.
+    ///
+    /// Registering the same network twice is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a different network with the same id or the same
+    /// prefix is already known, including the built-in networks. Prefixes are
+    /// unique so that registering a network can never change the network of
+    /// existing addresses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use base_primitives::{Address, Network};
+    ///
+    /// let network = Network::new(2024, 0x8c);
+    /// network.register()?;
+    ///
+    /// let address = Address::ZERO.to_ican(2024);
+    /// assert_eq!(address.try_network_id()?, 2024);
+    /// # Ok::<_, base_primitives::AddressError>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn register(self) -> Result<(), AddressError> {
+        let mut registry = registry::write();
+        let known = || registry.iter().chain(&BUILTIN);
+        if known().any(|network| *network == self) {
+            Ok(())
+        } else if known().any(|network| network.id == self.id) {
+            Err(AddressError::NetworkAlreadyRegistered(self.id))
+        } else if known().any(|network| network.prefix == self.prefix) {
+            Err(AddressError::NetworkPrefixAlreadyRegistered(self.prefix))
+        } else {
+            registry.push(self);
+            Ok(())
+        }
+    }
+
+    fn find(f: impl Fn(&Self) -> bool) -> Option<Self> {
+        #[cfg(feature = "std")]
+        if let Some(network) = registry::read().iter().find(|network| f(network)) {
+            return Some(*network);
+        }
+        BUILTIN.iter().find(|network| f(network)).copied()
+    }
+}
+
+#[cfg(feature = "std")]
+mod registry {
+    use super::Network;
+    use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+    static REGISTRY: RwLock<Vec<Network>> = RwLock::new(Vec::new());
+
+    pub(super) fn read() -> RwLockReadGuard<'static, Vec<Network>> {
+        REGISTRY.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(super) fn write() -> RwLockWriteGuard<'static, Vec<Network>> {
+        REGISTRY.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin() {
+        assert_eq!(Network::from_id(1), Some(Network::MAINNET));
+        assert_eq!(Network::from_id(3), Some(Network::DEVIN));
+        assert_eq!(Network::from_id(1337), Some(Network::PRIVATE));
+        assert_eq!(Network::from_prefix(0xcb), Some(Network::MAINNET));
+        assert_eq!(Network::from_prefix(0xab), Some(Network::DEVIN));
+        assert_eq!(Network::from_prefix(0xce), Some(Network::PRIVATE));
+
+        assert!(matches!(Network::try_from_id(2), Err(AddressError::UnknownNetwork(2))));
+        assert!(matches!(
+            Network::try_from_prefix(0x42),
+            Err(AddressError::UnknownNetworkPrefix(0x42))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn register() {
+        let network = Network::new(0xc0ffee, 0x7e);
+        assert_eq!(Network::from_id(0xc0ffee), None);
+
+        network.register().unwrap();
+        network.register().unwrap();
+        assert_eq!(Network::from_id(0xc0ffee), Some(network));
+        assert_eq!(Network::from_prefix(0x7e), Some(network));
+
+        assert!(matches!(
+            Network::new(0xc0ffee, 0x7f).register(),
+            Err(AddressError::NetworkAlreadyRegistered(0xc0ffee))
+        ));
+        assert!(matches!(
+            Network::new(1, 0x7f).register(),
+            Err(AddressError::NetworkAlreadyRegistered(1))
+        ));
+
+        // Prefixes cannot be taken over, not even the built-in ones.
+        assert!(matches!(
+            Network::new(0xc0ffef, 0x7e).register(),
+            Err(AddressError::NetworkPrefixAlreadyRegistered(0x7e))
+        ));
+        assert!(matches!(
+            Network::new(0xc0ffef, 0xce).register(),
+            Err(AddressError::NetworkPrefixAlreadyRegistered(0xce))
+        ));
+        assert_eq!(Network::from_prefix(0xce), Some(Network::PRIVATE));
+        assert_eq!(Network::from_id(0xc0ffef), None);
+    }
+}
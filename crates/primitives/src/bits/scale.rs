@@ -0,0 +1,102 @@
+use super::FixedBytes;
+use parity_scale_codec::{
+    ConstEncodedLen, Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output,
+};
+
+impl<const N: usize> Encode for FixedBytes<N> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        dest.write(&self.0);
+    }
+
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> EncodeLike for FixedBytes<N> {}
+
+impl<const N: usize> Decode for FixedBytes<N> {
+    #[inline]
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        <[u8; N]>::decode(input).map(Self)
+    }
+
+    #[inline]
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(N)
+    }
+}
+
+impl<const N: usize> MaxEncodedLen for FixedBytes<N> {
+    #[inline]
+    fn max_encoded_len() -> usize {
+        N
+    }
+}
+
+impl<const N: usize> ConstEncodedLen for FixedBytes<N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cAddress, Bloom, IcanAddress};
+
+    macro_rules! test_encode_decode_scale {
+        ($test_name:ident, $type:ty, [$( $value:expr ),*]) => {
+            #[test]
+            fn $test_name() {
+                $(
+                    let expected: $type = $value;
+                    let encoded = expected.encode();
+                    assert_eq!(encoded, expected.as_slice(), "Failed for value: {:?}", $value);
+                    assert_eq!(encoded.len(), <$type>::max_encoded_len());
+                    let actual = <$type>::decode(&mut &encoded[..]).unwrap();
+                    assert_eq!(expected, actual, "Failed for value: {:?}", $value);
+                )*
+            }
+        };
+    }
+
+    test_encode_decode_scale!(
+        test_encode_decode_fixed_bytes32,
+        FixedBytes<32>,
+        [fixed_bytes!("a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2")]
+    );
+
+    test_encode_decode_scale!(
+        test_encode_decode_fixed_bytes4,
+        FixedBytes<4>,
+        [fixed_bytes!("01234567")]
+    );
+
+    test_encode_decode_scale!(
+        test_encode_decode_ican_address,
+        IcanAddress,
+        [cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"), IcanAddress::ZERO]
+    );
+
+    test_encode_decode_scale!(test_encode_decode_bloom, Bloom, [Bloom::repeat_byte(0x42)]);
+
+    #[test]
+    fn test_decode_wrong_length() {
+        assert!(FixedBytes::<4>::decode(&mut &[1, 2, 3][..]).is_err());
+        assert!(IcanAddress::decode(&mut &[0; 21][..]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn test_same_as_borsh() {
+        let address = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+        assert_eq!(address.encode(), borsh::to_vec(&address).unwrap());
+        let bytes =
+            fixed_bytes!("a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2");
+        assert_eq!(bytes.encode(), borsh::to_vec(&bytes).unwrap());
+    }
+}
@@ -0,0 +1,34 @@
+use crate::Bytes;
+use alloc::vec::Vec;
+use borsh::{io, BorshDeserialize, BorshSerialize};
+
+impl BorshSerialize for Bytes {
+    #[inline]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        <[u8]>::serialize(self, writer)
+    }
+}
+
+impl BorshDeserialize for Bytes {
+    #[inline]
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Vec::<u8>::deserialize_reader(reader).map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_borsh() {
+        for expected in [bytes!(""), bytes!("01234567"), Bytes::from(vec![0x42; 300])] {
+            let encoded = borsh::to_vec(&expected).unwrap();
+            assert_eq!(encoded[..4], (expected.len() as u32).to_le_bytes());
+            assert_eq!(encoded[4..], expected[..]);
+            let actual: Bytes = borsh::from_slice(&encoded).unwrap();
+            assert_eq!(expected, actual);
+        }
+        assert!(borsh::from_slice::<Bytes>(&[5, 0, 0, 0, 1, 2]).is_err());
+    }
+}
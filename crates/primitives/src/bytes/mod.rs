@@ -15,6 +15,12 @@ mod serde;
 #[cfg(feature = "ssz")]
 mod ssz;
 
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "scale")]
+mod scale;
+
 /// Wrapper type around [`bytes::Bytes`] to support "0x" prefixed hex strings.
 #[derive(Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
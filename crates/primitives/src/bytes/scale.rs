@@ -0,0 +1,61 @@
+use crate::Bytes;
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode, EncodeLike, Error, Input, Output};
+
+impl Encode for Bytes {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        <[u8]>::size_hint(self)
+    }
+
+    #[inline]
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        <[u8]>::encode_to(self, dest);
+    }
+
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        <[u8]>::encoded_size(self)
+    }
+}
+
+impl EncodeLike for Bytes {}
+
+impl EncodeLike<Vec<u8>> for Bytes {}
+
+impl EncodeLike<Bytes> for Vec<u8> {}
+
+impl Decode for Bytes {
+    #[inline]
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        Vec::<u8>::decode(input).map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+
+    #[test]
+    fn test_encode_decode_scale() {
+        for expected in [bytes!(""), bytes!("01234567"), Bytes::from(vec![0x42; 300])] {
+            let encoded = expected.encode();
+            assert_eq!(encoded, expected.to_vec().encode());
+            let actual = Bytes::decode(&mut &encoded[..]).unwrap();
+            assert_eq!(expected, actual);
+        }
+        // compact length prefix
+        assert_eq!(bytes!("01234567").encode(), hex!("1001234567"));
+        assert_eq!(Bytes::from(vec![0x42; 300]).encode()[..2], hex!("b104"));
+        assert!(Bytes::decode(&mut &hex!("140102")[..]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn test_borsh_length_prefix() {
+        // borsh always uses a 4-byte length prefix, SCALE a compact one
+        let bytes = bytes!("01234567");
+        assert_eq!(borsh::to_vec(&bytes).unwrap()[4..], bytes.encode()[1..]);
+    }
+}
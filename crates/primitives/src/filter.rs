@@ -0,0 +1,651 @@
+use crate::{sha3, BlockHash, BlockNumber, Bloom, BloomInput, IcanAddress, Log, B256};
+use alloc::collections::{btree_set, BTreeSet};
+use core::fmt;
+
+/// A set of values to match against, where the empty set matches everything.
+///
+/// Used for the [address](Filter::address) and each [topic](Filter::topics) of
+/// a [`Filter`]: a log matches a set if the set is empty, or if it contains the
+/// log's value.
+///
+/// In JSON-RPC filter objects a set is `null` when empty, a single value when it
+/// has one element, and an array of alternatives otherwise. A `null` inside an
+/// array of alternatives also matches everything, like in go-core.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FilterSet<T: Ord>(BTreeSet<T>);
+
+/// A set of alternatives for one topic position of a [`Filter`].
+pub type Topic = FilterSet<B256>;
+
+impl<T: Ord> Default for FilterSet<T> {
+    #[inline]
+    fn default() -> Self {
+        Self(BTreeSet::new())
+    }
+}
+
+impl<T: Ord> From<T> for FilterSet<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self(BTreeSet::from([value]))
+    }
+}
+
+impl<T: Ord> From<Option<T>> for FilterSet<T> {
+    #[inline]
+    fn from(value: Option<T>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<T: Ord, const N: usize> From<[T; N]> for FilterSet<T> {
+    #[inline]
+    fn from(values: [T; N]) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T: Ord> From<alloc::vec::Vec<T>> for FilterSet<T> {
+    #[inline]
+    fn from(values: alloc::vec::Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for FilterSet<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T: Ord> Extend<T> for FilterSet<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a FilterSet<T> {
+    type Item = &'a T;
+    type IntoIter = btree_set::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T: Ord> IntoIterator for FilterSet<T> {
+    type Item = T;
+    type IntoIter = btree_set::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: Ord> FilterSet<T> {
+    /// Creates a new empty set, which matches everything.
+    #[inline]
+    pub fn new() -> Self {
+        Self(BTreeSet::new())
+    }
+
+    /// Returns `true` if the set is empty, and therefore matches everything.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of values in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Adds a value to the set. Returns `false` if it was already present.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.0.insert(value)
+    }
+
+    /// Returns `true` if the set contains the value.
+    ///
+    /// Unlike [`matches`](Self::matches), this returns `false` for the empty
+    /// set.
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        self.0.contains(value)
+    }
+
+    /// Returns an iterator over the values in the set, in ascending order.
+    #[inline]
+    pub fn iter(&self) -> btree_set::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    /// Returns `true` if the set is empty or contains the value.
+    #[inline]
+    pub fn matches(&self, value: &T) -> bool {
+        self.is_empty() || self.contains(value)
+    }
+}
+
+impl<T: Ord + AsRef<[u8]>> FilterSet<T> {
+    /// Returns `true` if the set is empty, or if the bloom filter may contain
+    /// any of its values.
+    ///
+    /// Note: This method may return false positives. This is inherent to the
+    /// bloom filter data structure.
+    pub fn matches_bloom(&self, bloom: &Bloom) -> bool {
+        self.is_empty() || self.iter().any(|v| bloom.contains_input(BloomInput::Raw(v.as_ref())))
+    }
+}
+
+/// A block number or one of the block tags accepted by JSON-RPC methods.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BlockNumberOrTag {
+    /// The most recent block in the canonical chain.
+    #[default]
+    Latest,
+    /// The genesis block.
+    Earliest,
+    /// The pending block.
+    Pending,
+    /// A block number.
+    Number(BlockNumber),
+}
+
+impl From<BlockNumber> for BlockNumberOrTag {
+    #[inline]
+    fn from(number: BlockNumber) -> Self {
+        Self::Number(number)
+    }
+}
+
+impl BlockNumberOrTag {
+    /// Returns the block number, if this is not a tag.
+    #[inline]
+    pub const fn as_number(&self) -> Option<BlockNumber> {
+        match *self {
+            Self::Number(number) => Some(number),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for BlockNumberOrTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Latest => f.write_str("latest"),
+            Self::Earliest => f.write_str("earliest"),
+            Self::Pending => f.write_str("pending"),
+            Self::Number(number) => write!(f, "{number:#x}"),
+        }
+    }
+}
+
+impl core::str::FromStr for BlockNumberOrTag {
+    type Err = core::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "latest" => Self::Latest,
+            "earliest" => Self::Earliest,
+            "pending" => Self::Pending,
+            s => match s.strip_prefix("0x") {
+                Some(hex) => Self::Number(BlockNumber::from_str_radix(hex, 16)?),
+                None => Self::Number(s.parse()?),
+            },
+        })
+    }
+}
+
+/// The blocks a [`Filter`] applies to: either a range, or a single block by
+/// hash.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FilterBlockOption {
+    /// A range of blocks. Missing bounds default to the latest block.
+    Range {
+        /// The first block of the range.
+        from_block: Option<BlockNumberOrTag>,
+        /// The last block of the range, inclusive.
+        to_block: Option<BlockNumberOrTag>,
+    },
+    /// A single block.
+    AtBlockHash(BlockHash),
+}
+
+impl Default for FilterBlockOption {
+    #[inline]
+    fn default() -> Self {
+        Self::Range { from_block: None, to_block: None }
+    }
+}
+
+impl FilterBlockOption {
+    /// Returns the first block of the range, if set.
+    #[inline]
+    pub const fn from_block(&self) -> Option<&BlockNumberOrTag> {
+        match self {
+            Self::Range { from_block, .. } => from_block.as_ref(),
+            Self::AtBlockHash(_) => None,
+        }
+    }
+
+    /// Returns the last block of the range, if set.
+    #[inline]
+    pub const fn to_block(&self) -> Option<&BlockNumberOrTag> {
+        match self {
+            Self::Range { to_block, .. } => to_block.as_ref(),
+            Self::AtBlockHash(_) => None,
+        }
+    }
+
+    /// Returns the block hash, if this selects a single block.
+    #[inline]
+    pub const fn block_hash(&self) -> Option<&BlockHash> {
+        match self {
+            Self::AtBlockHash(hash) => Some(hash),
+            Self::Range { .. } => None,
+        }
+    }
+}
+
+/// A log filter, as used by `core_getLogs` and `core_newFilter`.
+///
+/// A log matches the filter if it was emitted by one of the
+/// [addresses](Self::address), and if for each of the four
+/// [topic positions](Self::topics) it has one of the alternatives. Empty sets
+/// match everything, so a wildcard position also matches logs that have fewer
+/// topics.
+///
+/// # Examples
+///
+/// ```
+/// use base_primitives::{b256, cAddress, Filter, Log};
+///
+/// let transfer = b256!("c17a9d92b89f27cb79cc390f23a1a5d302fefab8c7911075ede952ac2b5607a1");
+/// let token = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+/// let filter = Filter::new().from_block(100).address(token).event_signature(transfer);
+///
+/// let log = Log::new(token, vec![transfer, b256!("0000000000000000000000000000000000000000000000000000000000000001")], Default::default()).unwrap();
+/// assert!(filter.matches(&log));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Filter {
+    /// The blocks to search.
+    pub block_option: FilterBlockOption,
+    /// The addresses that may have emitted the logs.
+    pub address: FilterSet<IcanAddress>,
+    /// The alternatives for each topic position.
+    pub topics: [Topic; 4],
+}
+
+impl Filter {
+    /// Creates a new filter that matches every log in the latest block.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the first block of the range.
+    #[inline]
+    #[must_use]
+    pub fn from_block(mut self, block: impl Into<BlockNumberOrTag>) -> Self {
+        let to_block = self.block_option.to_block().copied();
+        self.block_option = FilterBlockOption::Range { from_block: Some(block.into()), to_block };
+        self
+    }
+
+    /// Sets the last block of the range.
+    #[inline]
+    #[must_use]
+    pub fn to_block(mut self, block: impl Into<BlockNumberOrTag>) -> Self {
+        let from_block = self.block_option.from_block().copied();
+        self.block_option = FilterBlockOption::Range { from_block, to_block: Some(block.into()) };
+        self
+    }
+
+    /// Restricts the filter to a single block, replacing any range.
+    #[inline]
+    #[must_use]
+    pub fn at_block_hash(mut self, hash: impl Into<BlockHash>) -> Self {
+        self.block_option = FilterBlockOption::AtBlockHash(hash.into());
+        self
+    }
+
+    /// Sets the addresses that may have emitted the logs.
+    #[inline]
+    #[must_use]
+    pub fn address(mut self, address: impl Into<FilterSet<IcanAddress>>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Sets the alternatives for the first topic, which is the event
+    /// signature hash of non-anonymous events.
+    #[inline]
+    #[must_use]
+    pub fn event_signature(mut self, topic: impl Into<Topic>) -> Self {
+        self.topics[0] = topic.into();
+        self
+    }
+
+    /// Sets the first topic to the hash of the given event signature, such as
+    /// `Transfer(address,address,uint256)`.
+    #[inline]
+    #[must_use]
+    pub fn event(self, signature: &str) -> Self {
+        self.event_signature(sha3(signature))
+    }
+
+    /// Sets the alternatives for the second topic.
+    #[inline]
+    #[must_use]
+    pub fn topic1(mut self, topic: impl Into<Topic>) -> Self {
+        self.topics[1] = topic.into();
+        self
+    }
+
+    /// Sets the alternatives for the third topic.
+    #[inline]
+    #[must_use]
+    pub fn topic2(mut self, topic: impl Into<Topic>) -> Self {
+        self.topics[2] = topic.into();
+        self
+    }
+
+    /// Sets the alternatives for the fourth topic.
+    #[inline]
+    #[must_use]
+    pub fn topic3(mut self, topic: impl Into<Topic>) -> Self {
+        self.topics[3] = topic.into();
+        self
+    }
+
+    /// Returns `true` if the address matches the filter.
+    #[inline]
+    pub fn matches_address(&self, address: &IcanAddress) -> bool {
+        self.address.matches(address)
+    }
+
+    /// Returns `true` if the topics match the filter.
+    pub fn matches_topics(&self, topics: &[B256]) -> bool {
+        self.topics.iter().enumerate().all(|(i, alternatives)| {
+            alternatives.is_empty() || topics.get(i).map_or(false, |t| alternatives.contains(t))
+        })
+    }
+
+    /// Returns `true` if the log matches the filter's address and topics.
+    ///
+    /// The block option is not checked.
+    #[inline]
+    pub fn matches(&self, log: &Log) -> bool {
+        self.matches_address(&log.address) && self.matches_topics(log.topics())
+    }
+
+    /// Returns `true` if a block or receipt with the given bloom filter may
+    /// contain logs that match the filter, so that it is worth scanning.
+    ///
+    /// Note: This method may return false positives. This is inherent to the
+    /// bloom filter data structure.
+    pub fn matches_bloom(&self, bloom: &Bloom) -> bool {
+        self.address.matches_bloom(bloom) && self.topics.iter().all(|t| t.matches_bloom(bloom))
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use alloc::vec::Vec;
+    use serde::{
+        de::{self, Visitor},
+        ser::SerializeSeq,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    impl<T: Ord + Serialize> Serialize for FilterSet<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self.len() {
+                0 => serializer.serialize_none(),
+                1 => self.iter().next().unwrap().serialize(serializer),
+                len => {
+                    let mut seq = serializer.serialize_seq(Some(len))?;
+                    self.iter().try_for_each(|v| seq.serialize_element(v))?;
+                    seq.end()
+                }
+            }
+        }
+    }
+
+    impl<'de, T: Ord + Deserialize<'de>> Deserialize<'de> for FilterSet<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            #[derive(Deserialize)]
+            #[serde(untagged)]
+            enum Repr<T> {
+                One(T),
+                Many(Vec<Option<T>>),
+            }
+
+            Ok(match Option::<Repr<T>>::deserialize(deserializer)? {
+                None => Self::new(),
+                Some(Repr::One(value)) => value.into(),
+                Some(Repr::Many(values)) => {
+                    // A `null` alternative matches everything.
+                    values.into_iter().collect::<Option<_>>().unwrap_or_default()
+                }
+            })
+        }
+    }
+
+    impl Serialize for BlockNumberOrTag {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(self)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for BlockNumberOrTag {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct BlockVisitor;
+
+            impl Visitor<'_> for BlockVisitor {
+                type Value = BlockNumberOrTag;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("a block number or `latest`, `earliest` or `pending`")
+                }
+
+                fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                    Ok(BlockNumberOrTag::Number(v))
+                }
+
+                fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                    v.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+                }
+            }
+
+            deserializer.deserialize_any(BlockVisitor)
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct FilterRepr {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        from_block: Option<BlockNumberOrTag>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        to_block: Option<BlockNumberOrTag>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        block_hash: Option<BlockHash>,
+        #[serde(default, skip_serializing_if = "FilterSet::is_empty")]
+        address: FilterSet<IcanAddress>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        topics: Vec<Topic>,
+    }
+
+    impl Serialize for Filter {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            // Trailing wildcards are left out.
+            let len = self.topics.iter().rposition(|t| !t.is_empty()).map_or(0, |i| i + 1);
+            FilterRepr {
+                from_block: self.block_option.from_block().copied(),
+                to_block: self.block_option.to_block().copied(),
+                block_hash: self.block_option.block_hash().copied(),
+                address: self.address.clone(),
+                topics: self.topics[..len].to_vec(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Filter {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let FilterRepr { from_block, to_block, block_hash, address, topics } =
+                FilterRepr::deserialize(deserializer)?;
+
+            let block_option = match block_hash {
+                Some(_) if from_block.is_some() || to_block.is_some() => {
+                    return Err(de::Error::custom(
+                        "cannot specify both blockHash and fromBlock/toBlock",
+                    ))
+                }
+                Some(hash) => FilterBlockOption::AtBlockHash(hash),
+                None => FilterBlockOption::Range { from_block, to_block },
+            };
+
+            let len = topics.len();
+            if len > 4 {
+                return Err(de::Error::invalid_length(len, &"at most 4 topics"));
+            }
+            let mut filter_topics: [Topic; 4] = Default::default();
+            for (slot, topic) in filter_topics.iter_mut().zip(topics) {
+                *slot = topic;
+            }
+
+            Ok(Self { block_option, address, topics: filter_topics })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cAddress, hex, Bytes};
+
+    const TRANSFER: B256 =
+        B256::new(hex!("c17a9d92b89f27cb79cc390f23a1a5d302fefab8c7911075ede952ac2b5607a1"));
+    const APPROVAL: B256 =
+        B256::new(hex!("afa504e0962ad93dec232a2c88581b4028671c11f4571f9edec54fb75bd7293d"));
+    const ALICE: B256 =
+        B256::new(hex!("0000000000000000000000000000000000000000000000000000000000000001"));
+    const BOB: B256 =
+        B256::new(hex!("0000000000000000000000000000000000000000000000000000000000000002"));
+
+    fn transfer_log(address: IcanAddress) -> Log {
+        Log::new(address, vec![TRANSFER, ALICE, BOB], Bytes::new()).unwrap()
+    }
+
+    #[test]
+    fn matches_log() {
+        let token = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+        let other = cAddress!("cb751111111111111111111111111111111111111111");
+        let log = transfer_log(token);
+
+        assert!(Filter::new().matches(&log));
+        assert!(Filter::new().address(token).matches(&log));
+        assert!(Filter::new().address([other, token]).matches(&log));
+        assert!(!Filter::new().address(other).matches(&log));
+
+        assert!(Filter::new().event("Transfer(address,address,uint256)").matches(&log));
+        assert!(Filter::new().event_signature([APPROVAL, TRANSFER]).matches(&log));
+        assert!(!Filter::new().event_signature(APPROVAL).matches(&log));
+        assert!(Filter::new().topic2(BOB).matches(&log));
+        assert!(!Filter::new().topic1(BOB).matches(&log));
+        assert!(Filter::new().topic1(ALICE).topic2([ALICE, BOB]).matches(&log));
+
+        // wildcards match missing topics, but concrete values do not
+        assert!(Filter::new().topic1(ALICE).matches(&log));
+        assert!(!Filter::new().topic3(ALICE).matches(&log));
+    }
+
+    #[test]
+    fn matches_bloom() {
+        let token = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+        let other = cAddress!("cb751111111111111111111111111111111111111111");
+        let log = transfer_log(token);
+        let mut bloom = Bloom::ZERO;
+        bloom.accrue_log(&log);
+
+        assert!(Filter::new().matches_bloom(&Bloom::ZERO));
+        assert!(Filter::new().matches_bloom(&bloom));
+        assert!(Filter::new()
+            .address([other, token])
+            .event_signature(TRANSFER)
+            .matches_bloom(&bloom));
+        assert!(Filter::new().topic1(ALICE).topic2(BOB).matches_bloom(&bloom));
+        assert!(!Filter::new().address(other).matches_bloom(&bloom));
+        assert!(!Filter::new().event_signature(APPROVAL).matches_bloom(&bloom));
+        assert!(!Filter::new().address(token).matches_bloom(&Bloom::ZERO));
+    }
+
+    #[test]
+    fn block_number_or_tag() {
+        for (s, block) in [
+            ("latest", BlockNumberOrTag::Latest),
+            ("earliest", BlockNumberOrTag::Earliest),
+            ("pending", BlockNumberOrTag::Pending),
+            ("0x1b4", BlockNumberOrTag::Number(436)),
+        ] {
+            assert_eq!(s.parse::<BlockNumberOrTag>().unwrap(), block);
+            assert_eq!(block.to_string(), s);
+        }
+        assert_eq!("436".parse::<BlockNumberOrTag>().unwrap(), BlockNumberOrTag::Number(436));
+        assert!("safe".parse::<BlockNumberOrTag>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let token = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+        let other = cAddress!("cb751111111111111111111111111111111111111111");
+
+        let filter = Filter::new()
+            .from_block(100)
+            .to_block(BlockNumberOrTag::Latest)
+            .address(token)
+            .topic2([ALICE, BOB]);
+        let json = serde_json::to_value(&filter).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "fromBlock": "0x64",
+                "toBlock": "latest",
+                "address": token,
+                "topics": [null, null, [ALICE, BOB]],
+            })
+        );
+        assert_eq!(serde_json::from_value::<Filter>(json).unwrap(), filter);
+
+        let json = serde_json::json!({
+            "blockHash": TRANSFER,
+            "address": [token, other],
+            "topics": [TRANSFER, [ALICE, null], null],
+        });
+        let filter: Filter = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            filter,
+            Filter::new().at_block_hash(TRANSFER).address([token, other]).event_signature(TRANSFER)
+        );
+
+        assert_eq!(serde_json::to_string(&Filter::new()).unwrap(), "{}");
+        assert_eq!(serde_json::from_str::<Filter>("{}").unwrap(), Filter::new());
+
+        let both = serde_json::json!({ "blockHash": TRANSFER, "fromBlock": "earliest" });
+        assert!(serde_json::from_value::<Filter>(both).is_err());
+        let too_many = serde_json::json!({ "topics": [null, null, null, null, TRANSFER] });
+        assert!(serde_json::from_value::<Filter>(too_many).is_err());
+    }
+}
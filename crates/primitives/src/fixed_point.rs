@@ -0,0 +1,551 @@
+//! Fixed-point decimal numbers, Ylem's `fixedMxN` and `ufixedMxN`.
+
+use crate::{Sign, I256, U256};
+use alloc::string::{String, ToString};
+use core::{fmt, ops, str::FromStr};
+use ruint::Uint;
+
+/// Wide enough for `U256::MAX * 10^80`, the largest intermediate value of a
+/// fixed-point multiplication or division.
+type Wide = Uint<576, 9>;
+
+/// The error type that is returned when parsing a fixed-point number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseFixedError {
+    /// The string is not a decimal number.
+    InvalidDigit,
+
+    /// The number has more fractional digits than the type has decimals.
+    TooManyDecimals,
+
+    /// The number does not fit in the type.
+    Overflow,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFixedError {}
+
+impl fmt::Display for ParseFixedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidDigit => "invalid digit found in string",
+            Self::TooManyDecimals => "too many fractional digits",
+            Self::Overflow => "number does not fit in the fixed-point type",
+        })
+    }
+}
+
+/// A signed fixed-point decimal number, Ylem's `fixedMxN`.
+///
+/// The value is `raw / 10^N`, where `raw` is an `M`-bit signed integer. `M`
+/// must be a multiple of 8 between 8 and 256, and `N` must be at most 80.
+///
+/// Arithmetic truncates towards zero, and the operators panic on overflow and
+/// division by zero like the primitive integer types do. Use the `checked_*`
+/// methods to handle these cases.
+///
+/// # Examples
+///
+/// ```
+/// use base_primitives::Fixed;
+///
+/// let a: Fixed<128, 18> = "1.5".parse()?;
+/// let b: Fixed<128, 18> = "-0.25".parse()?;
+/// assert_eq!((a + b).to_string(), "1.25");
+/// assert_eq!((a * b).to_string(), "-0.375");
+/// assert_eq!(format!("{:.3}", a / b), "-6.000");
+/// assert_eq!(a.into_raw().to_string(), "1500000000000000000");
+/// # Ok::<_, base_primitives::ParseFixedError>(())
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const M: usize, const N: usize>(I256);
+
+/// An unsigned fixed-point decimal number, Ylem's `ufixedMxN`.
+///
+/// The value is `raw / 10^N`, where `raw` is an `M`-bit unsigned integer. See
+/// [`Fixed`] for the constraints on `M` and `N` and how arithmetic behaves.
+///
+/// # Examples
+///
+/// ```
+/// use base_primitives::Ufixed;
+///
+/// let price: Ufixed<128, 18> = "1234.5".parse()?;
+/// let amount: Ufixed<128, 18> = "0.1".parse()?;
+/// assert_eq!((price * amount).to_string(), "123.45");
+/// assert!(price.checked_sub(price + amount).is_none());
+/// # Ok::<_, base_primitives::ParseFixedError>(())
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ufixed<const M: usize, const N: usize>(U256);
+
+/// Panics at compile time if `M` and `N` are not valid fixed-point parameters.
+const fn check_params(m: usize, n: usize) {
+    assert!(m % 8 == 0 && m >= 8 && m <= 256, "fixed-point bits must be a multiple of 8 up to 256");
+    assert!(n <= 80, "fixed-point decimals must be at most 80");
+}
+
+impl<const M: usize, const N: usize> Fixed<M, N> {
+    const PARAMS: () = check_params(M, N);
+
+    /// The number of bits of the raw value.
+    pub const BITS: usize = M;
+
+    /// The number of decimal places.
+    pub const DECIMALS: usize = N;
+
+    /// Zero.
+    pub const ZERO: Self = Self(I256::ZERO);
+
+    /// Creates a number from its raw value, `self * 10^N`. Returns `None` if
+    /// the value does not fit in `M` bits.
+    #[inline]
+    pub fn from_raw(raw: I256) -> Option<Self> {
+        let () = Self::PARAMS;
+        (raw.bits() as usize <= M).then_some(Self(raw))
+    }
+
+    /// Returns the raw value, `self * 10^N`.
+    #[inline]
+    pub const fn into_raw(self) -> I256 {
+        self.0
+    }
+
+    /// The largest value of this type.
+    #[inline]
+    pub fn max_value() -> Self {
+        let () = Self::PARAMS;
+        Self(I256::from_raw((U256::from(1) << (M - 1)) - U256::from(1)))
+    }
+
+    /// The smallest value of this type.
+    #[inline]
+    pub fn min_value() -> Self {
+        Self(-Self::max_value().0 - I256::ONE)
+    }
+
+    /// Returns `true` if the number is zero.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns `true` if the number is negative.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    /// Returns the absolute value, or `None` if it overflows.
+    #[inline]
+    pub fn checked_abs(self) -> Option<Self> {
+        self.0.checked_abs().and_then(Self::from_raw)
+    }
+
+    /// Negates the number, returning `None` if it overflows.
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        self.0.checked_neg().and_then(Self::from_raw)
+    }
+
+    /// Adds two numbers, returning `None` if the result overflows.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).and_then(Self::from_raw)
+    }
+
+    /// Subtracts two numbers, returning `None` if the result overflows.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).and_then(Self::from_raw)
+    }
+
+    /// Multiplies two numbers, truncating towards zero. Returns `None` if the
+    /// result overflows.
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let abs = mul_abs(self.0.unsigned_abs(), rhs.0.unsigned_abs(), N)?;
+        self.with_sign_of(rhs, abs)
+    }
+
+    /// Divides two numbers, truncating towards zero. Returns `None` if `rhs` is
+    /// zero or the result overflows.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        let abs = div_abs(self.0.unsigned_abs(), rhs.0.unsigned_abs(), N)?;
+        self.with_sign_of(rhs, abs)
+    }
+
+    fn with_sign_of(self, rhs: Self, abs: U256) -> Option<Self> {
+        let sign = self.0.sign() * rhs.0.sign();
+        I256::checked_from_sign_and_abs(sign, abs).and_then(Self::from_raw)
+    }
+}
+
+impl<const M: usize, const N: usize> Ufixed<M, N> {
+    const PARAMS: () = check_params(M, N);
+
+    /// The number of bits of the raw value.
+    pub const BITS: usize = M;
+
+    /// The number of decimal places.
+    pub const DECIMALS: usize = N;
+
+    /// Zero.
+    pub const ZERO: Self = Self(U256::ZERO);
+
+    /// Creates a number from its raw value, `self * 10^N`. Returns `None` if
+    /// the value does not fit in `M` bits.
+    #[inline]
+    pub fn from_raw(raw: U256) -> Option<Self> {
+        let () = Self::PARAMS;
+        (raw.bit_len() <= M).then_some(Self(raw))
+    }
+
+    /// Returns the raw value, `self * 10^N`.
+    #[inline]
+    pub const fn into_raw(self) -> U256 {
+        self.0
+    }
+
+    /// The largest value of this type.
+    #[inline]
+    pub fn max_value() -> Self {
+        let () = Self::PARAMS;
+        Self(U256::MAX >> (256 - M))
+    }
+
+    /// The smallest value of this type, zero.
+    #[inline]
+    pub const fn min_value() -> Self {
+        Self::ZERO
+    }
+
+    /// Returns `true` if the number is zero.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        // `Uint::is_zero` is not `const`; zero has the same bits either way.
+        I256::from_raw(self.0).is_zero()
+    }
+
+    /// Adds two numbers, returning `None` if the result overflows.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).and_then(Self::from_raw)
+    }
+
+    /// Subtracts two numbers, returning `None` if the result is negative.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).and_then(Self::from_raw)
+    }
+
+    /// Multiplies two numbers, truncating towards zero. Returns `None` if the
+    /// result overflows.
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        mul_abs(self.0, rhs.0, N).and_then(Self::from_raw)
+    }
+
+    /// Divides two numbers, truncating towards zero. Returns `None` if `rhs` is
+    /// zero or the result overflows.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        div_abs(self.0, rhs.0, N).and_then(Self::from_raw)
+    }
+}
+
+fn scale(decimals: usize) -> Wide {
+    Wide::from(10).pow(Wide::from(decimals))
+}
+
+fn narrow(wide: Wide) -> Option<U256> {
+    (wide.bit_len() <= 256).then(|| wide.to())
+}
+
+/// `a * b / 10^decimals`
+fn mul_abs(a: U256, b: U256, decimals: usize) -> Option<U256> {
+    let product = Wide::from(a) * Wide::from(b) / scale(decimals);
+    narrow(product)
+}
+
+/// `a * 10^decimals / b`
+fn div_abs(a: U256, b: U256, decimals: usize) -> Option<U256> {
+    if b.is_zero() {
+        return None;
+    }
+    let quotient = Wide::from(a) * scale(decimals) / Wide::from(b);
+    narrow(quotient)
+}
+
+impl<const M: usize, const N: usize> ops::Neg for Fixed<M, N> {
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn neg(self) -> Self {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+
+macro_rules! impl_ops {
+    ($t:ident) => {
+        impl_ops!(@op $t, Add, add, AddAssign, add_assign, checked_add, "attempt to add with overflow");
+        impl_ops!(@op $t, Sub, sub, SubAssign, sub_assign, checked_sub, "attempt to subtract with overflow");
+        impl_ops!(@op $t, Mul, mul, MulAssign, mul_assign, checked_mul, "attempt to multiply with overflow");
+        impl_ops!(@op $t, Div, div, DivAssign, div_assign, checked_div, "attempt to divide by zero or with overflow");
+
+        impl<const M: usize, const N: usize> fmt::Debug for $t<M, N> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+    };
+    (@op $t:ident, $tr:ident, $f:ident, $tr_assign:ident, $f_assign:ident, $checked:ident, $msg:literal) => {
+        impl<const M: usize, const N: usize> ops::$tr for $t<M, N> {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn $f(self, rhs: Self) -> Self {
+                self.$checked(rhs).expect($msg)
+            }
+        }
+
+        impl<const M: usize, const N: usize> ops::$tr_assign for $t<M, N> {
+            #[inline]
+            #[track_caller]
+            fn $f_assign(&mut self, rhs: Self) {
+                *self = ops::$tr::$f(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_ops!(Fixed);
+impl_ops!(Ufixed);
+
+impl<const M: usize, const N: usize> fmt::Display for Fixed<M, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_decimal(f, self.is_negative(), self.0.unsigned_abs(), N)
+    }
+}
+
+impl<const M: usize, const N: usize> fmt::Display for Ufixed<M, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_decimal(f, false, self.0, N)
+    }
+}
+
+/// Formats `abs / 10^decimals`. Trailing zeros of the fraction are omitted,
+/// unless a precision is given, in which case the fraction is padded or
+/// truncated to it.
+fn fmt_decimal(
+    f: &mut fmt::Formatter<'_>,
+    negative: bool,
+    abs: U256,
+    decimals: usize,
+) -> fmt::Result {
+    let mut digits = abs.to_string();
+    if digits.len() <= decimals {
+        digits.insert_str(0, &"0".repeat(decimals + 1 - digits.len()));
+    }
+    let (int, fraction) = digits.split_at(digits.len() - decimals);
+
+    let mut s = String::with_capacity(digits.len() + 1);
+    s.push_str(int);
+    let fraction = match f.precision() {
+        Some(precision) => {
+            let mut fraction = fraction[..precision.min(decimals)].to_string();
+            fraction.extend(core::iter::repeat('0').take(precision.saturating_sub(decimals)));
+            fraction
+        }
+        None => fraction.trim_end_matches('0').to_string(),
+    };
+    if !fraction.is_empty() {
+        s.push('.');
+        s.push_str(&fraction);
+    }
+    f.pad_integral(!negative, "", &s)
+}
+
+impl<const M: usize, const N: usize> FromStr for Fixed<M, N> {
+    type Err = ParseFixedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (sign, abs) = parse_decimal(s, N)?;
+        I256::checked_from_sign_and_abs(sign, abs)
+            .and_then(Self::from_raw)
+            .ok_or(ParseFixedError::Overflow)
+    }
+}
+
+impl<const M: usize, const N: usize> FromStr for Ufixed<M, N> {
+    type Err = ParseFixedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_decimal(s, N)? {
+            (Sign::Negative, abs) if !abs.is_zero() => Err(ParseFixedError::Overflow),
+            (_, abs) => Self::from_raw(abs).ok_or(ParseFixedError::Overflow),
+        }
+    }
+}
+
+/// Parses a decimal number with at most `decimals` fractional digits into its
+/// sign and `abs(number) * 10^decimals`.
+///
+/// The number must match `[+-]?[0-9]+(\.[0-9]+)?`, so there must be digits on
+/// both sides of the decimal point. This is the grammar of the [`Fixed`] and
+/// [`Ufixed`] [`FromStr`] implementations, and of `fixedMxN` values in the
+/// dynamic ABI.
+///
+/// # Examples
+///
+/// ```
+/// use base_primitives::{utils::parse_decimal, ParseFixedError, Sign, U256};
+///
+/// assert_eq!(parse_decimal("-1.5", 2), Ok((Sign::Negative, U256::from(150))));
+/// assert_eq!(parse_decimal("1.555", 2), Err(ParseFixedError::TooManyDecimals));
+/// assert_eq!(parse_decimal(".5", 2), Err(ParseFixedError::InvalidDigit));
+/// assert_eq!(parse_decimal("2.", 2), Err(ParseFixedError::InvalidDigit));
+/// ```
+pub fn parse_decimal(s: &str, decimals: usize) -> Result<(Sign, U256), ParseFixedError> {
+    let (sign, s) = match s.as_bytes().first() {
+        Some(b'-') => (Sign::Negative, &s[1..]),
+        Some(b'+') => (Sign::Positive, &s[1..]),
+        _ => (Sign::Positive, s),
+    };
+    let (int, fraction) = match s.split_once('.') {
+        Some((int, fraction)) if !fraction.is_empty() => (int, fraction),
+        Some(_) => return Err(ParseFixedError::InvalidDigit),
+        None => (s, ""),
+    };
+    if int.is_empty() || !int.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit()) {
+        return Err(ParseFixedError::InvalidDigit);
+    }
+    if fraction.len() > decimals {
+        return Err(ParseFixedError::TooManyDecimals);
+    }
+
+    let mut digits = String::with_capacity(int.len() + decimals);
+    digits.push_str(int);
+    digits.push_str(fraction);
+    digits.extend(core::iter::repeat('0').take(decimals - fraction.len()));
+    let abs = U256::from_str_radix(&digits, 10).map_err(|_| ParseFixedError::Overflow)?;
+    Ok((sign, abs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type F128x18 = Fixed<128, 18>;
+    type U128x18 = Ufixed<128, 18>;
+
+    #[test]
+    fn parse_and_format() {
+        for (s, raw, formatted) in [
+            ("0", 0i128, "0"),
+            ("-0", 0, "0"),
+            ("1", 1_000_000_000_000_000_000, "1"),
+            ("+1.50", 1_500_000_000_000_000_000, "1.5"),
+            ("-1.5", -1_500_000_000_000_000_000, "-1.5"),
+            ("-0.000000000000000001", -1, "-0.000000000000000001"),
+        ] {
+            let value: F128x18 = s.parse().unwrap();
+            assert_eq!(value.into_raw(), I256::try_from(raw).unwrap(), "{s}");
+            assert_eq!(value.to_string(), formatted, "{s}");
+            assert_eq!(formatted.parse::<F128x18>().unwrap(), value);
+        }
+
+        for s in
+            ["", "-", ".", ".5", "2.", "-.5", "1..2", "1.2.3", "0x1", "1e18", " 1", "1,5", "--1"]
+        {
+            assert_eq!(s.parse::<F128x18>(), Err(ParseFixedError::InvalidDigit), "{s}");
+        }
+        assert_eq!(
+            "0.0000000000000000001".parse::<F128x18>(),
+            Err(ParseFixedError::TooManyDecimals)
+        );
+        assert_eq!("-1".parse::<U128x18>(), Err(ParseFixedError::Overflow));
+        assert_eq!("-0".parse::<U128x18>(), Ok(U128x18::ZERO));
+        assert_eq!(".5".parse::<U128x18>(), Err(ParseFixedError::InvalidDigit));
+        assert_eq!("2.".parse::<U128x18>(), Err(ParseFixedError::InvalidDigit));
+
+        let value: Fixed<32, 2> = "-12.3".parse().unwrap();
+        assert_eq!(format!("{value:.0}"), "-12");
+        assert_eq!(format!("{value:.1}"), "-12.3");
+        assert_eq!(format!("{value:.4}"), "-12.3000");
+        assert_eq!(format!("{value:>8}"), "   -12.3");
+        assert_eq!(format!("{value:08.2}"), "-0012.30");
+        assert_eq!(format!("{:+}", -value), "+12.3");
+        assert_eq!(format!("{value:?}"), "-12.3");
+
+        let value: Ufixed<8, 0> = "255".parse().unwrap();
+        assert_eq!(value.to_string(), "255");
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(Fixed::<8, 1>::max_value().to_string(), "12.7");
+        assert_eq!(Fixed::<8, 1>::min_value().to_string(), "-12.8");
+        assert_eq!(Ufixed::<8, 1>::max_value().to_string(), "25.5");
+        assert_eq!("12.8".parse::<Fixed<8, 1>>(), Err(ParseFixedError::Overflow));
+        assert_eq!("-12.8".parse::<Fixed<8, 1>>(), Ok(Fixed::<8, 1>::min_value()));
+        assert_eq!("25.6".parse::<Ufixed<8, 1>>(), Err(ParseFixedError::Overflow));
+
+        assert_eq!(Fixed::<256, 0>::max_value().into_raw(), I256::MAX);
+        assert_eq!(Fixed::<256, 0>::min_value().into_raw(), I256::MIN);
+        assert_eq!(Ufixed::<256, 80>::max_value().into_raw(), U256::MAX);
+        assert_eq!(Fixed::<8, 1>::from_raw(I256::try_from(128).unwrap()), None);
+        assert_eq!(Ufixed::<8, 1>::from_raw(U256::from(256)), None);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let f = |s: &str| s.parse::<F128x18>().unwrap();
+        assert_eq!(f("1.5") + f("2.25"), f("3.75"));
+        assert_eq!(f("1.5") - f("2.25"), f("-0.75"));
+        assert_eq!(f("1.5") * f("-2.25"), f("-3.375"));
+        assert_eq!(f("-1") / f("3"), f("-0.333333333333333333"));
+        assert_eq!(f("2") / f("-0.5"), f("-4"));
+        assert_eq!(-f("1.5"), f("-1.5"));
+
+        let mut x = f("10");
+        x += f("1");
+        x *= f("0.5");
+        x -= f("0.5");
+        x /= f("2.5");
+        assert_eq!(x, f("2"));
+
+        // truncation
+        assert_eq!(f("0.000000000000000001") * f("0.5"), F128x18::ZERO);
+        assert_eq!(f("-0.000000000000000001") * f("0.5"), F128x18::ZERO);
+
+        // overflow
+        assert_eq!(F128x18::max_value().checked_add(f("0.000000000000000001")), None);
+        assert_eq!(F128x18::min_value().checked_neg(), None);
+        assert_eq!(F128x18::min_value().checked_abs(), None);
+        assert_eq!(F128x18::max_value().checked_mul(f("2")), None);
+        assert_eq!(f("1").checked_div(F128x18::ZERO), None);
+
+        let u = |s: &str| s.parse::<U128x18>().unwrap();
+        assert_eq!(u("1.5").checked_sub(u("2")), None);
+        assert_eq!(u("1.5") * u("3"), u("4.5"));
+        assert_eq!(u("1") / u("8"), u("0.125"));
+
+        // intermediate values beyond 512 bits
+        let max = Ufixed::<256, 76>::max_value();
+        let one = Ufixed::<256, 76>::from_raw(scale(76).to()).unwrap();
+        assert_eq!(max * one, max);
+        assert_eq!(max / one, max);
+        assert_eq!(max / max, one);
+        assert_eq!(max.checked_div(Ufixed::from_raw(U256::from(1)).unwrap()), None);
+    }
+
+    #[test]
+    #[should_panic = "attempt to add with overflow"]
+    fn add_overflow() {
+        let _ = Fixed::<8, 0>::max_value() + "1".parse().unwrap();
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{
+    sha3, trie::EMPTY_ROOT_HASH, BlockNumber, Bloom, Bytes, IcanAddress, Sealable, B256, B64, U256,
+};
+use alloc::vec::Vec;
+use alloy_rlp::{length_of_length, BufMut, Decodable, Encodable, Header as RlpHeader};
+
+/// A Core block header.
+///
+/// The [hash](Sealable::hash_slow) of a block is the [`sha3`] hash of the RLP
+/// encoding of its header. The proof-of-work is computed over the
+/// [seal hash](Self::seal_hash), which leaves out the [nonce](Self::nonce)
+/// found by the miner.
+///
+/// With the `serde` feature, headers are (de)serialized in the shape returned
+/// by the JSON-RPC API, with numbers as `0x`-prefixed hex quantities.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Header {
+    /// The hash of the parent block.
+    pub parent_hash: B256,
+    /// The address that receives the block reward.
+    #[cfg_attr(feature = "serde", serde(rename = "miner"))]
+    pub coinbase: IcanAddress,
+    /// The root of the state trie after the block is executed.
+    pub state_root: B256,
+    /// The root of the [ordered trie](crate::trie) of the block's
+    /// transactions.
+    pub transactions_root: B256,
+    /// The root of the [ordered trie](crate::trie) of the block's receipts.
+    pub receipts_root: B256,
+    /// The bloom filter of the logs of all the block's receipts.
+    pub logs_bloom: Bloom,
+    /// The proof-of-work difficulty of the block.
+    pub difficulty: U256,
+    /// The number of ancestor blocks.
+    #[cfg_attr(feature = "serde", serde(with = "quantity"))]
+    pub number: BlockNumber,
+    /// The maximum energy all the block's transactions may use.
+    #[cfg_attr(feature = "serde", serde(with = "quantity"))]
+    pub energy_limit: u64,
+    /// The energy used by all the block's transactions.
+    #[cfg_attr(feature = "serde", serde(with = "quantity"))]
+    pub energy_used: u64,
+    /// The Unix timestamp of the block, in seconds.
+    #[cfg_attr(feature = "serde", serde(with = "quantity"))]
+    pub timestamp: u64,
+    /// Arbitrary data chosen by the miner.
+    pub extra_data: Bytes,
+    /// The RandomY proof-of-work nonce.
+    pub nonce: B64,
+}
+
+impl Default for Header {
+    fn default() -> Self {
+        Self {
+            parent_hash: B256::ZERO,
+            coinbase: IcanAddress::ZERO,
+            state_root: EMPTY_ROOT_HASH,
+            transactions_root: EMPTY_ROOT_HASH,
+            receipts_root: EMPTY_ROOT_HASH,
+            logs_bloom: Bloom::ZERO,
+            difficulty: U256::ZERO,
+            number: 0,
+            energy_limit: 0,
+            energy_used: 0,
+            timestamp: 0,
+            extra_data: Bytes::new(),
+            nonce: B64::ZERO,
+        }
+    }
+}
+
+impl Header {
+    /// Returns the length of the RLP encoding of all fields except the nonce.
+    fn seal_fields_len(&self) -> usize {
+        self.parent_hash.length()
+            + self.coinbase.length()
+            + self.state_root.length()
+            + self.transactions_root.length()
+            + self.receipts_root.length()
+            + self.logs_bloom.length()
+            + self.difficulty.length()
+            + self.number.length()
+            + self.energy_limit.length()
+            + self.energy_used.length()
+            + self.timestamp.length()
+            + self.extra_data.length()
+    }
+
+    /// Encodes all fields except the nonce, without the list header.
+    fn encode_seal_fields(&self, out: &mut dyn BufMut) {
+        self.parent_hash.encode(out);
+        self.coinbase.encode(out);
+        self.state_root.encode(out);
+        self.transactions_root.encode(out);
+        self.receipts_root.encode(out);
+        self.logs_bloom.encode(out);
+        self.difficulty.encode(out);
+        self.number.encode(out);
+        self.energy_limit.encode(out);
+        self.energy_used.encode(out);
+        self.timestamp.encode(out);
+        self.extra_data.encode(out);
+    }
+
+    /// Encodes the RandomY seal hash preimage: the RLP list of all fields
+    /// except the nonce.
+    pub fn encode_seal_preimage(&self, out: &mut dyn BufMut) {
+        RlpHeader { list: true, payload_length: self.seal_fields_len() }.encode(out);
+        self.encode_seal_fields(out);
+    }
+
+    /// Returns the RandomY seal hash preimage. See
+    /// [`encode_seal_preimage`](Self::encode_seal_preimage).
+    pub fn seal_preimage(&self) -> Vec<u8> {
+        let payload_length = self.seal_fields_len();
+        let mut out = Vec::with_capacity(payload_length + length_of_length(payload_length));
+        self.encode_seal_preimage(&mut out);
+        out
+    }
+
+    /// Returns the hash that the proof-of-work is computed over: the [`sha3`]
+    /// hash of the [seal preimage](Self::seal_preimage).
+    #[inline]
+    pub fn seal_hash(&self) -> B256 {
+        sha3(self.seal_preimage())
+    }
+
+    /// Returns `true` if the block has no transactions.
+    #[inline]
+    pub fn transactions_root_is_empty(&self) -> bool {
+        self.transactions_root == EMPTY_ROOT_HASH
+    }
+
+    fn payload_len(&self) -> usize {
+        self.seal_fields_len() + self.nonce.length()
+    }
+}
+
+impl Encodable for Header {
+    fn encode(&self, out: &mut dyn BufMut) {
+        RlpHeader { list: true, payload_length: self.payload_len() }.encode(out);
+        self.encode_seal_fields(out);
+        self.nonce.encode(out);
+    }
+
+    fn length(&self) -> usize {
+        let payload_length = self.payload_len();
+        payload_length + length_of_length(payload_length)
+    }
+}
+
+impl Decodable for Header {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = RlpHeader::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+        let started_len = buf.len();
+        let this = Self {
+            parent_hash: Decodable::decode(buf)?,
+            coinbase: Decodable::decode(buf)?,
+            state_root: Decodable::decode(buf)?,
+            transactions_root: Decodable::decode(buf)?,
+            receipts_root: Decodable::decode(buf)?,
+            logs_bloom: Decodable::decode(buf)?,
+            difficulty: Decodable::decode(buf)?,
+            number: Decodable::decode(buf)?,
+            energy_limit: Decodable::decode(buf)?,
+            energy_used: Decodable::decode(buf)?,
+            timestamp: Decodable::decode(buf)?,
+            extra_data: Decodable::decode(buf)?,
+            nonce: Decodable::decode(buf)?,
+        };
+        if started_len - buf.len() != header.payload_length {
+            return Err(alloy_rlp::Error::ListLengthMismatch {
+                expected: header.payload_length,
+                got: started_len - buf.len(),
+            });
+        }
+        Ok(this)
+    }
+}
+
+impl Sealable for Header {
+    fn hash_slow(&self) -> B256 {
+        sha3(alloy_rlp::encode(self))
+    }
+}
+
+/// (De)serializes a `u64` as a `0x`-prefixed hex quantity.
+#[cfg(feature = "serde")]
+mod quantity {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{value:#x}"))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        let s = alloc::string::String::deserialize(deserializer)?;
+        let hex = s.strip_prefix("0x").ok_or_else(|| de::Error::custom("missing 0x prefix"))?;
+        u64::from_str_radix(hex, 16).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cAddress, hex};
+
+    fn header() -> Header {
+        Header {
+            parent_hash: B256::new(hex!(
+                "ea1b2bdd513bb6d97cb2fadad17624b218b4f520f3acf675b6f039d6440e1225"
+            )),
+            coinbase: cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"),
+            state_root: B256::new(hex!(
+                "6382cada33c26f07e3612bc08891190f138311477b92827f2b0b18392b2208a7"
+            )),
+            transactions_root: B256::new(hex!(
+                "e5f3fcd0e187a0e7e487befbcadcaf0cda58b6bf23f31fc7d112450decdf1474"
+            )),
+            receipts_root: B256::new(hex!(
+                "df4243fb9d83122bdb6fcae6d2e587331ed6434d6a437e95ac4ae252e1332dcb"
+            )),
+            logs_bloom: Bloom::ZERO,
+            difficulty: U256::from(0x2f5b3cu64),
+            number: 1_234_567,
+            energy_limit: 10_500_000,
+            energy_used: 21000,
+            timestamp: 1_700_000_000,
+            extra_data: Bytes::from_static(b"core"),
+            nonce: B64::new(hex!("0123456789abcdef")),
+        }
+    }
+
+    #[test]
+    fn rlp() {
+        let header = header();
+        let rlp = alloy_rlp::encode(&header);
+        assert_eq!(rlp, hex!("f901c0a0ea1b2bdd513bb6d97cb2fadad17624b218b4f520f3acf675b6f039d6440e122596cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5a06382cada33c26f07e3612bc08891190f138311477b92827f2b0b18392b2208a7a0e5f3fcd0e187a0e7e487befbcadcaf0cda58b6bf23f31fc7d112450decdf1474a0df4243fb9d83122bdb6fcae6d2e587331ed6434d6a437e95ac4ae252e1332dcbb9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000832f5b3c8312d68783a037a0825208846553f10084636f7265880123456789abcdef"));
+        assert_eq!(header.length(), rlp.len());
+        assert_eq!(Header::decode(&mut &rlp[..]).unwrap(), header);
+
+        assert_eq!(
+            header.clone().seal_slow().seal(),
+            B256::new(hex!("0039f4d6385971522a5c3c92a580be140bc9bf220547a831282344cd8e0a57fd"))
+        );
+
+        // the preimage is the encoding without the nonce
+        let preimage = header.seal_preimage();
+        assert_eq!(preimage[3..], rlp[3..rlp.len() - 9]);
+        assert_eq!(
+            header.seal_hash(),
+            B256::new(hex!("79e2e084b4d8cb24d6bd1c697d5d6d2aecf4a4971de65914f876f89bc8608d7c"))
+        );
+        let mut other = header.clone();
+        other.nonce = B64::ZERO;
+        assert_eq!(other.seal_hash(), header.seal_hash());
+        assert_ne!(other.hash_slow(), header.hash_slow());
+    }
+
+    #[test]
+    fn default() {
+        let header = Header::default();
+        assert!(header.transactions_root_is_empty());
+        assert_eq!(Header::decode(&mut &alloy_rlp::encode(&header)[..]).unwrap(), header);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let header = header();
+        let json = serde_json::json!({
+            "parentHash": "0xea1b2bdd513bb6d97cb2fadad17624b218b4f520f3acf675b6f039d6440e1225",
+            "miner": header.coinbase,
+            "stateRoot": "0x6382cada33c26f07e3612bc08891190f138311477b92827f2b0b18392b2208a7",
+            "transactionsRoot": "0xe5f3fcd0e187a0e7e487befbcadcaf0cda58b6bf23f31fc7d112450decdf1474",
+            "receiptsRoot": "0xdf4243fb9d83122bdb6fcae6d2e587331ed6434d6a437e95ac4ae252e1332dcb",
+            "logsBloom": Bloom::ZERO,
+            "difficulty": "0x2f5b3c",
+            "number": "0x12d687",
+            "energyLimit": "0xa037a0",
+            "energyUsed": "0x5208",
+            "timestamp": "0x6553f100",
+            "extraData": "0x636f7265",
+            "nonce": "0x0123456789abcdef",
+        });
+        assert_eq!(serde_json::to_value(&header).unwrap(), json);
+        assert_eq!(serde_json::from_value::<Header>(json.clone()).unwrap(), header);
+
+        // other fields of the JSON-RPC block object are ignored
+        let mut block = json;
+        block["hash"] = serde_json::json!(header.hash_slow());
+        block["size"] = serde_json::json!("0x220");
+        assert_eq!(serde_json::from_value::<Header>(block).unwrap(), header);
+    }
+}
@@ -35,8 +35,8 @@ pub use aliases::{
 #[macro_use]
 mod bits;
 pub use bits::{
-    Address, AddressError, Bloom, BloomInput, FixedBytes, Function, IcanAddress,
-    BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES,
+    Address, AddressError, Bloom, BloomInput, FixedBytes, Function, IcanAddress, IcanDisplay,
+    IcanFormat, Network, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES,
 };
 
 #[path = "bytes/mod.rs"]
@@ -46,9 +46,25 @@ pub use self::bytes_::Bytes;
 mod common;
 pub use common::TxKind;
 
+mod filter;
+pub use filter::{BlockNumberOrTag, Filter, FilterBlockOption, FilterSet, Topic};
+
+mod fixed_point;
+pub use fixed_point::{Fixed, ParseFixedError, Ufixed};
+
+#[cfg(feature = "rlp")]
+mod header;
+#[cfg(feature = "rlp")]
+pub use header::Header;
+
 mod log;
 pub use log::{Log, LogData};
 
+#[cfg(feature = "rlp")]
+mod receipt;
+#[cfg(feature = "rlp")]
+pub use receipt::{Receipt, ReceiptWithBloom};
+
 mod sealed;
 pub use sealed::{Sealable, Sealed};
 
@@ -56,14 +72,32 @@ mod signed;
 pub use signed::{BigIntConversionError, ParseSignedError, Sign, Signed};
 
 mod signature;
-pub use signature::{to_eip155_v, Parity, SignatureError};
+pub use signature::SignatureError;
+#[cfg(feature = "legacy")]
+pub use signature::{to_eip155_v, Parity};
 
 /// Type for ed448 signature
 pub type Signature = signature::Signature;
 
+#[cfg(feature = "signer")]
+pub mod signer;
+#[cfg(feature = "signer")]
+pub use signer::{LocalSigner, SignerError};
+
+#[cfg(feature = "rlp")]
+mod transaction;
+#[cfg(feature = "rlp")]
+pub use transaction::{SignedTx, TxLegacy};
+
+#[cfg(feature = "rlp")]
+pub mod trie;
+
 pub mod utils;
 pub use utils::{eip191_hash_message, sha3, Sha3};
 
+#[cfg(feature = "vanity")]
+pub mod vanity;
+
 #[doc(no_inline)]
 pub use {
     ::bytes,
@@ -76,6 +110,9 @@ pub use {
 #[doc(no_inline)]
 pub use ::hex::serde as serde_hex;
 
+#[cfg(feature = "serde")]
+pub use bits::serde_ican;
+
 /// 20-byte [fixed byte-array][FixedBytes] type.
 ///
 /// You'll likely want to use [`Address`] instead, as it is a different type
@@ -119,6 +156,12 @@ pub mod private {
     #[cfg(feature = "ssz")]
     pub use ssz;
 
+    #[cfg(feature = "borsh")]
+    pub use borsh;
+
+    #[cfg(feature = "scale")]
+    pub use parity_scale_codec;
+
     #[cfg(feature = "serde")]
     pub use serde;
 
@@ -5,6 +5,8 @@ use alloc::vec::Vec;
 #[derive(Clone, Default, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(derive_arbitrary::Arbitrary, proptest_derive::Arbitrary))]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "scale", derive(parity_scale_codec::Encode, parity_scale_codec::Decode))]
 pub struct LogData {
     /// The indexed topic list.
     topics: Vec<B256>,
@@ -85,6 +87,8 @@ impl LogData {
 #[derive(Clone, Default, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(derive_arbitrary::Arbitrary, proptest_derive::Arbitrary))]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "scale", derive(parity_scale_codec::Encode, parity_scale_codec::Decode))]
 pub struct Log<T = LogData> {
     /// The address which emitted this log.
     pub address: IcanAddress,
@@ -196,17 +200,62 @@ impl alloy_rlp::Decodable for Log {
     }
 }
 
-#[cfg(feature = "rlp")]
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_rlp::{Decodable, Encodable};
+
+    #[cfg(any(feature = "borsh", feature = "scale"))]
+    fn log() -> Log {
+        Log::new(
+            crate::cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"),
+            vec![B256::repeat_byte(0x11), B256::repeat_byte(0x22)],
+            crate::bytes!("c0ffee"),
+        )
+        .unwrap()
+    }
 
     #[test]
+    #[cfg(feature = "rlp")]
     fn test_roundtrip_rlp_log_data() {
+        use alloy_rlp::{Decodable, Encodable};
+
         let log = Log::<LogData>::default();
         let mut buf = Vec::<u8>::new();
         log.encode(&mut buf);
         assert_eq!(Log::decode(&mut &buf[..]).unwrap(), log);
     }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn test_roundtrip_borsh() {
+        let log = log();
+        let encoded = borsh::to_vec(&log).unwrap();
+        let mut expected = log.address.to_vec();
+        expected.extend_from_slice(&2u32.to_le_bytes());
+        expected.extend_from_slice(&[0x11; 32]);
+        expected.extend_from_slice(&[0x22; 32]);
+        expected.extend_from_slice(&3u32.to_le_bytes());
+        expected.extend_from_slice(&[0xc0, 0xff, 0xee]);
+        assert_eq!(encoded, expected);
+        assert_eq!(borsh::from_slice::<Log>(&encoded).unwrap(), log);
+        assert_eq!(borsh::from_slice::<LogData>(&encoded[22..]).unwrap(), log.data);
+    }
+
+    #[test]
+    #[cfg(feature = "scale")]
+    fn test_roundtrip_scale() {
+        use parity_scale_codec::{Decode, Encode};
+
+        let log = log();
+        let encoded = log.encode();
+        let mut expected = log.address.to_vec();
+        expected.push(2 << 2);
+        expected.extend_from_slice(&[0x11; 32]);
+        expected.extend_from_slice(&[0x22; 32]);
+        expected.push(3 << 2);
+        expected.extend_from_slice(&[0xc0, 0xff, 0xee]);
+        assert_eq!(encoded, expected);
+        assert_eq!(Log::decode(&mut &encoded[..]).unwrap(), log);
+        assert_eq!(LogData::decode(&mut &encoded[22..]).unwrap(), log.data);
+    }
 }
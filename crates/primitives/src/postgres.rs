@@ -3,7 +3,9 @@
 //! **WARNING**: this module depends entirely on [`postgres_types`, which is not yet stable,
 //! therefore this module is exempt from the semver guarantees of this crate.
 
-use super::{FixedBytes, Sign, Signed};
+use super::{
+    Address, AddressError, Bloom, Bytes, FixedBytes, Function, IcanAddress, Sign, Signature, Signed,
+};
 use bytes::{BufMut, BytesMut};
 use derive_more::{Display, Error};
 use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type, WrongType};
@@ -34,6 +36,163 @@ impl<'a, const BITS: usize> FromSql<'a> for FixedBytes<BITS> {
     }
 }
 
+/// Reads a string column, removing the blank padding of `char(n)` values.
+fn text_from_sql<'a>(ty: &Type, raw: &'a [u8]) -> Result<&'a str, BoxedError> {
+    let s = from_utf8(raw)?;
+    Ok(if *ty == Type::BPCHAR { s.trim_end_matches(' ') } else { s })
+}
+
+/// Implements `ToSql` and `FromSql` for fixed-size byte types that are stored
+/// as raw `BYTEA` or as `0x`-prefixed hex strings in `TEXT`, `VARCHAR` and
+/// `CHAR(n)` columns.
+macro_rules! impl_fixed_bytes_sql {
+    ($($t:ty),+ $(,)?) => {$(
+        impl ToSql for $t {
+            fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxedError> {
+                match *ty {
+                    Type::BYTEA => out.put_slice(&self[..]),
+                    Type::TEXT | Type::VARCHAR | Type::BPCHAR => {
+                        out.put_slice(format!("{self:#x}").as_bytes());
+                    }
+                    _ => return Err(Box::new(WrongType::new::<Self>(ty.clone()))),
+                }
+                Ok(IsNull::No)
+            }
+
+            accepts!(BYTEA, TEXT, VARCHAR, BPCHAR);
+
+            to_sql_checked!();
+        }
+
+        impl<'a> FromSql<'a> for $t {
+            accepts!(BYTEA, TEXT, VARCHAR, BPCHAR);
+
+            fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxedError> {
+                match *ty {
+                    Type::BYTEA => Ok(Self::try_from(raw)?),
+                    _ => Ok(text_from_sql(ty, raw)?.parse()?),
+                }
+            }
+        }
+    )+};
+}
+
+impl_fixed_bytes_sql!(Address, Bloom, Function);
+
+/// Converts [`Bytes`] to Postgres types.
+///
+/// `BYTEA` columns store the raw bytes, and `TEXT`, `VARCHAR` and `CHAR(n)`
+/// columns a `0x`-prefixed hex string.
+impl ToSql for Bytes {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxedError> {
+        match *ty {
+            Type::BYTEA => out.put_slice(self),
+            Type::TEXT | Type::VARCHAR | Type::BPCHAR => {
+                out.put_slice(format!("{self:x}").as_bytes());
+            }
+            _ => return Err(Box::new(WrongType::new::<Self>(ty.clone()))),
+        }
+        Ok(IsNull::No)
+    }
+
+    accepts!(BYTEA, TEXT, VARCHAR, BPCHAR);
+
+    to_sql_checked!();
+}
+
+/// Converts [`Bytes`] from Postgres types.
+impl<'a> FromSql<'a> for Bytes {
+    accepts!(BYTEA, TEXT, VARCHAR, BPCHAR);
+
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxedError> {
+        match *ty {
+            Type::BYTEA => Ok(Self::copy_from_slice(raw)),
+            _ => Ok(text_from_sql(ty, raw)?.parse()?),
+        }
+    }
+}
+
+/// Converts an [`IcanAddress`] to Postgres types.
+///
+/// `BYTEA` columns store the 22 raw bytes. `TEXT`, `VARCHAR` and `CHAR(n)`
+/// columns store the address in its [plain](crate::IcanFormat::Plain) ICAN
+/// form, without a `0x` prefix.
+impl ToSql for IcanAddress {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxedError> {
+        match *ty {
+            Type::BYTEA => out.put_slice(&self[..]),
+            Type::TEXT | Type::VARCHAR | Type::BPCHAR => out.put_slice(self.to_string().as_bytes()),
+            _ => return Err(Box::new(WrongType::new::<Self>(ty.clone()))),
+        }
+        Ok(IsNull::No)
+    }
+
+    accepts!(BYTEA, TEXT, VARCHAR, BPCHAR);
+
+    to_sql_checked!();
+}
+
+/// Converts an [`IcanAddress`] from Postgres types.
+///
+/// Text values may have a `0x` prefix, and their checksum is
+/// [validated](IcanAddress::has_valid_checksum). The only exception is the
+/// zero address, which has no valid checksum digits but is commonly stored as
+/// a placeholder, so that [`IcanAddress::ZERO`] can be read back. The network
+/// prefix is not checked, so that addresses of unregistered networks can be
+/// read back too. Raw `BYTEA` values are taken as they are.
+impl<'a> FromSql<'a> for IcanAddress {
+    accepts!(BYTEA, TEXT, VARCHAR, BPCHAR);
+
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxedError> {
+        match *ty {
+            Type::BYTEA => Ok(Self::try_from(raw)?),
+            _ => {
+                let address: Self = hex::FromHex::from_hex(text_from_sql(ty, raw)?)?;
+                if address.is_zero() || address.has_valid_checksum() {
+                    Ok(address)
+                } else {
+                    Err(Box::new(AddressError::InvalidChecksum))
+                }
+            }
+        }
+    }
+}
+
+/// Converts a [`Signature`] to Postgres types.
+///
+/// `BYTEA` columns store the 171-byte [representation](Signature::as_bytes),
+/// and `TEXT`, `VARCHAR` and `CHAR(n)` columns the same bytes as a
+/// `0x`-prefixed hex string.
+impl ToSql for Signature {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxedError> {
+        match *ty {
+            Type::BYTEA => out.put_slice(&self.as_bytes()),
+            Type::TEXT | Type::VARCHAR | Type::BPCHAR => {
+                out.put_slice(hex::encode_prefixed(self.as_bytes()).as_bytes());
+            }
+            _ => return Err(Box::new(WrongType::new::<Self>(ty.clone()))),
+        }
+        Ok(IsNull::No)
+    }
+
+    accepts!(BYTEA, TEXT, VARCHAR, BPCHAR);
+
+    to_sql_checked!();
+}
+
+/// Converts a [`Signature`] from Postgres types, validating its public key
+/// like [`Signature::from_bytes`].
+impl<'a> FromSql<'a> for Signature {
+    accepts!(BYTEA, TEXT, VARCHAR, BPCHAR);
+
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxedError> {
+        match *ty {
+            Type::BYTEA => Ok(Self::from_bytes(raw)?),
+            _ => Ok(text_from_sql(ty, raw)?.parse()?),
+        }
+    }
+}
+
 // https://github.com/recmo/uint/blob/6c755ad7cd54a0706d20f11f3f63b0d977af0226/src/support/postgres.rs#L22
 
 type BoxedError = Box<dyn Error + Sync + Send + 'static>;
@@ -340,3 +499,111 @@ impl<'a, const BITS: usize, const LIMBS: usize> FromSql<'a> for Signed<BITS, LIM
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cAddress, hex, B256};
+    use core::fmt::Debug;
+
+    const TYPES: [Type; 4] = [Type::BYTEA, Type::TEXT, Type::VARCHAR, Type::BPCHAR];
+
+    fn round_trip<T>(value: &T) -> Vec<(Type, BytesMut)>
+    where
+        T: ToSql + for<'a> FromSql<'a> + PartialEq + Debug,
+    {
+        TYPES
+            .into_iter()
+            .map(|ty| {
+                assert!(<T as ToSql>::accepts(&ty));
+                assert!(<T as FromSql<'_>>::accepts(&ty));
+                let mut out = BytesMut::new();
+                assert!(matches!(value.to_sql(&ty, &mut out).unwrap(), IsNull::No));
+                assert_eq!(T::from_sql(&ty, &out).unwrap(), *value, "{ty}");
+                (ty, out)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ican_address() {
+        let address = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+        let out = round_trip(&address);
+        assert_eq!(out[0].1[..], address[..]);
+        assert_eq!(out[1].1[..], b"cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"[..]);
+
+        for s in [
+            "0xcb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5",
+            "CB82A5FD22B9BEE8B8AB877C86E0A2C21765E1D5BFC5",
+        ] {
+            assert_eq!(IcanAddress::from_sql(&Type::TEXT, s.as_bytes()).unwrap(), address);
+        }
+        let padded = b"cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5    ";
+        assert_eq!(IcanAddress::from_sql(&Type::BPCHAR, padded).unwrap(), address);
+        assert!(IcanAddress::from_sql(&Type::TEXT, padded).is_err());
+
+        // last digit changed, and zero checksum digits on a non-zero address
+        for mistyped in [
+            "cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc4",
+            "cb00a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5",
+            "00000000000000000000000000000000000000000001",
+        ] {
+            let err = IcanAddress::from_sql(&Type::TEXT, mistyped.as_bytes()).unwrap_err();
+            assert!(matches!(err.downcast_ref(), Some(AddressError::InvalidChecksum)));
+        }
+        assert!(IcanAddress::from_sql(&Type::BYTEA, &address[1..]).is_err());
+        assert!(!<IcanAddress as ToSql>::accepts(&Type::INT8));
+        assert!(address.to_sql(&Type::INT8, &mut BytesMut::new()).is_err());
+
+        // valid checksum, unknown network
+        round_trip(&cAddress!("0041a48388222c7ee7daefde5d0b9c99319995c4a990"));
+        // the zero address is not validated
+        round_trip(&IcanAddress::ZERO);
+    }
+
+    #[test]
+    fn fixed_bytes() {
+        let address = Address::new(hex!("d8da6bf26964af9d7eed9e03e53415d37aa96045"));
+        let out = round_trip(&address);
+        assert_eq!(out[1].1[..], b"0xd8da6bf26964af9d7eed9e03e53415d37aa96045"[..]);
+        assert_eq!(
+            Address::from_sql(&Type::TEXT, b"D8DA6BF26964AF9D7EED9E03E53415D37AA96045").unwrap(),
+            address
+        );
+
+        let mut bloom = Bloom::ZERO;
+        bloom.accrue_raw_log(
+            cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"),
+            &[B256::new(hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"))],
+        );
+        round_trip(&bloom);
+        assert!(Bloom::from_sql(&Type::BYTEA, &[0; 255]).is_err());
+
+        let function = Function::new(hex!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5a9059cbb"));
+        round_trip(&function);
+        assert!(Function::from_sql(&Type::TEXT, b"0xa9059cbb").is_err());
+    }
+
+    #[test]
+    fn bytes() {
+        round_trip(&Bytes::new());
+        let bytes = Bytes::from_static(&hex!("deadbeef"));
+        let out = round_trip(&bytes);
+        assert_eq!(out[1].1[..], b"0xdeadbeef"[..]);
+        assert_eq!(Bytes::from_sql(&Type::BPCHAR, b"deadbeef  ").unwrap(), bytes);
+        assert!(Bytes::from_sql(&Type::TEXT, b"0xdeadbee").is_err());
+    }
+
+    #[test]
+    fn signature() {
+        let signature = Signature::from_bytes(&hex!(
+            "ef5caff99a454ff14b60370496b0a49067deb37bde920d2e6dde1ad542a0a7406a67844e83a2b72b71e21376e155d3372c77fc1016e0765680cc6f7b28021af3b711ff8bc3cbf4d0ae3683329c925a8de8384f2c5c778af0e86a76c24a4ac3f9a5b93e9dd61e18c455ce6aa30d40ad303600"
+            "315484db568379ce94f9c894e3e6e4c7ee216676b713ca892d9b26746ae902a772e217a6a8bb493ce2bb313cf0cb66e76765d4c45ec6b68600"
+        ))
+        .unwrap();
+        let out = round_trip(&signature);
+        assert_eq!(out[0].1[..], signature.as_bytes()[..]);
+        assert_eq!(out[1].1.len(), 2 + 2 * 171);
+        assert!(Signature::from_sql(&Type::BYTEA, &out[0].1[..170]).is_err());
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{sha3, Bloom, Log, Sealable, B256};
+use alloc::vec::Vec;
+use alloy_rlp::{length_of_length, BufMut, Decodable, Encodable, Header};
+
+/// The outcome of executing a transaction.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Receipt {
+    /// Whether the transaction succeeded.
+    pub success: bool,
+    /// The energy used in the block up to and including this transaction.
+    pub cumulative_energy_used: u64,
+    /// The logs emitted by the transaction.
+    pub logs: Vec<Log>,
+}
+
+impl Receipt {
+    /// Computes the bloom filter of the logs.
+    pub fn bloom_slow(&self) -> Bloom {
+        self.logs.iter().fold(Bloom::ZERO, |mut bloom, log| {
+            bloom.accrue_log(log);
+            bloom
+        })
+    }
+
+    /// Computes the bloom filter of the logs and attaches it to the receipt.
+    #[inline]
+    pub fn with_bloom(self) -> ReceiptWithBloom {
+        let bloom = self.bloom_slow();
+        ReceiptWithBloom { receipt: self, bloom }
+    }
+}
+
+/// A [`Receipt`] with the bloom filter of its logs, as it is encoded in the
+/// receipts trie.
+///
+/// The RLP encoding is the list of the status, the cumulative energy used, the
+/// bloom filter and the logs. The status is `0x01` for success and empty for
+/// failure.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ReceiptWithBloom {
+    /// The receipt.
+    pub receipt: Receipt,
+    /// The bloom filter of the receipt's logs.
+    pub bloom: Bloom,
+}
+
+impl From<Receipt> for ReceiptWithBloom {
+    #[inline]
+    fn from(receipt: Receipt) -> Self {
+        receipt.with_bloom()
+    }
+}
+
+impl core::ops::Deref for ReceiptWithBloom {
+    type Target = Receipt;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.receipt
+    }
+}
+
+impl ReceiptWithBloom {
+    fn payload_len(&self) -> usize {
+        self.receipt.success.length()
+            + self.receipt.cumulative_energy_used.length()
+            + self.bloom.length()
+            + self.receipt.logs.length()
+    }
+}
+
+impl Encodable for ReceiptWithBloom {
+    fn encode(&self, out: &mut dyn BufMut) {
+        Header { list: true, payload_length: self.payload_len() }.encode(out);
+        self.receipt.success.encode(out);
+        self.receipt.cumulative_energy_used.encode(out);
+        self.bloom.encode(out);
+        self.receipt.logs.encode(out);
+    }
+
+    fn length(&self) -> usize {
+        let payload_length = self.payload_len();
+        payload_length + length_of_length(payload_length)
+    }
+}
+
+impl Decodable for ReceiptWithBloom {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+        let started_len = buf.len();
+        let success = Decodable::decode(buf)?;
+        let cumulative_energy_used = Decodable::decode(buf)?;
+        let bloom = Decodable::decode(buf)?;
+        let logs = Decodable::decode(buf)?;
+        if started_len - buf.len() != header.payload_length {
+            return Err(alloy_rlp::Error::ListLengthMismatch {
+                expected: header.payload_length,
+                got: started_len - buf.len(),
+            });
+        }
+        Ok(Self { receipt: Receipt { success, cumulative_energy_used, logs }, bloom })
+    }
+}
+
+impl Sealable for ReceiptWithBloom {
+    fn hash_slow(&self) -> B256 {
+        sha3(alloy_rlp::encode(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cAddress, hex, trie, Bytes};
+
+    fn receipts() -> [ReceiptWithBloom; 2] {
+        let log = Log::new(
+            cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"),
+            vec![B256::new(hex!(
+                "c17a9d92b89f27cb79cc390f23a1a5d302fefab8c7911075ede952ac2b5607a1"
+            ))],
+            Bytes::from_static(&hex!(
+                "0000000000000000000000000000000000000000000000000de0b6b3a7640000"
+            )),
+        )
+        .unwrap();
+        [
+            Receipt { success: true, cumulative_energy_used: 21000, logs: vec![log] }.into(),
+            Receipt { success: false, cumulative_energy_used: 50000, logs: vec![] }.into(),
+        ]
+    }
+
+    #[test]
+    fn encoding() {
+        let [success, failure] = receipts();
+        assert_eq!(success.bloom, Bloom::new(hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000020000000001000000000000000000000000000000000000000000000000000000000000000000000000000")));
+        assert_eq!(failure.bloom, Bloom::ZERO);
+
+        for (receipt, rlp) in [
+            (success, &hex!("f9016501825208b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000020000000001000000000000000000000000000000000000000000000000000000000000000000000000000f85cf85a96cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5e1a0c17a9d92b89f27cb79cc390f23a1a5d302fefab8c7911075ede952ac2b5607a1a00000000000000000000000000000000000000000000000000de0b6b3a7640000")[..]),
+            (failure, &hex!("f901088082c350b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000c0")[..]),
+        ] {
+            assert_eq!(alloy_rlp::encode(&receipt), rlp);
+            assert_eq!(receipt.length(), rlp.len());
+            assert_eq!(ReceiptWithBloom::decode(&mut &rlp[..]).unwrap(), receipt);
+            assert_eq!(receipt.hash_slow(), sha3(rlp));
+        }
+    }
+
+    #[test]
+    fn receipts_root() {
+        assert_eq!(
+            trie::ordered_trie_root(&receipts()),
+            B256::new(hex!("df4243fb9d83122bdb6fcae6d2e587331ed6434d6a437e95ac4ae252e1332dcb"))
+        );
+    }
+}
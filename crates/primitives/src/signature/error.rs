@@ -1,3 +1,4 @@
+use crate::IcanAddress;
 use core::convert::Infallible;
 
 /// Errors in signature parsing or verification.
@@ -10,10 +11,22 @@ pub enum SignatureError {
     FromHex(hex::FromHexError),
 
     /// Invalid parity.
+    #[cfg(feature = "legacy")]
     InvalidParity(u64),
 
     /// Libgoldilocks error
     Libgoldilocks(libgoldilocks::errors::LibgoldilockErrors),
+
+    /// The public key is not a valid, canonically encoded Ed448 point.
+    InvalidPublicKey,
+
+    /// The signature is valid, but was made by a different address.
+    AddressMismatch {
+        /// The address the signature was expected to be made by.
+        expected: IcanAddress,
+        /// The address derived from the signature's public key.
+        recovered: IcanAddress,
+    },
 }
 
 impl From<hex::FromHexError> for SignatureError {
@@ -37,8 +50,13 @@ impl core::fmt::Display for SignatureError {
         match self {
             Self::FromBytes(e) => f.write_str(e),
             Self::FromHex(e) => e.fmt(f),
+            #[cfg(feature = "legacy")]
             Self::InvalidParity(v) => write!(f, "invalid parity: {v}"),
             Self::Libgoldilocks(e) => e.fmt(f),
+            Self::InvalidPublicKey => f.write_str("invalid Ed448 public key"),
+            Self::AddressMismatch { expected, recovered } => {
+                write!(f, "signature is from {recovered}, expected {expected}")
+            }
         }
     }
 }
@@ -1,11 +1,15 @@
 mod error;
 pub use error::SignatureError;
 
+#[cfg(feature = "legacy")]
 mod parity;
+#[cfg(feature = "legacy")]
 pub use parity::Parity;
 
 mod sig;
 pub(crate) use sig::Signature;
 
+#[cfg(feature = "legacy")]
 mod utils;
+#[cfg(feature = "legacy")]
 pub use utils::to_eip155_v;
@@ -1,21 +1,28 @@
-use crate::{hex, signature::SignatureError, IcanAddress, B1368};
+use crate::{aliases::U448, hex, signature::SignatureError, IcanAddress, B1368};
 use alloc::vec::Vec;
-use core::{net, str::FromStr};
+use core::str::FromStr;
 use libgoldilocks::{
     errors::LibgoldilockErrors,
     goldilocks::{ed448_sign, ed448_verify_with_error},
     PrehashSigner, SigningKey, VerifyingKey,
 };
 
-#[cfg(feature = "arbitrary")]
-use arbitrary::Arbitrary;
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
-/// An Core ECDSA signature.
+/// An Core Ed448 signature.
+///
+/// This is 171 bytes long: the 114-byte Ed448 signature, followed by the
+/// 57-byte public key of the signer, which is needed to recover its address.
+///
+/// # Encoding
+///
+/// - RLP: a single 171-byte string, the same as the `Signature` field of
+///   go-core transactions.
+/// - serde: a `0x`-prefixed hex string in human-readable formats, and raw bytes
+///   otherwise. Deserializing also accepts hex without the prefix and byte
+///   sequences, and validates the public key like [`from_bytes`](Self::from_bytes).
+/// - borsh and SCALE: the 171 bytes, with no length prefix. Decoding validates
+///   the public key.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Signature {
     sig: B1368,
 }
@@ -81,33 +88,47 @@ impl From<crate::Signature> for Vec<u8> {
 
 #[cfg(feature = "rlp")]
 impl crate::Signature {
+    /// Decodes a signature from its RLP string encoding, validating the
+    /// public key.
     pub fn decode_rlp_sig(buf: &mut &[u8]) -> Result<Self, alloy_rlp::Error> {
         use alloy_rlp::Decodable;
 
         let sig: [u8; 171] = Decodable::decode(buf)?;
 
         Self::from_bytes(&sig)
-            .map_err(|_| alloy_rlp::Error::Custom("attempted to decode invalid field element"))
+            .map_err(|_| alloy_rlp::Error::Custom("invalid Ed448 public key in signature"))
     }
 }
 
 impl Signature {
-    pub fn new(sig: B1368) -> Self {
+    /// Length of the Ed448 signature part, in bytes.
+    pub const SIGNATURE_LEN: usize = 114;
+
+    /// Length of the Ed448 public key part, in bytes.
+    pub const PUBLIC_KEY_LEN: usize = 57;
+
+    /// Length of the full signature, in bytes.
+    pub const BYTES_LEN: usize = Self::SIGNATURE_LEN + Self::PUBLIC_KEY_LEN;
+
+    /// Creates a signature from its 171-byte representation, without
+    /// validating the public key.
+    ///
+    /// Prefer [`from_bytes`](Self::from_bytes) for untrusted input.
+    #[inline]
+    pub const fn new(sig: B1368) -> Self {
         Self { sig }
     }
 
-    /// Returns the `r` component of this signature.
+    /// Returns the 171-byte representation of this signature.
     #[inline]
     pub const fn sig(&self) -> B1368 {
         self.sig
     }
 
-    /// Returns the `s` component of this signature.
-
     /// Returns the byte-array representation of this signature.
     ///
-    /// The first 32 bytes are the `r` value, the second 32 bytes the `s` value
-    /// and the final byte is the `v` value in 'Electrum' notation.
+    /// The first 114 bytes are the Ed448 signature and the last 57 bytes the
+    /// public key of the signer.
     #[inline]
     pub fn as_bytes(&self) -> [u8; 171] {
         let mut sig = [0u8; 171];
@@ -115,28 +136,107 @@ impl Signature {
         sig
     }
 
+    /// Creates a signature from its 171-byte representation: the 114-byte
+    /// Ed448 signature followed by the 57-byte public key of the signer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not exactly 171 bytes long, or if the
+    /// public key is not a valid, canonically encoded Ed448 point.
     #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, SignatureError> {
-        let sig = B1368::from_slice(bytes);
+        if bytes.len() != Self::BYTES_LEN {
+            return Err(SignatureError::FromBytes("expected exactly 171 bytes"));
+        }
+        let (signature, public_key) = bytes.split_at(Self::SIGNATURE_LEN);
+        Self::from_parts(signature, public_key)
+    }
+
+    /// Creates a signature from the 114-byte Ed448 signature and the 57-byte
+    /// public key of the signer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either part has the wrong length, or if the public
+    /// key is not a valid, canonically encoded Ed448 point.
+    pub fn from_parts(signature: &[u8], public_key: &[u8]) -> Result<Self, SignatureError> {
+        if signature.len() != Self::SIGNATURE_LEN {
+            return Err(SignatureError::FromBytes("expected a 114 byte signature"));
+        }
+        if public_key.len() != Self::PUBLIC_KEY_LEN {
+            return Err(SignatureError::FromBytes("expected a 57 byte public key"));
+        }
+        if !is_canonical_public_key(public_key) {
+            return Err(SignatureError::InvalidPublicKey);
+        }
+
+        let mut sig = B1368::ZERO;
+        sig[..Self::SIGNATURE_LEN].copy_from_slice(signature);
+        sig[Self::SIGNATURE_LEN..].copy_from_slice(public_key);
         Ok(Self { sig })
     }
 
-    /// Length of RLP RS field encoding
+    /// Returns the 114-byte Ed448 signature.
+    #[inline]
+    pub fn signature_bytes(&self) -> &[u8; 114] {
+        self.sig[..Self::SIGNATURE_LEN].try_into().unwrap()
+    }
+
+    /// Returns the 57-byte Ed448 public key of the signer.
+    ///
+    /// This is not checked against the signature; see [`verify`](Self::verify).
+    #[inline]
+    pub fn public_key(&self) -> &[u8; 57] {
+        self.sig[Self::SIGNATURE_LEN..].try_into().unwrap()
+    }
+
+    /// Verifies that this signature over `prehash` is valid and was made by the
+    /// owner of `expected` on the given network.
+    ///
+    /// Unlike the `recover_*` methods, this never panics on malformed input,
+    /// which makes it suitable for signatures from untrusted sources.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SignatureError::InvalidPublicKey`] if the embedded public key
+    /// is malformed, [`SignatureError::Libgoldilocks`] if the signature does not
+    /// verify, and [`SignatureError::AddressMismatch`] if it was made by a
+    /// different key.
+    pub fn verify(
+        &self,
+        prehash: &crate::B256,
+        expected: &IcanAddress,
+        network_id: u64,
+    ) -> Result<(), SignatureError> {
+        let public_key = self.public_key();
+        if !is_canonical_public_key(public_key) {
+            return Err(SignatureError::InvalidPublicKey);
+        }
+        ed448_verify_with_error(public_key, self.signature_bytes(), prehash.as_slice())
+            .map_err(SignatureError::Libgoldilocks)?;
+
+        let recovered = IcanAddress::from_raw_public_key(public_key, network_id);
+        if recovered != *expected {
+            return Err(SignatureError::AddressMismatch { expected: *expected, recovered });
+        }
+        Ok(())
+    }
+
+    /// Length of the RLP encoding of this signature as a transaction field.
     #[cfg(feature = "rlp")]
     pub fn rlp_len(&self) -> usize {
         alloy_rlp::Encodable::length(&self.sig)
     }
 
-    /// Write R and S to an RLP buffer in progress.
+    /// Writes this signature as a transaction field to an RLP buffer in
+    /// progress.
     #[cfg(feature = "rlp")]
     pub fn write_rlp(&self, out: &mut dyn alloy_rlp::BufMut) {
         alloy_rlp::Encodable::encode(&self.sig, out);
     }
 
-    /// Recovers an [`Address`] from this signature and the given message by first prefixing and
-    /// hashing the message according to [EIP-191](crate::eip191_hash_message).
-    ///
-    /// [`IcanAddress`]: IcanAddress
+    /// Recovers an [`IcanAddress`] from this signature and the given message by first prefixing
+    /// and hashing the message according to [EIP-191](crate::eip191_hash_message).
     #[inline]
     pub fn recover_address_from_msg<T: AsRef<[u8]>>(
         &self,
@@ -146,9 +246,7 @@ impl Signature {
         self.recover_from_msg(msg).map(|vk| IcanAddress::from_public_key(&vk, network_id))
     }
 
-    /// Recovers an [`Address`] from this signature and the given prehashed message.
-    ///
-    /// [`IcanAddress`]: IcanAddress
+    /// Recovers an [`IcanAddress`] from this signature and the given prehashed message.
     #[inline]
     pub fn recover_address_from_prehash(
         &self,
@@ -175,42 +273,148 @@ impl Signature {
         &self,
         prehash: &crate::B256,
     ) -> Result<VerifyingKey, SignatureError> {
-        ed448_verify_with_error(
-            &self.as_bytes()[114..],
-            &self.as_bytes()[..114],
-            prehash.as_slice(),
-        )
-        .map_err(|err| SignatureError::Libgoldilocks(err))?;
+        let public_key = self.public_key();
+        if !is_canonical_public_key(public_key) {
+            return Err(SignatureError::InvalidPublicKey);
+        }
+        ed448_verify_with_error(public_key, self.signature_bytes(), prehash.as_slice())
+            .map_err(SignatureError::Libgoldilocks)?;
 
-        Ok(VerifyingKey::from_bytes(&self.as_bytes()[114..]))
+        Ok(VerifyingKey::from_bytes(public_key))
     }
 }
 
+/// Returns `true` if `public_key` is a canonical [RFC 8032] encoding of an
+/// Ed448 point: the little-endian `y` coordinate must be less than the field
+/// prime `p = 2^448 - 2^224 - 1`, only the sign bit of `x` may be set in the
+/// last byte, and the point must decompress onto the curve.
+///
+/// [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032#section-5.2.3
+fn is_canonical_public_key(public_key: &[u8]) -> bool {
+    let Some((&last, y)) = public_key.split_last() else { return false };
+    if y.len() != 56 || last & 0x7f != 0 {
+        return false;
+    }
+    // `y < p` unless its upper 224 bits are all ones (byte 28 has the low bit
+    // of the upper half) and the lower 224 bits are >= 2^224 - 1.
+    let upper_all_ones = (y[28] | 1) == 0xff && y[29..].iter().all(|&b| b == 0xff);
+    if upper_all_ones && (y[28] == 0xff || y[..28].iter().all(|&b| b == 0xff)) {
+        return false;
+    }
+    decompresses(U448::from_le_slice(y), last & 0x80 != 0)
+}
+
+/// Returns `true` if there is a point `(x, y)` on edwards448 with the given
+/// `y < p` and sign of `x`: `x^2 = (y^2 - 1) / (d y^2 - 1)` must be a square,
+/// and `x = 0` must not have the sign bit set.
+fn decompresses(y: U448, x_is_odd: bool) -> bool {
+    let p = U448::MAX - (U448::from(1) << 224);
+    let d = p - U448::from(39081);
+    let y2 = y.mul_mod(y, p);
+    let u = y2.add_mod(p - U448::from(1), p);
+    let v = d.mul_mod(y2, p).add_mod(p - U448::from(1), p);
+    if u.is_zero() {
+        return !x_is_odd;
+    }
+    let Some(v_inv) = v.inv_mod(p) else { return false };
+    let x2 = u.mul_mod(v_inv, p);
+    x2.pow_mod((p - U448::from(1)) >> 1, p) == U448::from(1)
+}
+
 #[cfg(feature = "rlp")]
 impl alloy_rlp::Encodable for crate::Signature {
+    #[inline]
     fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
-        alloy_rlp::Header { list: true, payload_length: self.rlp_len() }.encode(out);
         self.write_rlp(out);
     }
 
+    #[inline]
     fn length(&self) -> usize {
-        let payload_length = self.rlp_len();
-        payload_length + alloy_rlp::length_of_length(payload_length)
+        self.rlp_len()
     }
 }
 
 #[cfg(feature = "rlp")]
 impl alloy_rlp::Decodable for crate::Signature {
+    #[inline]
     fn decode(buf: &mut &[u8]) -> Result<Self, alloy_rlp::Error> {
-        let header = alloy_rlp::Header::decode(buf)?;
-        let pre_len = buf.len();
-        let decoded = Self::decode_rlp_sig(buf)?;
-        let consumed = pre_len - buf.len();
-        if consumed != header.payload_length {
-            return Err(alloy_rlp::Error::Custom("consumed incorrect number of bytes"));
-        }
+        Self::decode_rlp_sig(buf)
+    }
+}
 
-        Ok(decoded)
+#[cfg(feature = "serde")]
+impl serde::Serialize for crate::Signature {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.sig, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for crate::Signature {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let sig = <B1368 as serde::Deserialize>::deserialize(deserializer)?;
+        Self::from_bytes(sig.as_slice()).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for crate::Signature {
+    #[inline]
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        borsh::BorshSerialize::serialize(&self.sig, writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for crate::Signature {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let sig = <B1368 as borsh::BorshDeserialize>::deserialize_reader(reader)?;
+        Self::from_bytes(sig.as_slice()).map_err(|_| {
+            borsh::io::Error::new(
+                borsh::io::ErrorKind::InvalidData,
+                "invalid Ed448 public key in signature",
+            )
+        })
+    }
+}
+
+#[cfg(feature = "scale")]
+impl parity_scale_codec::Encode for crate::Signature {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        Self::BYTES_LEN
+    }
+
+    #[inline]
+    fn encode_to<O: parity_scale_codec::Output + ?Sized>(&self, dest: &mut O) {
+        self.sig.encode_to(dest);
+    }
+
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        Self::BYTES_LEN
+    }
+}
+
+#[cfg(feature = "scale")]
+impl parity_scale_codec::EncodeLike for crate::Signature {}
+
+#[cfg(feature = "scale")]
+impl parity_scale_codec::Decode for crate::Signature {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        let sig = <B1368 as parity_scale_codec::Decode>::decode(input)?;
+        Self::from_bytes(sig.as_slice()).map_err(|_| "invalid Ed448 public key in signature".into())
+    }
+}
+
+#[cfg(feature = "scale")]
+impl parity_scale_codec::MaxEncodedLen for crate::Signature {
+    #[inline]
+    fn max_encoded_len() -> usize {
+        Self::BYTES_LEN
     }
 }
 
@@ -220,7 +424,7 @@ mod tests {
     use ruint::aliases::U176;
 
     use super::*;
-    use crate::B1368;
+    use crate::{B1368, B256};
     use core::{fmt::Debug, str::FromStr};
 
     #[test]
@@ -241,6 +445,97 @@ mod tests {
         assert_eq!(sig.unwrap().sig().0, inner.0);
     }
 
+    #[test]
+    fn checked_constructors() {
+        let bytes = hex::decode("1e9e2b20b92cc21257764ffccc5e0ad7f9a350d4e6ece497f5856abb1fb244eaf527035814e28ac4d1eb905fd7ee3bc5b8aab5a79a8243f6804ef8b60e89c248473fde7150d43eb03b27623f354cc8965b8cdfe5029ea8a033d3143fe69a1d86c331b41588c336a050e5e6395508ec7e22004c4a20a489260a4f5829c04101e75ac20947d60eb01fbd29a96d48c02639384d2806c4263340153194e7a3638ec2cca39938c1b74be200f080").unwrap();
+        let sig = Signature::from_bytes(&bytes).unwrap();
+        assert_eq!(sig.signature_bytes()[..], bytes[..114]);
+        assert_eq!(sig.public_key()[..], bytes[114..]);
+        assert_eq!(Signature::from_parts(&bytes[..114], &bytes[114..]).unwrap(), sig);
+
+        for len in [0, 114, 170, 172] {
+            let bytes = vec![0; len];
+            assert!(matches!(Signature::from_bytes(&bytes), Err(SignatureError::FromBytes(_))));
+            assert!(matches!(Signature::try_from(&bytes[..]), Err(SignatureError::FromBytes(_))));
+        }
+        assert!(matches!(
+            Signature::from_parts(&bytes[..113], &bytes[114..]),
+            Err(SignatureError::FromBytes(_))
+        ));
+        assert!(matches!(
+            Signature::from_parts(&bytes[..114], &bytes[115..]),
+            Err(SignatureError::FromBytes(_))
+        ));
+
+        // bits other than the sign bit set in the last byte
+        let mut bad = bytes.clone();
+        bad[170] = 0x01;
+        assert!(matches!(Signature::from_bytes(&bad), Err(SignatureError::InvalidPublicKey)));
+
+        // `y` is not reduced
+        let mut bad = bytes.clone();
+        bad[114..170].fill(0xff);
+        assert!(matches!(Signature::from_bytes(&bad), Err(SignatureError::InvalidPublicKey)));
+        bad[114 + 28] = 0xfe;
+        assert!(matches!(Signature::from_bytes(&bad), Err(SignatureError::InvalidPublicKey)));
+        bad[114] = 0xfe;
+        // `y = p - 1` is the point `(0, -1)`, whose `x` cannot be negative
+        assert!(matches!(Signature::from_bytes(&bad), Err(SignatureError::InvalidPublicKey)));
+        bad[170] = 0x00;
+        assert!(Signature::from_bytes(&bad).is_ok());
+
+        // canonically encoded, but `y = 2` is not on the curve
+        let mut off_curve = [0u8; 57];
+        off_curve[0] = 2;
+        assert!(matches!(
+            Signature::from_parts(&bytes[..114], &off_curve),
+            Err(SignatureError::InvalidPublicKey)
+        ));
+        let mut bad = bytes.clone();
+        bad[114..].copy_from_slice(&off_curve);
+        let sig = Signature::new(B1368::from_slice(&bad));
+        let prehash = b256!("5a715dc3d0332f9d07824171d604d0cec9475f4299605e8c588d071a0c6c15cc");
+        let address = cAddress!("cb43bfd3937bfb2cd1b2b36253b43f60a1487ea4af3c");
+        assert!(matches!(sig.verify(&prehash, &address, 1), Err(SignatureError::InvalidPublicKey)));
+
+        // unchecked signatures are rejected on use instead of panicking
+        let mut bad = bytes;
+        bad[170] = 0xff;
+        let sig = Signature::new(B1368::from_slice(&bad));
+        let prehash = b256!("5a715dc3d0332f9d07824171d604d0cec9475f4299605e8c588d071a0c6c15cc");
+        let address = cAddress!("cb43bfd3937bfb2cd1b2b36253b43f60a1487ea4af3c");
+        assert!(matches!(sig.verify(&prehash, &address, 1), Err(SignatureError::InvalidPublicKey)));
+        assert!(matches!(
+            sig.recover_from_prehash(&prehash),
+            Err(SignatureError::InvalidPublicKey)
+        ));
+    }
+
+    #[test]
+    fn verify() {
+        let sig = crate::Signature::from_str(
+            "1e9e2b20b92cc21257764ffccc5e0ad7f9a350d4e6ece497f5856abb1fb244eaf527035814e28ac4d1eb905fd7ee3bc5b8aab5a79a8243f6804ef8b60e89c248473fde7150d43eb03b27623f354cc8965b8cdfe5029ea8a033d3143fe69a1d86c331b41588c336a050e5e6395508ec7e22004c4a20a489260a4f5829c04101e75ac20947d60eb01fbd29a96d48c02639384d2806c4263340153194e7a3638ec2cca39938c1b74be200f080",
+        ).unwrap();
+        let prehash = b256!("5a715dc3d0332f9d07824171d604d0cec9475f4299605e8c588d071a0c6c15cc");
+        let address = cAddress!("cb43bfd3937bfb2cd1b2b36253b43f60a1487ea4af3c");
+
+        sig.verify(&prehash, &address, 1).unwrap();
+
+        let other = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+        assert!(matches!(
+            sig.verify(&prehash, &other, 1),
+            Err(SignatureError::AddressMismatch { recovered, .. }) if recovered == address
+        ));
+        assert!(matches!(
+            sig.verify(&prehash, &address, 3),
+            Err(SignatureError::AddressMismatch { .. })
+        ));
+        assert!(matches!(
+            sig.verify(&B256::ZERO, &address, 1),
+            Err(SignatureError::Libgoldilocks(_))
+        ));
+    }
+
     #[test]
     fn recover_address_from_prehash() {
         let sig = crate::Signature::from_str(
@@ -306,186 +601,97 @@ mod tests {
         assert_eq!(addr, VerifyingKey::from_str("4c4a20a489260a4f5829c04101e75ac20947d60eb01fbd29a96d48c02639384d2806c4263340153194e7a3638ec2cca39938c1b74be200f080"));
     }
 
-    //
-    // #[cfg(feature = "rlp")]
-    // use alloy_rlp::{Decodable, Encodable};
-    //
-    // #[test]
-    // fn signature_from_str() {
-    //     let s1 = crate::Signature::from_str(
-    //         "0xaa231fbe0ed2b5418e6ba7c19bee2522852955ec50996c02a2fe3e71d30ddaf1645baf4823fea7cb4fcc7150842493847cfb6a6d63ab93e8ee928ee3f61f503500"
-    //     ).expect("could not parse 0x-prefixed signature");
-    //
-    //     let s2 = crate::Signature::from_str(
-    //         "aa231fbe0ed2b5418e6ba7c19bee2522852955ec50996c02a2fe3e71d30ddaf1645baf4823fea7cb4fcc7150842493847cfb6a6d63ab93e8ee928ee3f61f503500"
-    //     ).expect("could not parse non-prefixed signature");
-    //
-    //     assert_eq!(s1, s2);
-    // }
-    //
-    // #[cfg(feature = "serde")]
-    // #[test]
-    // fn deserialize_without_parity() {
-    //     let raw_signature_without_y_parity = r#"{
-    //         "r":"0xc569c92f176a3be1a6352dd5005bfc751dcb32f57623dd2a23693e64bf4447b0",
-    //         "s":"0x1a891b566d369e79b7a66eecab1e008831e22daa15f91a0a0cf4f9f28f47ee05",
-    //         "v":"0x1"
-    //     }"#;
-    //
-    //     let signature: crate::Signature =
-    //         serde_json::from_str(raw_signature_without_y_parity).unwrap();
-    //
-    //     let expected = crate::Signature::from_rs_and_parity(
-    //         U256::from_str("0xc569c92f176a3be1a6352dd5005bfc751dcb32f57623dd2a23693e64bf4447b0")
-    //             .unwrap(),
-    //         U256::from_str("0x1a891b566d369e79b7a66eecab1e008831e22daa15f91a0a0cf4f9f28f47ee05")
-    //             .unwrap(),
-    //         1,
-    //     )
-    //     .unwrap();
-    //
-    //     assert_eq!(signature, expected);
-    // }
-    //
-    // #[cfg(feature = "serde")]
-    // #[test]
-    // fn deserialize_with_parity() {
-    //     let raw_signature_with_y_parity = serde_json::json!(
-    //         {
-    //         "r":"0xc569c92f176a3be1a6352dd5005bfc751dcb32f57623dd2a23693e64bf4447b0",
-    //         "s":"0x1a891b566d369e79b7a66eecab1e008831e22daa15f91a0a0cf4f9f28f47ee05",
-    //         "v":"0x1",
-    //         "yParity": "0x1"
-    //     }
-    //     );
-    //
-    //     println!("{raw_signature_with_y_parity}");
-    //     let signature: crate::Signature =
-    //         serde_json::from_value(raw_signature_with_y_parity).unwrap();
-    //
-    //     let expected = crate::Signature::from_rs_and_parity(
-    //         U256::from_str("0xc569c92f176a3be1a6352dd5005bfc751dcb32f57623dd2a23693e64bf4447b0")
-    //             .unwrap(),
-    //         U256::from_str("0x1a891b566d369e79b7a66eecab1e008831e22daa15f91a0a0cf4f9f28f47ee05")
-    //             .unwrap(),
-    //         1,
-    //     )
-    //     .unwrap();
-    //
-    //     assert_eq!(signature, expected);
-    // }
-    //
-    // #[cfg(feature = "serde")]
-    // #[test]
-    // fn serialize_both_parity() {
-    //     // this test should be removed if the struct moves to an enum based on tx type
-    //     let signature = crate::Signature::from_rs_and_parity(
-    //         U256::from_str("0xc569c92f176a3be1a6352dd5005bfc751dcb32f57623dd2a23693e64bf4447b0")
-    //             .unwrap(),
-    //         U256::from_str("0x1a891b566d369e79b7a66eecab1e008831e22daa15f91a0a0cf4f9f28f47ee05")
-    //             .unwrap(),
-    //         1,
-    //     )
-    //     .unwrap();
-    //
-    //     let serialized = serde_json::to_string(&signature).unwrap();
-    //     assert_eq!(
-    //         serialized,
-    //         r#"{"r":"0xc569c92f176a3be1a6352dd5005bfc751dcb32f57623dd2a23693e64bf4447b0","s":"
-    // 0x1a891b566d369e79b7a66eecab1e008831e22daa15f91a0a0cf4f9f28f47ee05","yParity":"0x1"}"#
-    //     );
-    // }
-    //
-    // #[cfg(feature = "serde")]
-    // #[test]
-    // fn serialize_v_only() {
-    //     // this test should be removed if the struct moves to an enum based on tx type
-    //     let signature = crate::Signature::from_rs_and_parity(
-    //         U256::from_str("0xc569c92f176a3be1a6352dd5005bfc751dcb32f57623dd2a23693e64bf4447b0")
-    //             .unwrap(),
-    //         U256::from_str("0x1a891b566d369e79b7a66eecab1e008831e22daa15f91a0a0cf4f9f28f47ee05")
-    //             .unwrap(),
-    //         1,
-    //     )
-    //     .unwrap();
-    //
-    //     let expected =
-    // r#"{"r":"0xc569c92f176a3be1a6352dd5005bfc751dcb32f57623dd2a23693e64bf4447b0","s":"
-    // 0x1a891b566d369e79b7a66eecab1e008831e22daa15f91a0a0cf4f9f28f47ee05","yParity":"0x1"}"#;
-    //
-    //     let serialized = serde_json::to_string(&signature).unwrap();
-    //     assert_eq!(serialized, expected);
-    // }
-    //
-    // #[cfg(feature = "serde")]
-    // #[test]
-    // fn test_bincode_roundtrip() {
-    //     let signature = crate::Signature::from_rs_and_parity(
-    //         U256::from_str("0xc569c92f176a3be1a6352dd5005bfc751dcb32f57623dd2a23693e64bf4447b0")
-    //             .unwrap(),
-    //         U256::from_str("0x1a891b566d369e79b7a66eecab1e008831e22daa15f91a0a0cf4f9f28f47ee05")
-    //             .unwrap(),
-    //         1,
-    //     )
-    //     .unwrap();
-    //
-    //     let bin = bincode::serialize(&signature).unwrap();
-    //     assert_eq!(bincode::deserialize::<crate::Signature>(&bin).unwrap(), signature);
-    // }
-    //
-    // #[cfg(feature = "rlp")]
-    // #[test]
-    // fn signature_rlp_decode() {
-    //     // Given a hex-encoded byte sequence
-    //     let bytes =
-    // crate::hex!("
-    // f84301a048b55bfa915ac795c431978d8a6a992b628d557da5ff759b307d495a36649353a010002cef538bc0c8e21c46080634a93e082408b0ad93f4a7207e63ec5463793d"
-    // );
-    //
-    //     // Decode the byte sequence into a Signature instance
-    //     let result = Signature::decode(&mut &bytes[..]).unwrap();
-    //
-    //     // Assert that the decoded Signature matches the expected Signature
-    //     assert_eq!(
-    //         result,
-    //         Signature::from_str("48b55bfa915ac795c431978d8a6a992b628d557da5ff759b307d495a3664935310002cef538bc0c8e21c46080634a93e082408b0ad93f4a7207e63ec5463793d01").unwrap()
-    //     );
-    // }
-    //
-    // #[cfg(feature = "rlp")]
-    // #[test]
-    // fn signature_rlp_encode() {
-    //     // Given a Signature instance
-    //     let sig =
-    // Signature::from_str("
-    // 48b55bfa915ac795c431978d8a6a992b628d557da5ff759b307d495a36649353efffd310ac743f371de3b9f7f9cb56c0b28ad43601b4ab949f53faa07bd2c8041b"
-    // ).unwrap();
-    //
-    //     // Initialize an empty buffer
-    //     let mut buf = vec![];
-    //
-    //     // Encode the Signature into the buffer
-    //     sig.encode(&mut buf);
-    //
-    //     // Define the expected hex-encoded string
-    //     let expected =
-    // "f8431ba048b55bfa915ac795c431978d8a6a992b628d557da5ff759b307d495a36649353a0efffd310ac743f371de3b9f7f9cb56c0b28ad43601b4ab949f53faa07bd2c804"
-    // ;
-    //
-    //     // Assert that the encoded buffer matches the expected hex-encoded string
-    //     assert_eq!(hex::encode(&buf), expected);
-    // }
-    //
-    // #[cfg(feature = "rlp")]
-    // #[test]
-    // fn signature_rlp_length() {
-    //     // Given a Signature instance
-    //     let sig =
-    // Signature::from_str("
-    // 48b55bfa915ac795c431978d8a6a992b628d557da5ff759b307d495a36649353efffd310ac743f371de3b9f7f9cb56c0b28ad43601b4ab949f53faa07bd2c8041b"
-    // ).unwrap();
-    //
-    //     // Assert that the length of the Signature matches the expected length
-    //     assert_eq!(sig.length(), 69);
-    // }
+    /// The signature from the `sign_prehash` test, used to pin the encodings
+    /// byte for byte.
+    const TEST_SIG: &str = "1e9e2b20b92cc21257764ffccc5e0ad7f9a350d4e6ece497f5856abb1fb244eaf527035814e28ac4d1eb905fd7ee3bc5b8aab5a79a8243f6804ef8b60e89c248473fde7150d43eb03b27623f354cc8965b8cdfe5029ea8a033d3143fe69a1d86c331b41588c336a050e5e6395508ec7e22004c4a20a489260a4f5829c04101e75ac20947d60eb01fbd29a96d48c02639384d2806c4263340153194e7a3638ec2cca39938c1b74be200f080";
+
+    #[cfg(feature = "rlp")]
+    #[test]
+    fn signature_rlp() {
+        use alloy_rlp::{Decodable, Encodable};
+
+        let sig = Signature::from_str(TEST_SIG).unwrap();
+
+        // a plain 171-byte string, with no list header
+        let mut buf = vec![];
+        sig.encode(&mut buf);
+        assert_eq!(hex::encode(&buf[..2]), "b8ab");
+        assert_eq!(hex::encode(&buf[2..]), TEST_SIG);
+        assert_eq!(buf.len(), sig.length());
+        assert_eq!(buf.len(), 173);
+
+        assert_eq!(Signature::decode(&mut buf.as_slice()).unwrap(), sig);
+
+        // truncated
+        assert!(Signature::decode(&mut &buf[..172]).is_err());
+        // wrapped in a list
+        let mut list = vec![];
+        alloy_rlp::Header { list: true, payload_length: buf.len() }.encode(&mut list);
+        list.extend_from_slice(&buf);
+        assert!(Signature::decode(&mut list.as_slice()).is_err());
+        // invalid public key
+        let mut bad = buf.clone();
+        bad[172] = 0x01;
+        assert!(Signature::decode(&mut bad.as_slice()).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn signature_serde() {
+        let sig = Signature::from_str(TEST_SIG).unwrap();
+
+        let json = serde_json::to_string(&sig).unwrap();
+        assert_eq!(json, format!("\"0x{TEST_SIG}\""));
+        assert_eq!(serde_json::from_str::<Signature>(&json).unwrap(), sig);
+        assert_eq!(serde_json::from_str::<Signature>(&format!("\"{TEST_SIG}\"")).unwrap(), sig);
+
+        let bin = bincode::serialize(&sig).unwrap();
+        // length prefix + raw bytes
+        assert_eq!(bin.len(), 8 + 171);
+        assert_eq!(bin[8..], sig.as_bytes());
+        assert_eq!(bincode::deserialize::<Signature>(&bin).unwrap(), sig);
+
+        let mut bad = sig.as_bytes();
+        bad[170] = 0x01;
+        let json = format!("\"0x{}\"", hex::encode(bad));
+        assert!(serde_json::from_str::<Signature>(&json).is_err());
+        assert!(serde_json::from_str::<Signature>("\"0x1234\"").is_err());
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn signature_borsh() {
+        let sig = Signature::from_str(TEST_SIG).unwrap();
+
+        let buf = borsh::to_vec(&sig).unwrap();
+        assert_eq!(hex::encode(&buf), TEST_SIG);
+        assert_eq!(borsh::from_slice::<Signature>(&buf).unwrap(), sig);
+        #[cfg(feature = "serde")]
+        assert_eq!(bincode::serialize(&sig).unwrap()[8..], buf[..]);
+
+        assert!(borsh::from_slice::<Signature>(&buf[..170]).is_err());
+        let mut bad = buf;
+        bad[170] = 0x01;
+        assert!(borsh::from_slice::<Signature>(&bad).is_err());
+    }
+
+    #[cfg(feature = "scale")]
+    #[test]
+    fn signature_scale() {
+        use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+
+        let sig = Signature::from_str(TEST_SIG).unwrap();
+
+        let buf = sig.encode();
+        assert_eq!(hex::encode(&buf), TEST_SIG);
+        assert_eq!(buf.len(), Signature::max_encoded_len());
+        assert_eq!(Signature::decode(&mut buf.as_slice()).unwrap(), sig);
+        #[cfg(feature = "borsh")]
+        assert_eq!(borsh::to_vec(&sig).unwrap(), buf);
+
+        assert!(Signature::decode(&mut &buf[..170]).is_err());
+        let mut bad = buf;
+        bad[170] = 0x01;
+        assert!(Signature::decode(&mut bad.as_slice()).is_err());
+    }
 }
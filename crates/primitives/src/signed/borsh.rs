@@ -0,0 +1,57 @@
+use super::Signed;
+use borsh::{io, BorshDeserialize, BorshSerialize};
+use ruint::Uint;
+
+impl<const BITS: usize, const LIMBS: usize> BorshSerialize for Signed<BITS, LIMBS> {
+    #[inline]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.0.as_le_bytes())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> BorshDeserialize for Signed<BITS, LIMBS> {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = vec![0; Self::BYTES];
+        reader.read_exact(&mut bytes)?;
+        Uint::try_from_le_slice(&bytes).map(Self).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "value is larger than fits the integer")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aliases::{I128, I16, I256, I8};
+    use crate::Signed;
+
+    #[test]
+    fn test_encode_decode_borsh() {
+        macro_rules! run_test {
+            ($ty:ty, $native:ty) => {
+                for n in [0, 1, -1, <$native>::MIN, <$native>::MAX, 42, -42] {
+                    let value = <$ty>::try_from(n).unwrap();
+                    let encoded = borsh::to_vec(&value).unwrap();
+                    assert_eq!(encoded, borsh::to_vec(&n).unwrap());
+                    assert_eq!(borsh::from_slice::<$ty>(&encoded).unwrap(), value);
+                }
+            };
+        }
+
+        run_test!(I8, i8);
+        run_test!(I16, i16);
+        run_test!(I128, i128);
+
+        let value = I256::MIN + I256::try_from(5).unwrap();
+        let encoded = borsh::to_vec(&value).unwrap();
+        assert_eq!(encoded.len(), 32);
+        assert_eq!(borsh::from_slice::<I256>(&encoded).unwrap(), value);
+        assert!(borsh::from_slice::<I256>(&encoded[1..]).is_err());
+
+        // the padding bits of a 12-bit integer must be zero
+        assert_eq!(
+            borsh::from_slice::<Signed<12, 1>>(&[0xff, 0x0f]).unwrap(),
+            Signed::<12, 1>::MINUS_ONE
+        );
+        assert!(borsh::from_slice::<Signed<12, 1>>(&[0xff, 0xff]).is_err());
+    }
+}
@@ -92,19 +92,13 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<i128> for Signed<BITS, LIMBS
     type Error = BigIntConversionError;
 
     fn try_from(value: i128) -> Result<Self, Self::Error> {
-        let u = value as u128;
         if value >= 0 {
-            return Self::try_from(u);
+            return Self::try_from(value as u128);
         }
 
-        // This is a bit messy :(
-        let tc = (!u).wrapping_add(1);
-        let stc = Uint::<128, 2>::saturating_from(tc);
-        let (num, overflow) = Uint::<BITS, LIMBS>::overflowing_from_limbs_slice(stc.as_limbs());
-        if overflow {
-            return Err(BigIntConversionError);
-        }
-        Ok(Self(twos_complement(num)))
+        let abs = Uint::<BITS, LIMBS>::try_from(value.unsigned_abs())
+            .map_err(|_| BigIntConversionError)?;
+        Self::checked_from_sign_and_abs(Sign::Negative, abs).ok_or(BigIntConversionError)
     }
 }
 
@@ -116,13 +110,6 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<Signed<BITS, LIMBS>> for u12
             return Err(BigIntConversionError);
         }
 
-        let saturated = Uint::<BITS, LIMBS>::saturating_from(Self::MAX);
-
-        // if the value is greater than the saturated value, return an error
-        if value > Signed(saturated) {
-            return Err(BigIntConversionError);
-        }
-
         value.into_raw().try_into().map_err(|_| BigIntConversionError)
     }
 }
@@ -172,15 +159,7 @@ macro_rules! impl_conversions {
 
                 #[inline]
                 fn try_from(value: $i) -> Result<Self, Self::Error> {
-                    let uint: $u = value as $u;
-
-                    if value.is_positive() {
-                        return Self::try_from(uint);
-                    }
-
-                    let abs = (!uint).wrapping_add(1);
-                    let tc = twos_complement(Uint::<BITS, LIMBS>::from(abs));
-                    Ok(Self(tc))
+                    Self::try_from(value as i128)
                 }
             }
 
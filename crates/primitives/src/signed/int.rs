@@ -605,6 +605,12 @@ mod tests {
         assert_eq!(I1::try_from(1u8), Err(BigIntConversionError));
         assert_eq!(I1::try_from(1i8), Err(BigIntConversionError));
         assert_eq!(I1::try_from(-1), Ok(I1::MINUS_ONE));
+        assert_eq!(I8::try_from(-128i16), Ok(I8::MIN));
+        assert_eq!(I8::try_from(-129i16), Err(BigIntConversionError));
+        assert_eq!(I8::try_from(-1000i64), Err(BigIntConversionError));
+        assert_eq!(I8::try_from(-129i128), Err(BigIntConversionError));
+        assert_eq!(u64::try_from(I8::MAX), Ok(127));
+        assert_eq!(u128::try_from(I128::MAX), Ok(i128::MAX as u128));
 
         run_test!(I96, U96);
         run_test!(I128, U128);
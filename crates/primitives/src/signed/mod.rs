@@ -14,6 +14,10 @@ pub use int::Signed;
 /// Operation implementations.
 mod ops;
 
+/// `num-traits` support.
+#[cfg(feature = "num-traits")]
+mod num_traits;
+
 /// A simple [`Sign`] enum, for dealing with integer signs.
 mod sign;
 pub use sign::Sign;
@@ -22,5 +26,13 @@ pub use sign::Sign;
 #[cfg(feature = "serde")]
 mod serde;
 
+/// Borsh support.
+#[cfg(feature = "borsh")]
+mod borsh;
+
+/// SCALE codec support.
+#[cfg(feature = "scale")]
+mod scale;
+
 /// Utility functions used in the signed integer implementation.
 pub(crate) mod utils;
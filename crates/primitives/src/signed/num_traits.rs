@@ -0,0 +1,415 @@
+use super::{utils::handle_overflow, ParseSignedError, Sign, Signed};
+use num_traits::{
+    ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub},
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, FromPrimitive,
+    Num, NumCast, One, Pow, Saturating, SaturatingAdd, SaturatingMul, SaturatingSub, ToPrimitive,
+    WrappingAdd, WrappingMul, WrappingNeg, WrappingSub, Zero,
+};
+use ruint::Uint;
+
+impl<const BITS: usize, const LIMBS: usize> Zero for Signed<BITS, LIMBS> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Self::is_zero(self)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> One for Signed<BITS, LIMBS> {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Bounded for Signed<BITS, LIMBS> {
+    #[inline]
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Num for Signed<BITS, LIMBS> {
+    type FromStrRadixErr = ParseSignedError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let (sign, s) = match s.as_bytes().first() {
+            Some(b'+') => (Sign::Positive, &s[1..]),
+            Some(b'-') => (Sign::Negative, &s[1..]),
+            _ => (Sign::Positive, s),
+        };
+        let abs = Uint::<BITS, LIMBS>::from_str_radix(s, radix.into())?;
+        Self::checked_from_sign_and_abs(sign, abs).ok_or(ParseSignedError::IntegerOverflow)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> num_traits::Signed for Signed<BITS, LIMBS> {
+    #[inline]
+    #[track_caller]
+    fn abs(&self) -> Self {
+        Self::abs(*self)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self <= other {
+            Self::ZERO
+        } else {
+            *self - *other
+        }
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        if Self::is_negative(self) {
+            Self::MINUS_ONE
+        } else if Self::is_zero(self) {
+            Self::ZERO
+        } else {
+            Self::ONE
+        }
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        Self::is_positive(self)
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        Self::is_negative(self)
+    }
+}
+
+macro_rules! impl_binary_ops {
+    ($($trait:ident::$method:ident -> $output:ty;)+) => {$(
+        impl<const BITS: usize, const LIMBS: usize> $trait for Signed<BITS, LIMBS> {
+            #[inline]
+            fn $method(&self, rhs: &Self) -> $output {
+                Self::$method(*self, *rhs)
+            }
+        }
+    )+};
+}
+
+impl_binary_ops! {
+    CheckedAdd::checked_add -> Option<Self>;
+    CheckedSub::checked_sub -> Option<Self>;
+    CheckedMul::checked_mul -> Option<Self>;
+    CheckedDiv::checked_div -> Option<Self>;
+    CheckedRem::checked_rem -> Option<Self>;
+    SaturatingAdd::saturating_add -> Self;
+    SaturatingSub::saturating_sub -> Self;
+    SaturatingMul::saturating_mul -> Self;
+    WrappingAdd::wrapping_add -> Self;
+    WrappingSub::wrapping_sub -> Self;
+    WrappingMul::wrapping_mul -> Self;
+    OverflowingAdd::overflowing_add -> (Self, bool);
+    OverflowingSub::overflowing_sub -> (Self, bool);
+    OverflowingMul::overflowing_mul -> (Self, bool);
+}
+
+impl<const BITS: usize, const LIMBS: usize> CheckedNeg for Signed<BITS, LIMBS> {
+    #[inline]
+    fn checked_neg(&self) -> Option<Self> {
+        Self::checked_neg(*self)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> WrappingNeg for Signed<BITS, LIMBS> {
+    #[inline]
+    fn wrapping_neg(&self) -> Self {
+        Self::wrapping_neg(*self)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Saturating for Signed<BITS, LIMBS> {
+    #[inline]
+    fn saturating_add(self, rhs: Self) -> Self {
+        Self::saturating_add(self, rhs)
+    }
+
+    #[inline]
+    fn saturating_sub(self, rhs: Self) -> Self {
+        Self::saturating_sub(self, rhs)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToPrimitive for Signed<BITS, LIMBS> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(*self).ok()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(*self).ok()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(*self).ok()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(*self).ok()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromPrimitive for Signed<BITS, LIMBS> {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> NumCast for Signed<BITS, LIMBS> {
+    #[inline]
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        match n.to_i128() {
+            Some(n) => Self::from_i128(n),
+            None => n.to_u128().and_then(Self::from_u128),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Pow<Uint<BITS, LIMBS>> for Signed<BITS, LIMBS> {
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn pow(self, exp: Uint<BITS, LIMBS>) -> Self {
+        Self::pow(self, exp)
+    }
+}
+
+macro_rules! impl_pow {
+    ($($t:ty),+) => {$(
+        impl<const BITS: usize, const LIMBS: usize> Pow<$t> for Signed<BITS, LIMBS> {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn pow(self, exp: $t) -> Self {
+                handle_overflow(overflowing_pow(self, exp as u64))
+            }
+        }
+    )+};
+}
+
+impl_pow!(u8, u16, u32, u64, usize);
+
+/// Like [`Signed::overflowing_pow`], but with an exponent that may not fit in
+/// `BITS` bits.
+fn overflowing_pow<const BITS: usize, const LIMBS: usize>(
+    base: Signed<BITS, LIMBS>,
+    mut exp: u64,
+) -> (Signed<BITS, LIMBS>, bool) {
+    if BITS == 0 {
+        return (base, false);
+    }
+
+    let sign = if exp % 2 == 1 { base.sign() } else { Sign::Positive };
+    let mut base = base.unsigned_abs();
+    let mut abs = Uint::<BITS, LIMBS>::from(1);
+    let mut overflow = false;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            let (result, o) = abs.overflowing_mul(base);
+            abs = result;
+            overflow |= o;
+        }
+        exp /= 2;
+        if exp > 0 {
+            let (result, o) = base.overflowing_mul(base);
+            base = result;
+            overflow |= o;
+        }
+    }
+
+    let (result, overflow_conv) = Signed::overflowing_from_sign_and_abs(sign, abs);
+    (result, overflow || overflow_conv)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases::{I128, I16, I32, I64, I8};
+    use num_traits::Signed as _;
+    use proptest::proptest;
+
+    // Compares the implementations for types of up to 128 bits with the same
+    // operations on `i128`, narrowed to the range of the type.
+    macro_rules! against_i128 {
+        ($($name:ident: $ty:ty;)+) => {$(
+            mod $name {
+                use super::*;
+
+                type T = $ty;
+                const SHIFT: u32 = 128 - T::BITS as u32;
+                const MIN: i128 = i128::MIN >> SHIFT;
+                const MAX: i128 = i128::MAX >> SHIFT;
+
+                fn int(x: i128) -> T {
+                    T::from_i128(x).unwrap()
+                }
+
+                fn to_i128(x: T) -> i128 {
+                    x.to_i128().unwrap()
+                }
+
+                fn checked(x: Option<i128>) -> Option<i128> {
+                    x.filter(|x| (MIN..=MAX).contains(x))
+                }
+
+                const fn wrapped(x: i128) -> i128 {
+                    (x << SHIFT) >> SHIFT
+                }
+
+                fn saturated(x: i128) -> i128 {
+                    x.clamp(MIN, MAX)
+                }
+
+                fn radix_string(x: i128, radix: u32) -> String {
+                    let sign = if x < 0 { "-" } else { "" };
+                    match radix {
+                        2 => format!("{sign}{:b}", x.unsigned_abs()),
+                        8 => format!("{sign}{:o}", x.unsigned_abs()),
+                        16 => format!("{sign}{:x}", x.unsigned_abs()),
+                        _ => x.to_string(),
+                    }
+                }
+
+                proptest! {
+                    #[test]
+                    fn conversions(a: i128, radix in proptest::sample::select(vec![2u32, 8, 10, 16])) {
+                        assert_eq!(T::from_i128(a).map(to_i128), checked(Some(a)));
+                        assert_eq!(T::from_i64(a as i64).map(to_i128), checked(Some(a as i64 as i128)));
+                        assert_eq!(T::from_u128(a as u128).map(to_i128), checked(i128::try_from(a as u128).ok()));
+                        assert_eq!(<T as NumCast>::from(a).map(to_i128), checked(Some(a)));
+                        assert_eq!(<T as NumCast>::from(a as u128).map(to_i128), checked(i128::try_from(a as u128).ok()));
+                        assert_eq!(
+                            T::from_str_radix(&radix_string(a, radix), radix).ok().map(to_i128),
+                            checked(Some(a))
+                        );
+
+                        let a = wrapped(a);
+                        let x = int(a);
+                        assert_eq!(to_i128(x), a);
+                        assert_eq!(x.to_string(), a.to_string());
+                        assert_eq!(x.to_i32(), i32::try_from(a).ok());
+                        assert_eq!(x.to_i64(), i64::try_from(a).ok());
+                        assert_eq!(x.to_u64(), u64::try_from(a).ok());
+                        assert_eq!(x.to_u128(), u128::try_from(a).ok());
+                    }
+
+                    #[test]
+                    fn arithmetic(a: i128, b: i128, sa in 0..128u32, sb in 0..128u32) {
+                        // shift the operands so that small values are common
+                        let (a, b) = (wrapped(a) >> sa, wrapped(b) >> sb);
+                        let (x, y) = (int(a), int(b));
+
+                        assert_eq!(CheckedAdd::checked_add(&x, &y).map(to_i128), checked(a.checked_add(b)));
+                        assert_eq!(CheckedSub::checked_sub(&x, &y).map(to_i128), checked(a.checked_sub(b)));
+                        assert_eq!(CheckedMul::checked_mul(&x, &y).map(to_i128), checked(a.checked_mul(b)));
+                        assert_eq!(CheckedDiv::checked_div(&x, &y).map(to_i128), checked(a.checked_div(b)));
+                        assert_eq!(
+                            CheckedRem::checked_rem(&x, &y).map(to_i128),
+                            if a == MIN && b == -1 { None } else { a.checked_rem(b) }
+                        );
+                        assert_eq!(CheckedNeg::checked_neg(&x).map(to_i128), checked(a.checked_neg()));
+
+                        assert_eq!(to_i128(SaturatingAdd::saturating_add(&x, &y)), saturated(a.saturating_add(b)));
+                        assert_eq!(to_i128(SaturatingSub::saturating_sub(&x, &y)), saturated(a.saturating_sub(b)));
+                        assert_eq!(to_i128(SaturatingMul::saturating_mul(&x, &y)), saturated(a.saturating_mul(b)));
+                        assert_eq!(to_i128(Saturating::saturating_add(x, y)), saturated(a.saturating_add(b)));
+                        assert_eq!(to_i128(Saturating::saturating_sub(x, y)), saturated(a.saturating_sub(b)));
+
+                        assert_eq!(to_i128(WrappingAdd::wrapping_add(&x, &y)), wrapped(a.wrapping_add(b)));
+                        assert_eq!(to_i128(WrappingSub::wrapping_sub(&x, &y)), wrapped(a.wrapping_sub(b)));
+                        assert_eq!(to_i128(WrappingMul::wrapping_mul(&x, &y)), wrapped(a.wrapping_mul(b)));
+                        assert_eq!(to_i128(WrappingNeg::wrapping_neg(&x)), wrapped(a.wrapping_neg()));
+
+                        let (sum, overflow) = OverflowingAdd::overflowing_add(&x, &y);
+                        assert_eq!((to_i128(sum), overflow), (wrapped(a.wrapping_add(b)), checked(a.checked_add(b)).is_none()));
+                        let (diff, overflow) = OverflowingSub::overflowing_sub(&x, &y);
+                        assert_eq!((to_i128(diff), overflow), (wrapped(a.wrapping_sub(b)), checked(a.checked_sub(b)).is_none()));
+                        let (product, overflow) = OverflowingMul::overflowing_mul(&x, &y);
+                        assert_eq!((to_i128(product), overflow), (wrapped(a.wrapping_mul(b)), checked(a.checked_mul(b)).is_none()));
+
+                        if a != MIN {
+                            assert_eq!(to_i128(num_traits::Signed::abs(&x)), a.abs());
+                        }
+                        if let Some(diff) = checked(a.checked_sub(b)) {
+                            assert_eq!(to_i128(x.abs_sub(&y)), diff.max(0));
+                        }
+                        assert_eq!(to_i128(x.signum()), a.signum());
+                        assert_eq!(num_traits::Signed::is_positive(&x), a.is_positive());
+                        assert_eq!(num_traits::Signed::is_negative(&x), a.is_negative());
+                    }
+
+                    #[test]
+                    fn pow(a: i128, s in 0..128u32, exp in 0..256u32) {
+                        let a = wrapped(a) >> s;
+                        let x = int(a);
+
+                        let (power, overflow) = overflowing_pow(x, exp.into());
+                        assert_eq!(to_i128(power), wrapped(a.wrapping_pow(exp)));
+                        assert_eq!(overflow, checked(a.checked_pow(exp)).is_none());
+                        if !overflow {
+                            assert_eq!(to_i128(Pow::pow(x, exp)), to_i128(power));
+                            assert_eq!(to_i128(Pow::pow(x, exp as u8)), to_i128(power));
+                        }
+                    }
+                }
+            }
+        )+};
+    }
+
+    against_i128! {
+        int8: I8;
+        int16: I16;
+        int24: Signed<24, 1>;
+        int32: I32;
+        int64: I64;
+        int96: Signed<96, 2>;
+        int128: I128;
+    }
+
+    #[test]
+    fn identities() {
+        assert_eq!(I128::zero(), I128::ZERO);
+        assert!(Zero::is_zero(&I128::zero()));
+        assert_eq!(I128::one(), I128::ONE);
+        assert_eq!(I8::min_value(), I8::MIN);
+        assert_eq!(I8::max_value(), I8::MAX);
+        assert_eq!(I8::min_value().to_i128(), Some(i8::MIN.into()));
+        assert_eq!(I8::max_value().to_i128(), Some(i8::MAX.into()));
+    }
+}
@@ -0,0 +1,91 @@
+use super::Signed;
+use parity_scale_codec::{
+    ConstEncodedLen, Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output,
+};
+use ruint::Uint;
+
+impl<const BITS: usize, const LIMBS: usize> Encode for Signed<BITS, LIMBS> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        Self::BYTES
+    }
+
+    #[inline]
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        dest.write(&self.0.as_le_bytes());
+    }
+
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        Self::BYTES
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> EncodeLike for Signed<BITS, LIMBS> {}
+
+impl<const BITS: usize, const LIMBS: usize> Decode for Signed<BITS, LIMBS> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let mut bytes = vec![0; Self::BYTES];
+        input.read(&mut bytes)?;
+        Uint::try_from_le_slice(&bytes)
+            .map(Self)
+            .ok_or_else(|| "value is larger than fits the integer".into())
+    }
+
+    #[inline]
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(Self::BYTES)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> MaxEncodedLen for Signed<BITS, LIMBS> {
+    #[inline]
+    fn max_encoded_len() -> usize {
+        Self::BYTES
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ConstEncodedLen for Signed<BITS, LIMBS> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases::{I128, I16, I256, I8};
+
+    #[test]
+    fn test_encode_decode_scale() {
+        macro_rules! run_test {
+            ($ty:ty, $native:ty) => {
+                for n in [0, 1, -1, <$native>::MIN, <$native>::MAX, 42, -42] {
+                    let value = <$ty>::try_from(n).unwrap();
+                    let encoded = value.encode();
+                    assert_eq!(encoded, n.encode());
+                    assert_eq!(encoded.len(), <$ty>::max_encoded_len());
+                    assert_eq!(<$ty>::decode(&mut &encoded[..]).unwrap(), value);
+                }
+            };
+        }
+
+        run_test!(I8, i8);
+        run_test!(I16, i16);
+        run_test!(I128, i128);
+
+        let value = I256::MIN + I256::try_from(5).unwrap();
+        let encoded = value.encode();
+        assert_eq!(encoded.len(), 32);
+        assert_eq!(I256::decode(&mut &encoded[..]).unwrap(), value);
+        assert!(I256::decode(&mut &encoded[1..]).is_err());
+
+        // the padding bits of a 12-bit integer must be zero
+        assert_eq!(Signed::<12, 1>::decode(&mut &[0xff, 0x0f][..]).unwrap(), Signed::MINUS_ONE);
+        assert!(Signed::<12, 1>::decode(&mut &[0xff, 0xff][..]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn test_same_as_borsh() {
+        for value in [I256::MIN, I256::MINUS_ONE, I256::ZERO, I256::MAX] {
+            assert_eq!(value.encode(), borsh::to_vec(&value).unwrap());
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{AddressError, IcanAddress, SignatureError};
+use alloc::string::String;
+
+/// Errors in key management, keystore handling or signing.
+#[derive(Debug)]
+pub enum SignerError {
+    /// Error creating or verifying a signature.
+    Signature(SignatureError),
+
+    /// The keystore address does not belong to a known network.
+    Address(AddressError),
+
+    /// The private key is not 57 bytes long.
+    InvalidKeyLength(usize),
+
+    /// The keystore version is not supported. Only version 3 is.
+    UnsupportedVersion(u64),
+
+    /// The keystore cipher is not supported. Only `aes-128-ctr` is.
+    UnsupportedCipher(String),
+
+    /// The PBKDF2 pseudo-random function is not supported. Only `hmac-sha256`
+    /// is.
+    UnsupportedPrf(String),
+
+    /// The key derivation parameters are invalid.
+    InvalidKdfParams(&'static str),
+
+    /// The keystore MAC does not match, which usually means that the password
+    /// is wrong.
+    MacMismatch,
+
+    /// The decrypted key does not belong to the address stored in the
+    /// keystore.
+    AddressMismatch {
+        /// The address stored in the keystore.
+        expected: IcanAddress,
+        /// The address derived from the decrypted key.
+        derived: IcanAddress,
+    },
+
+    /// Error getting randomness for a key, salt or IV.
+    Random(getrandom::Error),
+
+    /// Error (de)serializing a keystore.
+    Json(serde_json::Error),
+
+    /// Error reading or writing a keystore file.
+    Io(std::io::Error),
+}
+
+impl From<SignatureError> for SignerError {
+    fn from(err: SignatureError) -> Self {
+        Self::Signature(err)
+    }
+}
+
+impl From<AddressError> for SignerError {
+    fn from(err: AddressError) -> Self {
+        Self::Address(err)
+    }
+}
+
+impl From<getrandom::Error> for SignerError {
+    fn from(err: getrandom::Error) -> Self {
+        Self::Random(err)
+    }
+}
+
+impl From<serde_json::Error> for SignerError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+impl From<std::io::Error> for SignerError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl std::error::Error for SignerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Signature(e) => Some(e),
+            Self::Address(e) => Some(e),
+            Self::Json(e) => Some(e),
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for SignerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Signature(e) => e.fmt(f),
+            Self::Address(e) => e.fmt(f),
+            Self::InvalidKeyLength(len) => {
+                write!(f, "invalid private key length: expected 57 bytes, got {len}")
+            }
+            Self::UnsupportedVersion(v) => write!(f, "unsupported keystore version: {v}"),
+            Self::UnsupportedCipher(c) => write!(f, "unsupported keystore cipher: {c}"),
+            Self::UnsupportedPrf(prf) => write!(f, "unsupported PBKDF2 PRF: {prf}"),
+            Self::InvalidKdfParams(e) => write!(f, "invalid key derivation parameters: {e}"),
+            Self::MacMismatch => f.write_str("keystore MAC mismatch, wrong password?"),
+            Self::AddressMismatch { expected, derived } => {
+                write!(f, "keystore is for {expected}, but the key belongs to {derived}")
+            }
+            Self::Random(e) => e.fmt(f),
+            Self::Json(e) => e.fmt(f),
+            Self::Io(e) => e.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,447 @@
+//! go-core compatible JSON keystores.
+//!
+//! See [`Keystore`] for the format.
+
+use super::SignerError;
+use crate::{FixedBytes, IcanAddress, Sha3, B256};
+use aes::{
+    cipher::{KeyIvInit, StreamCipher},
+    Aes128,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+type Aes128Ctr = ctr::Ctr128BE<Aes128>;
+
+/// The only supported keystore version.
+pub const KEYSTORE_VERSION: u64 = 3;
+
+/// The only supported keystore cipher.
+pub const KEYSTORE_CIPHER: &str = "aes-128-ctr";
+
+/// The only supported PBKDF2 pseudo-random function.
+pub const PBKDF2_PRF: &str = "hmac-sha256";
+
+/// The only supported derived key length, in bytes.
+pub const DERIVED_KEY_LEN: u32 = 32;
+
+/// The largest accepted scrypt `N`, four times go-core's standard cost.
+pub const MAX_SCRYPT_N: u32 = 1 << 20;
+
+/// The largest accepted scrypt `r`. With [`MAX_SCRYPT_N`], scrypt needs at
+/// most 1 GiB of memory.
+pub const MAX_SCRYPT_R: u32 = 8;
+
+/// The largest accepted scrypt `p`.
+pub const MAX_SCRYPT_P: u32 = 16;
+
+/// The largest accepted PBKDF2 iteration count.
+pub const MAX_PBKDF2_C: u32 = 10_000_000;
+
+/// A version 3 JSON keystore, as written by go-core's `accounts/keystore`.
+///
+/// The private key is encrypted with AES-128-CTR, using the first 16 bytes of
+/// a key derived from the password with scrypt or PBKDF2. The MAC is the
+/// SHA3-256 hash of the second 16 bytes of the derived key, followed by the
+/// ciphertext.
+///
+/// Binary fields are hex-encoded without a `0x` prefix, and the address is the
+/// full 22-byte ICAN address, so the network can be read from the file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    /// The address of the encrypted key.
+    #[serde(with = "bare_hex")]
+    pub address: IcanAddress,
+    /// The encrypted key and the parameters needed to decrypt it.
+    pub crypto: KeystoreCrypto,
+    /// A random UUID identifying this keystore.
+    pub id: String,
+    /// The keystore version. Always 3.
+    pub version: u64,
+}
+
+/// The `crypto` section of a [`Keystore`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeystoreCrypto {
+    /// The cipher. Always `aes-128-ctr`.
+    pub cipher: String,
+    /// The encrypted private key.
+    #[serde(with = "bare_hex")]
+    pub ciphertext: Vec<u8>,
+    /// The cipher parameters.
+    pub cipherparams: CipherParams,
+    /// The key derivation function and its parameters.
+    #[serde(flatten)]
+    pub kdf: KdfParams,
+    /// The MAC of the derived key and the ciphertext.
+    #[serde(with = "bare_hex")]
+    pub mac: B256,
+}
+
+/// The AES-128-CTR parameters of a [`Keystore`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CipherParams {
+    /// The initial counter value.
+    #[serde(with = "bare_hex")]
+    pub iv: FixedBytes<16>,
+}
+
+/// The key derivation function of a [`Keystore`], stored as the `kdf` and
+/// `kdfparams` fields.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kdf", content = "kdfparams", rename_all = "lowercase")]
+pub enum KdfParams {
+    /// scrypt, used by go-core by default.
+    Scrypt(ScryptParams),
+    /// PBKDF2, which go-core can only decrypt.
+    Pbkdf2(Pbkdf2Params),
+}
+
+/// scrypt key derivation parameters.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScryptParams {
+    /// The length of the derived key. Always 32.
+    pub dklen: u32,
+    /// The CPU/memory cost. Must be a power of two, at most [`MAX_SCRYPT_N`].
+    pub n: u32,
+    /// The parallelization parameter. At most [`MAX_SCRYPT_P`].
+    pub p: u32,
+    /// The block size. At most [`MAX_SCRYPT_R`].
+    pub r: u32,
+    /// The salt.
+    #[serde(with = "bare_hex")]
+    pub salt: Vec<u8>,
+}
+
+/// PBKDF2 key derivation parameters.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pbkdf2Params {
+    /// The iteration count. At most [`MAX_PBKDF2_C`].
+    pub c: u32,
+    /// The length of the derived key. Always 32.
+    pub dklen: u32,
+    /// The pseudo-random function. Always `hmac-sha256`.
+    pub prf: String,
+    /// The salt.
+    #[serde(with = "bare_hex")]
+    pub salt: Vec<u8>,
+}
+
+impl KdfParams {
+    /// scrypt with go-core's standard parameters: `N = 2^18`, `r = 8`, `p = 1`,
+    /// and a random 32-byte salt.
+    pub fn scrypt() -> Result<Self, SignerError> {
+        Self::scrypt_with(1 << 18, 8, 1)
+    }
+
+    /// scrypt with go-core's light parameters: `N = 2^12`, `r = 8`, `p = 6`,
+    /// and a random 32-byte salt.
+    ///
+    /// This uses much less memory and CPU time, and is therefore also much
+    /// easier to brute-force.
+    pub fn scrypt_light() -> Result<Self, SignerError> {
+        Self::scrypt_with(1 << 12, 8, 6)
+    }
+
+    /// scrypt with the given parameters and a random 32-byte salt.
+    pub fn scrypt_with(n: u32, r: u32, p: u32) -> Result<Self, SignerError> {
+        let salt = B256::try_random()?.to_vec();
+        Ok(Self::Scrypt(ScryptParams { dklen: DERIVED_KEY_LEN, n, p, r, salt }))
+    }
+
+    /// PBKDF2-HMAC-SHA256 with the given iteration count and a random 32-byte
+    /// salt.
+    pub fn pbkdf2(c: u32) -> Result<Self, SignerError> {
+        let salt = B256::try_random()?.to_vec();
+        Ok(Self::Pbkdf2(Pbkdf2Params {
+            c,
+            dklen: DERIVED_KEY_LEN,
+            prf: PBKDF2_PRF.to_string(),
+            salt,
+        }))
+    }
+
+    /// Derives the encryption key from the password.
+    ///
+    /// The parameters are checked before deriving, so that an untrusted
+    /// keystore cannot make this allocate or compute without bound.
+    fn derive_key(&self, password: &[u8]) -> Result<Zeroizing<Vec<u8>>, SignerError> {
+        match self {
+            Self::Scrypt(params) => {
+                if !params.n.is_power_of_two() || params.n < 2 {
+                    return Err(SignerError::InvalidKdfParams("scrypt N must be a power of two"));
+                }
+                if params.n > MAX_SCRYPT_N || params.r > MAX_SCRYPT_R || params.p > MAX_SCRYPT_P {
+                    return Err(SignerError::InvalidKdfParams("scrypt cost is too high"));
+                }
+                let mut key = derived_key_buf(params.dklen)?;
+                let log_n = params.n.trailing_zeros() as u8;
+                let scrypt_params = scrypt::Params::new(log_n, params.r, params.p, key.len())
+                    .map_err(|_| SignerError::InvalidKdfParams("invalid scrypt parameters"))?;
+                scrypt::scrypt(password, &params.salt, &scrypt_params, &mut key)
+                    .map_err(|_| SignerError::InvalidKdfParams("invalid scrypt key length"))?;
+                Ok(key)
+            }
+            Self::Pbkdf2(params) => {
+                if params.prf != PBKDF2_PRF {
+                    return Err(SignerError::UnsupportedPrf(params.prf.clone()));
+                }
+                if params.c > MAX_PBKDF2_C {
+                    return Err(SignerError::InvalidKdfParams(
+                        "PBKDF2 iteration count is too high",
+                    ));
+                }
+                let mut key = derived_key_buf(params.dklen)?;
+                pbkdf2::pbkdf2_hmac::<Sha256>(password, &params.salt, params.c, &mut key);
+                Ok(key)
+            }
+        }
+    }
+}
+
+impl Keystore {
+    /// Encrypts `secret` with the password, using the given key derivation
+    /// function and a random IV and id.
+    ///
+    /// `address` is stored as-is, it is not checked to belong to `secret`.
+    pub fn encrypt(
+        secret: &[u8],
+        address: IcanAddress,
+        password: impl AsRef<[u8]>,
+        kdf: KdfParams,
+    ) -> Result<Self, SignerError> {
+        let iv = FixedBytes::try_random()?;
+        let id = uuid_v4(FixedBytes::try_random()?);
+        Self::encrypt_with(secret, address, password.as_ref(), kdf, iv, id)
+    }
+
+    fn encrypt_with(
+        secret: &[u8],
+        address: IcanAddress,
+        password: &[u8],
+        kdf: KdfParams,
+        iv: FixedBytes<16>,
+        id: String,
+    ) -> Result<Self, SignerError> {
+        let key = kdf.derive_key(password)?;
+        let mut ciphertext = secret.to_vec();
+        Aes128Ctr::new(key[..16].into(), iv.as_slice().into()).apply_keystream(&mut ciphertext);
+        let mac = mac(&key, &ciphertext);
+        Ok(Self {
+            address,
+            crypto: KeystoreCrypto {
+                cipher: KEYSTORE_CIPHER.to_string(),
+                ciphertext,
+                cipherparams: CipherParams { iv },
+                kdf,
+                mac,
+            },
+            id,
+            version: KEYSTORE_VERSION,
+        })
+    }
+
+    /// Decrypts the secret with the password.
+    ///
+    /// The secret is zeroized when dropped. This does not check that the secret belongs to [`address`](Self::address);
+    /// [`LocalSigner::from_keystore`](super::LocalSigner::from_keystore) does.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SignerError::MacMismatch`] if the password is wrong, and an
+    /// error if the keystore version, cipher or key derivation function is not
+    /// supported.
+    pub fn decrypt(&self, password: impl AsRef<[u8]>) -> Result<Zeroizing<Vec<u8>>, SignerError> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(SignerError::UnsupportedVersion(self.version));
+        }
+        if self.crypto.cipher != KEYSTORE_CIPHER {
+            return Err(SignerError::UnsupportedCipher(self.crypto.cipher.clone()));
+        }
+
+        let key = self.crypto.kdf.derive_key(password.as_ref())?;
+        let mac = mac(&key, &self.crypto.ciphertext);
+        if !bool::from(mac.as_slice().ct_eq(self.crypto.mac.as_slice())) {
+            return Err(SignerError::MacMismatch);
+        }
+
+        let mut secret = Zeroizing::new(self.crypto.ciphertext.clone());
+        Aes128Ctr::new(key[..16].into(), self.crypto.cipherparams.iv.as_slice().into())
+            .apply_keystream(&mut secret);
+        Ok(secret)
+    }
+}
+
+fn derived_key_buf(dklen: u32) -> Result<Zeroizing<Vec<u8>>, SignerError> {
+    if dklen != DERIVED_KEY_LEN {
+        return Err(SignerError::InvalidKdfParams("derived key must be 32 bytes"));
+    }
+    Ok(Zeroizing::new(vec![0; DERIVED_KEY_LEN as usize]))
+}
+
+fn mac(key: &[u8], ciphertext: &[u8]) -> B256 {
+    let mut hasher = Sha3::new();
+    hasher.update(&key[16..32]);
+    hasher.update(ciphertext);
+    hasher.finalize()
+}
+
+/// Hex without the `0x` prefix, as written by go-core. Both forms are accepted
+/// when deserializing.
+mod bare_hex {
+    use serde::{Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer, T: AsRef<[u8]>>(
+        data: T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(data))
+    }
+
+    pub(super) fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: hex::FromHex,
+        <T as hex::FromHex>::Error: core::fmt::Display,
+    {
+        hex::serde::deserialize(deserializer)
+    }
+}
+
+/// Formats random bytes as a version 4 UUID.
+fn uuid_v4(mut bytes: FixedBytes<16>) -> String {
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cAddress, hex};
+
+    const SECRET: [u8; 57] = hex!("69bb68c3a00a0cd9cbf2cab316476228c758329bbfe0b1759e8634694a9497afea05bcbf24e2aa0627eac4240484bb71de646a9296872a3c0e");
+
+    const SCRYPT_KEYSTORE: &str = r#"{
+        "address": "cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5",
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "ciphertext": "12338106f279adfd39656958a5c59615178aa07f03361eda04bc59fe59b03be5429b0c98745a06018e482222478995125fe85bcf0c8b5d8892",
+            "cipherparams": { "iv": "83dbcc02d8ccb40e466191a123791e0e" },
+            "kdf": "scrypt",
+            "kdfparams": {
+                "dklen": 32,
+                "n": 1024,
+                "p": 1,
+                "r": 8,
+                "salt": "ab0c7876052600dd703518d6fc3fe8984592145b591fc8fb5c6d43190334ba19"
+            },
+            "mac": "f364ce74b1a162296db4e9d8f776187fa6c708f226876e754d13ca32880465e8"
+        },
+        "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version": 3
+    }"#;
+
+    const PBKDF2_KEYSTORE: &str = r#"{
+        "address": "cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5",
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "ciphertext": "de93942f798e58d1c9d6138e72fe00575c14c8b23d37cf48413e616e65c133e34df6af70385b96665007d88cf7f700efde70f10126b1d1183c",
+            "cipherparams": { "iv": "83dbcc02d8ccb40e466191a123791e0e" },
+            "kdf": "pbkdf2",
+            "kdfparams": {
+                "c": 1024,
+                "dklen": 32,
+                "prf": "hmac-sha256",
+                "salt": "ab0c7876052600dd703518d6fc3fe8984592145b591fc8fb5c6d43190334ba19"
+            },
+            "mac": "60da8e446975e2f251de7fdfd4bb83ae886810d062b1672463bc95c2af163121"
+        },
+        "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version": 3
+    }"#;
+
+    #[test]
+    fn decrypt() {
+        for json in [SCRYPT_KEYSTORE, PBKDF2_KEYSTORE] {
+            let keystore: Keystore = serde_json::from_str(json).unwrap();
+            assert_eq!(keystore.address, cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"));
+            assert_eq!(*keystore.decrypt("testpassword").unwrap(), SECRET);
+            assert!(matches!(keystore.decrypt("wrongpassword"), Err(SignerError::MacMismatch)));
+        }
+    }
+
+    #[test]
+    fn encrypt() {
+        let expected: Keystore = serde_json::from_str(SCRYPT_KEYSTORE).unwrap();
+        let keystore = Keystore::encrypt_with(
+            &SECRET,
+            expected.address,
+            b"testpassword",
+            expected.crypto.kdf.clone(),
+            expected.crypto.cipherparams.iv,
+            expected.id.clone(),
+        )
+        .unwrap();
+        assert_eq!(keystore, expected);
+
+        let json = serde_json::to_string(&keystore).unwrap();
+        assert!(json.starts_with(r#"{"address":"cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5","#));
+        assert!(json.contains(r#""kdf":"scrypt","kdfparams":{"dklen":32,"n":1024,"p":1,"r":8,"#));
+        assert_eq!(serde_json::from_str::<Keystore>(&json).unwrap(), keystore);
+
+        let kdf = KdfParams::pbkdf2(1024).unwrap();
+        let keystore = Keystore::encrypt(&SECRET, expected.address, "pw", kdf).unwrap();
+        assert_ne!(keystore.crypto.cipherparams.iv, expected.crypto.cipherparams.iv);
+        assert_eq!(keystore.id.len(), 36);
+        assert_eq!(&keystore.id[14..15], "4");
+        assert_eq!(*keystore.decrypt("pw").unwrap(), SECRET);
+    }
+
+    #[test]
+    fn unsupported() {
+        let mut keystore: Keystore = serde_json::from_str(SCRYPT_KEYSTORE).unwrap();
+        keystore.version = 1;
+        assert!(matches!(
+            keystore.decrypt("testpassword"),
+            Err(SignerError::UnsupportedVersion(1))
+        ));
+
+        let mut keystore: Keystore = serde_json::from_str(SCRYPT_KEYSTORE).unwrap();
+        keystore.crypto.cipher = "aes-128-cbc".into();
+        assert!(matches!(keystore.decrypt("testpassword"), Err(SignerError::UnsupportedCipher(_))));
+
+        let mut keystore: Keystore = serde_json::from_str(PBKDF2_KEYSTORE).unwrap();
+        let KdfParams::Pbkdf2(params) = &mut keystore.crypto.kdf else { unreachable!() };
+        params.prf = "hmac-sha512".into();
+        assert!(matches!(keystore.decrypt("testpassword"), Err(SignerError::UnsupportedPrf(_))));
+
+        let json = SCRYPT_KEYSTORE.replace("\"scrypt\"", "\"argon2\"");
+        assert!(serde_json::from_str::<Keystore>(&json).is_err());
+    }
+    #[test]
+    fn invalid_kdf_params() {
+        let invalid = |json: &str| {
+            let keystore: Keystore = serde_json::from_str(json).unwrap();
+            matches!(keystore.decrypt("testpassword"), Err(SignerError::InvalidKdfParams(_)))
+        };
+
+        for dklen in ["16", "64", "4294967295"] {
+            let json = format!("\"dklen\": {dklen}");
+            assert!(invalid(&SCRYPT_KEYSTORE.replace("\"dklen\": 32", &json)));
+            assert!(invalid(&PBKDF2_KEYSTORE.replace("\"dklen\": 32", &json)));
+        }
+        assert!(invalid(&SCRYPT_KEYSTORE.replace("\"n\": 1024", "\"n\": 1000")));
+        assert!(invalid(&SCRYPT_KEYSTORE.replace("\"n\": 1024", "\"n\": 2147483648")));
+        assert!(invalid(&SCRYPT_KEYSTORE.replace("\"r\": 8", "\"r\": 4294967295")));
+        assert!(invalid(&SCRYPT_KEYSTORE.replace("\"p\": 1", "\"p\": 4294967295")));
+        assert!(invalid(&PBKDF2_KEYSTORE.replace("\"c\": 1024", "\"c\": 4294967295")));
+    }
+}
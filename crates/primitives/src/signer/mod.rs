@@ -0,0 +1,275 @@
+//! Local Ed448 key management and signing.
+//!
+//! See [`LocalSigner`].
+
+use crate::{
+    eip191_hash_message, FixedBytes, IcanAddress, Network, Signature, SignatureError, B256,
+};
+use core::fmt;
+use libgoldilocks::{PrehashSigner, SigningKey};
+use std::path::Path;
+use zeroize::Zeroizing;
+
+mod error;
+pub use error::SignerError;
+
+pub mod keystore;
+use keystore::{KdfParams, Keystore};
+
+/// The length of an Ed448 private key, in bytes.
+pub const PRIVATE_KEY_LEN: usize = 57;
+
+/// An Ed448 private key together with the ICAN address it signs for.
+///
+/// The address depends on the network the signer is used on, which defaults
+/// to [mainnet](Network::MAINNET) and can be changed with
+/// [`with_network_id`](Self::with_network_id).
+///
+/// Keys can be generated randomly, or loaded from and stored to go-core
+/// compatible [keystores](Keystore).
+///
+/// # Examples
+///
+/// ```no_run
+/// use base_primitives::LocalSigner;
+///
+/// let signer = LocalSigner::random().with_network_id(3);
+/// let signature = signer.sign_message("hello world")?;
+/// signature.verify(&base_primitives::eip191_hash_message("hello world"), &signer.address(), 3)?;
+///
+/// signer.encrypt_keystore("./keystore.json", "password")?;
+/// let loaded = LocalSigner::decrypt_keystore("./keystore.json", "password")?;
+/// assert_eq!(loaded.address(), signer.address());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone)]
+#[allow(missing_copy_implementations)]
+pub struct LocalSigner {
+    credential: SigningKey,
+    address: IcanAddress,
+    network_id: u64,
+}
+
+impl fmt::Debug for LocalSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalSigner")
+            .field("address", &self.address)
+            .field("network_id", &self.network_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl LocalSigner {
+    /// Creates a new signer from a signing key, for the given network.
+    #[inline]
+    pub fn from_signing_key(credential: SigningKey, network_id: u64) -> Self {
+        let address = IcanAddress::from_private_key(&credential, network_id);
+        Self { credential, address, network_id }
+    }
+
+    /// Creates a new mainnet signer from a raw private key.
+    #[inline]
+    pub fn from_bytes(bytes: &FixedBytes<PRIVATE_KEY_LEN>) -> Self {
+        Self::from_signing_key(SigningKey::from_bytes(bytes.as_slice()), Network::MAINNET.id())
+    }
+
+    /// Creates a new mainnet signer from a raw private key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the slice is not exactly 57 bytes long.
+    #[inline]
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, SignerError> {
+        let bytes = Zeroizing::new(
+            <[u8; PRIVATE_KEY_LEN]>::try_from(bytes)
+                .map_err(|_| SignerError::InvalidKeyLength(bytes.len()))?,
+        );
+        Ok(Self::from_signing_key(SigningKey::from_bytes(&bytes[..]), Network::MAINNET.id()))
+    }
+
+    /// Creates a new mainnet signer with a random private key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying call to `getrandom` fails.
+    #[inline]
+    #[track_caller]
+    pub fn random() -> Self {
+        Self::try_random().unwrap()
+    }
+
+    /// Tries to create a new mainnet signer with a random private key.
+    #[inline]
+    pub fn try_random() -> Result<Self, SignerError> {
+        Ok(Self::from_bytes(&FixedBytes::try_random()?))
+    }
+
+    /// Creates a new mainnet signer with a random private key, using the given
+    /// random number generator.
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub fn random_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::from_bytes(&FixedBytes::random_with(rng))
+    }
+
+    /// Sets the network id, which changes the [address](Self::address).
+    #[inline]
+    pub fn with_network_id(self, network_id: u64) -> Self {
+        Self::from_signing_key(self.credential, network_id)
+    }
+
+    /// Returns the signer's address on its network.
+    #[inline]
+    pub const fn address(&self) -> IcanAddress {
+        self.address
+    }
+
+    /// Returns the network id the signer's address is derived for.
+    #[inline]
+    pub const fn network_id(&self) -> u64 {
+        self.network_id
+    }
+
+    /// Returns the signing key.
+    #[inline]
+    pub const fn credential(&self) -> &SigningKey {
+        &self.credential
+    }
+
+    /// Returns the raw private key.
+    #[inline]
+    pub fn to_bytes(&self) -> FixedBytes<PRIVATE_KEY_LEN> {
+        FixedBytes(self.credential.to_bytes())
+    }
+
+    /// Signs the given 32-byte hash.
+    #[inline]
+    pub fn sign_hash(&self, hash: &B256) -> Result<Signature, SignerError> {
+        self.credential
+            .sign_prehash(hash.as_slice())
+            .map_err(|e| SignatureError::Libgoldilocks(e).into())
+    }
+
+    /// Signs the [EIP-191] hash of the given message.
+    ///
+    /// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+    #[inline]
+    pub fn sign_message<T: AsRef<[u8]>>(&self, message: T) -> Result<Signature, SignerError> {
+        self.sign_hash(&eip191_hash_message(message))
+    }
+
+    /// Decrypts a keystore.
+    ///
+    /// The signer's network is read from the keystore address prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the password is wrong, the keystore is not
+    /// supported, or the decrypted key does not belong to the keystore address.
+    pub fn from_keystore(
+        keystore: &Keystore,
+        password: impl AsRef<[u8]>,
+    ) -> Result<Self, SignerError> {
+        let network_id = keystore.address.try_network_id()?;
+        let secret = keystore.decrypt(password)?;
+        let signer = Self::from_slice(&secret)?.with_network_id(network_id);
+        if signer.address != keystore.address {
+            return Err(SignerError::AddressMismatch {
+                expected: keystore.address,
+                derived: signer.address,
+            });
+        }
+        Ok(signer)
+    }
+
+    /// Encrypts the private key into a keystore, using the given key derivation
+    /// function.
+    pub fn to_keystore(
+        &self,
+        password: impl AsRef<[u8]>,
+        kdf: KdfParams,
+    ) -> Result<Keystore, SignerError> {
+        let secret = Zeroizing::new(self.credential.to_bytes());
+        Keystore::encrypt(&secret[..], self.address, password, kdf)
+    }
+
+    /// Reads and decrypts a keystore file.
+    ///
+    /// See [`from_keystore`](Self::from_keystore).
+    pub fn decrypt_keystore(
+        path: impl AsRef<Path>,
+        password: impl AsRef<[u8]>,
+    ) -> Result<Self, SignerError> {
+        let json = std::fs::read(path)?;
+        let keystore = serde_json::from_slice(&json)?;
+        Self::from_keystore(&keystore, password)
+    }
+
+    /// Encrypts the private key with go-core's
+    /// [standard scrypt parameters](KdfParams::scrypt) and writes it to a
+    /// keystore file, overwriting any existing file.
+    pub fn encrypt_keystore(
+        &self,
+        path: impl AsRef<Path>,
+        password: impl AsRef<[u8]>,
+    ) -> Result<Keystore, SignerError> {
+        let keystore = self.to_keystore(password, KdfParams::scrypt()?)?;
+        std::fs::write(path, serde_json::to_vec(&keystore)?)?;
+        Ok(keystore)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cAddress, hex};
+
+    const SECRET: [u8; 57] = hex!("69bb68c3a00a0cd9cbf2cab316476228c758329bbfe0b1759e8634694a9497afea05bcbf24e2aa0627eac4240484bb71de646a9296872a3c0e");
+
+    #[test]
+    fn address() {
+        let signer = LocalSigner::from_slice(&SECRET).unwrap();
+        assert_eq!(signer.network_id(), 1);
+        assert_eq!(signer.address(), cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"));
+        assert_eq!(signer.to_bytes(), SECRET);
+
+        let signer = signer.with_network_id(3);
+        assert_eq!(signer.address(), cAddress!("ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5"));
+
+        assert!(matches!(
+            LocalSigner::from_slice(&SECRET[1..]),
+            Err(SignerError::InvalidKeyLength(56))
+        ));
+    }
+
+    #[test]
+    fn sign_message() {
+        let signer = LocalSigner::from_slice(&SECRET).unwrap();
+        let signature = signer.sign_message("hello world").unwrap();
+        assert_eq!(
+            signature.signature_bytes()[..],
+            hex!("ef44d25e3ca7f03bf077deee70569ca1a89f1c5cf904b3b3d9ffe5e428abfffb2944095d32ea2127cd86d3c5358882718dad49fed269d9f700eb57a0d2816eefb11b75264ec549d75d3c862146cee973759c5461470c8281415c21c119bdb3499f65196eb3c67271c8b58a6f872d144f1a00")
+        );
+        let hash = eip191_hash_message("hello world");
+        signature.verify(&hash, &signer.address(), 1).unwrap();
+    }
+
+    #[test]
+    fn keystore() {
+        let signer = LocalSigner::random().with_network_id(3);
+        let kdf = KdfParams::scrypt_with(1024, 8, 1).unwrap();
+        let keystore = signer.to_keystore("password", kdf).unwrap();
+        assert_eq!(keystore.address, signer.address());
+
+        let decrypted = LocalSigner::from_keystore(&keystore, "password").unwrap();
+        assert_eq!(decrypted.network_id(), 3);
+        assert_eq!(decrypted.to_bytes(), signer.to_bytes());
+
+        let mut other = keystore;
+        other.address = LocalSigner::random().with_network_id(3).address();
+        assert!(matches!(
+            LocalSigner::from_keystore(&other, "password"),
+            Err(SignerError::AddressMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{
+    sha3, Bytes, ChainId, IcanAddress, Sealable, Signature, SignatureError, TxKind, B256, U256,
+};
+use alloc::vec::Vec;
+use alloy_rlp::{length_of_length, BufMut, Decodable, Encodable, Header, EMPTY_STRING_CODE};
+
+/// An unsigned Core transaction.
+///
+/// Core has a single transaction type. Its RLP encoding is the list of the
+/// fields below, in order, followed by the [`Signature`]; see [`SignedTx`].
+/// The network id is part of the transaction rather than of the signature.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TxLegacy {
+    /// The number of transactions sent by the sender before this one.
+    pub nonce: u64,
+    /// The price of a unit of energy, in ore.
+    pub energy_price: u128,
+    /// The maximum amount of energy the transaction may use.
+    pub energy_limit: u64,
+    /// The recipient, or [`TxKind::Create`] for a contract creation.
+    pub to: TxKind,
+    /// The amount of ore transferred to the recipient.
+    pub value: U256,
+    /// The call data, or the init code of a contract creation.
+    pub input: Bytes,
+    /// The id of the network the transaction is valid on.
+    pub network_id: ChainId,
+}
+
+impl TxLegacy {
+    /// Returns the length of the RLP encoding of the fields, without the list
+    /// header.
+    pub fn fields_len(&self) -> usize {
+        self.nonce.length()
+            + self.energy_price.length()
+            + self.energy_limit.length()
+            + self.to.length()
+            + self.value.length()
+            + self.input.length()
+            + self.network_id.length()
+    }
+
+    /// Encodes the fields, without the list header.
+    pub fn encode_fields(&self, out: &mut dyn BufMut) {
+        self.nonce.encode(out);
+        self.energy_price.encode(out);
+        self.energy_limit.encode(out);
+        self.to.encode(out);
+        self.value.encode(out);
+        self.input.encode(out);
+        self.network_id.encode(out);
+    }
+
+    /// Encodes the data that is hashed for signing: the list of the fields,
+    /// without a signature.
+    pub fn encode_for_signing(&self, out: &mut dyn BufMut) {
+        Header { list: true, payload_length: self.fields_len() }.encode(out);
+        self.encode_fields(out);
+    }
+
+    /// Returns the length of the [signing encoding](Self::encode_for_signing).
+    pub fn payload_len_for_signature(&self) -> usize {
+        let payload_length = self.fields_len();
+        payload_length + length_of_length(payload_length)
+    }
+
+    /// Returns the hash that the sender signs.
+    pub fn signature_hash(&self) -> B256 {
+        let mut buf = Vec::with_capacity(self.payload_len_for_signature());
+        self.encode_for_signing(&mut buf);
+        sha3(buf)
+    }
+
+    /// Attaches a signature to the transaction. The signature is not checked.
+    #[inline]
+    pub const fn into_signed(self, signature: Signature) -> SignedTx {
+        SignedTx { tx: self, signature }
+    }
+
+    /// Decodes the fields, without the list header.
+    fn decode_fields(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        Ok(Self {
+            nonce: Decodable::decode(buf)?,
+            energy_price: Decodable::decode(buf)?,
+            energy_limit: Decodable::decode(buf)?,
+            to: Decodable::decode(buf)?,
+            value: Decodable::decode(buf)?,
+            input: Decodable::decode(buf)?,
+            network_id: Decodable::decode(buf)?,
+        })
+    }
+}
+
+/// A signed Core transaction.
+///
+/// The [hash](Sealable::hash_slow) of a transaction is the [`sha3`] hash of
+/// its RLP encoding.
+///
+/// # Examples
+///
+/// ```
+/// use base_primitives::{hex, Sealable, SignedTx};
+///
+/// let rlp = hex!("f8d9098504a817c80082520896cb751111111111111111111111111111111111111111880de0b6b3a76400008001b8ab0fbbfbf685d250e91de5b1fb2c39e3090e1a58a5f2c7a2e2d02946a650d1fb7f163405bdb8f9d73c210e6481042408a826dccb4dd3163ad60034b62482477773f8bd539476854753a263d2291c866c8e9c2a4e894c509d65975ef0477dcc6c2c72e8acb9edfa7aa22dff4c7916d54b2d2000315484db568379ce94f9c894e3e6e4c7ee216676b713ca892d9b26746ae902a772e217a6a8bb493ce2bb313cf0cb66e76765d4c45ec6b68600");
+/// let tx: SignedTx = alloy_rlp::Decodable::decode(&mut &rlp[..])?;
+/// let sender = tx.recover_signer()?;
+/// assert_eq!(sender.to_string(), "cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+/// assert_eq!(tx.hash_slow().to_string(), "0xea1b2bdd513bb6d97cb2fadad17624b218b4f520f3acf675b6f039d6440e1225");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedTx {
+    /// The transaction.
+    pub tx: TxLegacy,
+    /// The sender's signature of the [signature hash](TxLegacy::signature_hash).
+    pub signature: Signature,
+}
+
+impl core::ops::Deref for SignedTx {
+    type Target = TxLegacy;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.tx
+    }
+}
+
+impl SignedTx {
+    /// Returns the address of the sender, on the transaction's network.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signature does not verify against the
+    /// [signature hash](TxLegacy::signature_hash).
+    #[inline]
+    pub fn recover_signer(&self) -> Result<IcanAddress, SignatureError> {
+        self.signature.recover_address_from_prehash(&self.tx.signature_hash(), self.tx.network_id)
+    }
+
+    /// Splits the signed transaction into the transaction and the signature.
+    #[inline]
+    pub fn into_parts(self) -> (TxLegacy, Signature) {
+        (self.tx, self.signature)
+    }
+
+    fn payload_len(&self) -> usize {
+        self.tx.fields_len() + self.signature.length()
+    }
+}
+
+impl Encodable for SignedTx {
+    fn encode(&self, out: &mut dyn BufMut) {
+        Header { list: true, payload_length: self.payload_len() }.encode(out);
+        self.tx.encode_fields(out);
+        self.signature.encode(out);
+    }
+
+    fn length(&self) -> usize {
+        let payload_length = self.payload_len();
+        payload_length + length_of_length(payload_length)
+    }
+}
+
+impl Decodable for SignedTx {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+        let started_len = buf.len();
+        let tx = TxLegacy::decode_fields(buf)?;
+        if buf.first() == Some(&EMPTY_STRING_CODE) {
+            return Err(alloy_rlp::Error::Custom("transaction is not signed"));
+        }
+        let signature = Decodable::decode(buf)?;
+        if started_len - buf.len() != header.payload_length {
+            return Err(alloy_rlp::Error::ListLengthMismatch {
+                expected: header.payload_length,
+                got: started_len - buf.len(),
+            });
+        }
+        Ok(Self { tx, signature })
+    }
+}
+
+impl Sealable for SignedTx {
+    fn hash_slow(&self) -> B256 {
+        sha3(alloy_rlp::encode(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cAddress, hex};
+
+    #[test]
+    fn transfer() {
+        let tx = TxLegacy {
+            nonce: 9,
+            energy_price: 20_000_000_000,
+            energy_limit: 21000,
+            to: cAddress!("cb751111111111111111111111111111111111111111").into(),
+            value: U256::from(1_000_000_000_000_000_000u64),
+            input: Bytes::new(),
+            network_id: 1,
+        };
+        assert_eq!(
+            tx.signature_hash(),
+            B256::new(hex!("e5f3fcd0e187a0e7e487befbcadcaf0cda58b6bf23f31fc7d112450decdf1474"))
+        );
+
+        let signature = Signature::from_bytes(&hex!("0fbbfbf685d250e91de5b1fb2c39e3090e1a58a5f2c7a2e2d02946a650d1fb7f163405bdb8f9d73c210e6481042408a826dccb4dd3163ad60034b62482477773f8bd539476854753a263d2291c866c8e9c2a4e894c509d65975ef0477dcc6c2c72e8acb9edfa7aa22dff4c7916d54b2d2000315484db568379ce94f9c894e3e6e4c7ee216676b713ca892d9b26746ae902a772e217a6a8bb493ce2bb313cf0cb66e76765d4c45ec6b68600")).unwrap();
+        let signed = tx.into_signed(signature);
+        let rlp = hex!("f8d9098504a817c80082520896cb751111111111111111111111111111111111111111880de0b6b3a76400008001b8ab0fbbfbf685d250e91de5b1fb2c39e3090e1a58a5f2c7a2e2d02946a650d1fb7f163405bdb8f9d73c210e6481042408a826dccb4dd3163ad60034b62482477773f8bd539476854753a263d2291c866c8e9c2a4e894c509d65975ef0477dcc6c2c72e8acb9edfa7aa22dff4c7916d54b2d2000315484db568379ce94f9c894e3e6e4c7ee216676b713ca892d9b26746ae902a772e217a6a8bb493ce2bb313cf0cb66e76765d4c45ec6b68600");
+        assert_eq!(alloy_rlp::encode(&signed), rlp);
+        assert_eq!(signed.length(), rlp.len());
+        assert_eq!(SignedTx::decode(&mut &rlp[..]).unwrap(), signed);
+        assert_eq!(
+            signed.clone().seal_slow().seal(),
+            B256::new(hex!("ea1b2bdd513bb6d97cb2fadad17624b218b4f520f3acf675b6f039d6440e1225"))
+        );
+        assert_eq!(
+            signed.recover_signer().unwrap(),
+            cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5")
+        );
+
+        // the network id is signed
+        let mut other = signed;
+        other.tx.network_id = 3;
+        assert!(other.recover_signer().is_err());
+    }
+
+    #[test]
+    fn create() {
+        let rlp = hex!("f8bc8001830186a0808085608060405203b8abe6c9205efe4ebf8a587296b8a42b610ec9311c6d568fb4ecf88c6e3894ba401bceff65cbbe24c6a1d02102d2cd4a6ea265589ae54583866f80c364b394e8293375c52235beb4f1e22103582f9306e5abd9ddd067af089454e91fbc1bba8f085674f91255a3f40ff936327a47f99bc4d02800315484db568379ce94f9c894e3e6e4c7ee216676b713ca892d9b26746ae902a772e217a6a8bb493ce2bb313cf0cb66e76765d4c45ec6b68600");
+        let signed = SignedTx::decode(&mut &rlp[..]).unwrap();
+        assert_eq!(
+            signed.tx,
+            TxLegacy {
+                nonce: 0,
+                energy_price: 1,
+                energy_limit: 100_000,
+                to: TxKind::Create,
+                value: U256::ZERO,
+                input: Bytes::from_static(&hex!("6080604052")),
+                network_id: 3,
+            }
+        );
+        assert_eq!(
+            signed.signature_hash(),
+            B256::new(hex!("5e5336747de0f6a33c38a1868a3aa4946a8c8820d2ef8a3e6a56012b78254005"))
+        );
+        assert_eq!(
+            signed.hash_slow(),
+            B256::new(hex!("6382cada33c26f07e3612bc08891190f138311477b92827f2b0b18392b2208a7"))
+        );
+        assert_eq!(
+            signed.recover_signer().unwrap(),
+            cAddress!("ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5")
+        );
+        assert_eq!(alloy_rlp::encode(&signed), rlp);
+    }
+
+    #[test]
+    fn decode_errors() {
+        let tx = TxLegacy::default();
+        let mut unsigned = Vec::new();
+        Header { list: true, payload_length: tx.fields_len() + 1 }.encode(&mut unsigned);
+        tx.encode_fields(&mut unsigned);
+        unsigned.push(EMPTY_STRING_CODE);
+        assert_eq!(
+            SignedTx::decode(&mut &unsigned[..]),
+            Err(alloy_rlp::Error::Custom("transaction is not signed"))
+        );
+
+        let mut signing = Vec::new();
+        tx.encode_for_signing(&mut signing);
+        assert_eq!(signing.len(), tx.payload_len_for_signature());
+        assert!(SignedTx::decode(&mut &signing[..]).is_err());
+    }
+}
@@ -0,0 +1,358 @@
+//! Merkle-Patricia trie roots and proofs.
+//!
+//! Core blocks commit to their transactions and receipts with the root of an
+//! *ordered trie*: a Merkle-Patricia trie that maps the RLP encoding of each
+//! item's index to the RLP encoding of the item. The construction is the same
+//! as Ethereum's, except that nodes are hashed with [`sha3`] instead of
+//! Keccak-256.
+//!
+//! The root is computed in one pass over the sorted entries, without
+//! allocating the trie itself. Single-entry [proofs](ordered_trie_proof) list
+//! the RLP encodings of the nodes on the path to the entry, from the root down,
+//! and can be checked against a root with [`verify_proof`].
+//!
+//! # Examples
+//!
+//! ```
+//! use base_primitives::{trie, Bytes};
+//!
+//! let receipts = [Bytes::from_static(b"first"), Bytes::from_static(b"second")];
+//! let root = trie::ordered_trie_root(&receipts);
+//!
+//! let proof = trie::ordered_trie_proof(&receipts, 1);
+//! trie::verify_ordered_proof(root, 1, &receipts[1], &proof).unwrap();
+//! ```
+
+use crate::{sha3, Bytes, B256};
+use alloc::vec::Vec;
+use alloy_rlp::{BufMut, Encodable, Header, EMPTY_STRING_CODE};
+
+mod proof;
+pub use proof::{verify_ordered_proof, verify_proof, ProofError};
+
+/// Root hash of an empty trie: the hash of the RLP encoding of an empty
+/// string.
+pub const EMPTY_ROOT_HASH: B256 = B256::new(hex_literal::hex!(
+    "bc2071a4de846f285702447f2589dd163678e0972a8a1b0d28b04ed5c094547f"
+));
+
+/// Returns the trie key of the item at `index` in an ordered trie: the RLP
+/// encoding of the index.
+#[inline]
+pub fn ordered_trie_key(index: usize) -> Vec<u8> {
+    alloy_rlp::encode(index)
+}
+
+/// Computes the root of the ordered trie of the RLP-encoded items, such as the
+/// transactions or receipts root of a block.
+#[inline]
+pub fn ordered_trie_root<T: Encodable>(items: &[T]) -> B256 {
+    ordered_trie_root_with_encoder(items, |item, out| item.encode(out))
+}
+
+/// Computes the root of the ordered trie of the items, each encoded with
+/// `encode`.
+pub fn ordered_trie_root_with_encoder<T, F>(items: &[T], encode: F) -> B256
+where
+    F: FnMut(&T, &mut Vec<u8>),
+{
+    trie_root(ordered_entries(items, encode))
+}
+
+/// Returns the proof for the item at `index` in the ordered trie of the
+/// RLP-encoded items.
+///
+/// If `index` is out of bounds, the result proves that there is no such item.
+#[inline]
+pub fn ordered_trie_proof<T: Encodable>(items: &[T], index: usize) -> Vec<Bytes> {
+    trie_proof(ordered_entries(items, |item, out| item.encode(out)), &ordered_trie_key(index))
+}
+
+/// Computes the root of the trie with the given keys and values.
+///
+/// If a key appears more than once, the last value is used. Entries with empty
+/// values are left out, since a trie cannot hold them.
+pub fn trie_root<K, V, I>(entries: I) -> B256
+where
+    K: AsRef<[u8]>,
+    V: AsRef<[u8]>,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let entries = sorted_entries(entries);
+    sha3(encode_node(&entries, 0, None, &mut Vec::new()))
+}
+
+/// Returns the proof for `key` in the trie with the given keys and values.
+///
+/// The proof lists the RLP encodings of the root node and of every node on the
+/// path to `key` that is referenced by its hash. If the trie does not contain
+/// `key`, the result proves its absence.
+pub fn trie_proof<K, V, I>(entries: I, key: &[u8]) -> Vec<Bytes>
+where
+    K: AsRef<[u8]>,
+    V: AsRef<[u8]>,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let entries = sorted_entries(entries);
+    let target = to_nibbles(key);
+    let mut proof = Vec::new();
+    encode_node(&entries, 0, Some(&target), &mut proof);
+    // Nodes are collected from the leaves up.
+    proof.reverse();
+    proof
+}
+
+fn ordered_entries<T, F>(items: &[T], mut encode: F) -> Vec<(Vec<u8>, Vec<u8>)>
+where
+    F: FnMut(&T, &mut Vec<u8>),
+{
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let mut value = Vec::new();
+            encode(item, &mut value);
+            (ordered_trie_key(index), value)
+        })
+        .collect()
+}
+
+fn sorted_entries<K, V, I>(entries: I) -> Vec<(Vec<u8>, V)>
+where
+    K: AsRef<[u8]>,
+    V: AsRef<[u8]>,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let mut entries: Vec<_> =
+        entries.into_iter().map(|(k, v)| (to_nibbles(k.as_ref()), v)).collect();
+    // Stable, so that the last of several equal keys ends up last.
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut deduped: Vec<(Vec<u8>, V)> = Vec::with_capacity(entries.len());
+    for entry in entries {
+        match deduped.last_mut() {
+            Some(last) if last.0 == entry.0 => *last = entry,
+            _ => deduped.push(entry),
+        }
+    }
+    deduped.retain(|(_, value)| !value.as_ref().is_empty());
+    deduped
+}
+
+/// Splits bytes into nibbles, high nibble first.
+pub(crate) fn to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect()
+}
+
+/// Encodes a path with the hex-prefix encoding, flagging leaves and odd
+/// lengths in the first nibble.
+fn hex_prefix(nibbles: &[u8], leaf: bool) -> Vec<u8> {
+    let flag = if leaf { 0x20 } else { 0x00 };
+    let mut out = Vec::with_capacity(nibbles.len() / 2 + 1);
+    let rest = if nibbles.len() % 2 == 1 {
+        out.push(flag | 0x10 | nibbles[0]);
+        &nibbles[1..]
+    } else {
+        out.push(flag);
+        nibbles
+    };
+    out.extend(rest.chunks_exact(2).map(|pair| pair[0] << 4 | pair[1]));
+    out
+}
+
+/// Returns the RLP encoding of the node holding the sorted `entries` below
+/// `depth` nibbles.
+///
+/// If `target` is set, nodes on its path that are referenced by hash, and the
+/// root node, are appended to `proof` after their children.
+fn encode_node<V: AsRef<[u8]>>(
+    entries: &[(Vec<u8>, V)],
+    depth: usize,
+    target: Option<&[u8]>,
+    proof: &mut Vec<Bytes>,
+) -> Vec<u8> {
+    let on_path = target.filter(|target| match entries.first() {
+        Some((key, _)) => target.get(..depth) == Some(&key[..depth]),
+        None => true,
+    });
+
+    let node = match entries {
+        [] => vec![EMPTY_STRING_CODE],
+        [(key, value)] => encode_list(&[
+            &encode_bytes(&hex_prefix(&key[depth..], true)),
+            &encode_bytes(value.as_ref()),
+        ]),
+        [(first, _), rest @ ..] => {
+            let common = rest.iter().fold(first.len() - depth, |common, (key, _)| {
+                first[depth..depth + common]
+                    .iter()
+                    .zip(&key[depth..])
+                    .take_while(|(a, b)| a == b)
+                    .count()
+            });
+            if common > 0 {
+                let child = encode_node(entries, depth + common, on_path, proof);
+                encode_list(&[
+                    &encode_bytes(&hex_prefix(&first[depth..depth + common], false)),
+                    &node_ref(child),
+                ])
+            } else {
+                encode_branch(entries, depth, on_path, proof)
+            }
+        }
+    };
+
+    if on_path.is_some() && (depth == 0 || node.len() >= 32) {
+        proof.push(node.clone().into());
+    }
+    node
+}
+
+fn encode_branch<V: AsRef<[u8]>>(
+    entries: &[(Vec<u8>, V)],
+    depth: usize,
+    target: Option<&[u8]>,
+    proof: &mut Vec<Bytes>,
+) -> Vec<u8> {
+    let mut items: Vec<Vec<u8>> = Vec::with_capacity(17);
+    // Entries are sorted, so a key that ends here comes first.
+    let (value, mut rest) = match entries.split_first() {
+        Some((entry, rest)) if entry.0.len() == depth => (Some(entry), rest),
+        _ => (None, entries),
+    };
+    for nibble in 0..16 {
+        let len = rest.iter().take_while(|(key, _)| key[depth] == nibble).count();
+        let (children, next) = rest.split_at(len);
+        rest = next;
+        items.push(if children.is_empty() {
+            vec![EMPTY_STRING_CODE]
+        } else {
+            node_ref(encode_node(children, depth + 1, target, proof))
+        });
+    }
+    items.push(match value {
+        Some((_, value)) => encode_bytes(value.as_ref()),
+        None => vec![EMPTY_STRING_CODE],
+    });
+    encode_list(&items.iter().map(Vec::as_slice).collect::<Vec<_>>())
+}
+
+/// Returns how a parent refers to a node: inline if its encoding is shorter
+/// than a hash, or by its hash otherwise.
+fn node_ref(node: Vec<u8>) -> Vec<u8> {
+    if node.len() < 32 {
+        node
+    } else {
+        encode_bytes(sha3(&node).as_slice())
+    }
+}
+
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(bytes.len() + 3);
+    bytes.encode(&mut out);
+    out
+}
+
+fn encode_list(items: &[&[u8]]) -> Vec<u8> {
+    let payload_length = items.iter().map(|item| item.len()).sum();
+    let mut out = Vec::with_capacity(payload_length + 3);
+    Header { list: true, payload_length }.encode(&mut out);
+    items.iter().for_each(|item| out.put_slice(item));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+
+    #[test]
+    fn empty() {
+        assert_eq!(sha3([EMPTY_STRING_CODE]), EMPTY_ROOT_HASH);
+        assert_eq!(ordered_trie_root::<Bytes>(&[]), EMPTY_ROOT_HASH);
+        assert_eq!(trie_root::<&[u8], &[u8], _>([]), EMPTY_ROOT_HASH);
+    }
+
+    #[test]
+    fn hex_prefix_encoding() {
+        assert_eq!(hex_prefix(&[1, 2, 3, 4, 5], false), [0x11, 0x23, 0x45]);
+        assert_eq!(hex_prefix(&[0, 1, 2, 3, 4, 5], false), [0x00, 0x01, 0x23, 0x45]);
+        assert_eq!(hex_prefix(&[0, 15, 1, 12, 11, 8], true), [0x20, 0x0f, 0x1c, 0xb8]);
+        assert_eq!(hex_prefix(&[15, 1, 12, 11, 8], true), [0x3f, 0x1c, 0xb8]);
+    }
+
+    #[test]
+    fn root() {
+        let entries = [("doe", "reindeer"), ("dog", "puppy"), ("dogglesworth", "cat")];
+        let expected =
+            B256::new(hex!("c33dc4124235214a96518fc8bfdef30a6c1462a08e423c29f7b08513829c551f"));
+        assert_eq!(trie_root(entries), expected);
+        // order and duplicates do not matter
+        assert_eq!(trie_root([entries[2], ("dog", "kitten"), entries[0], entries[1]]), expected);
+    }
+
+    #[test]
+    fn ordered_root() {
+        let items: Vec<Bytes> =
+            (0..300u32).map(|i| Bytes::from(i.to_be_bytes().repeat(i as usize % 7 + 1))).collect();
+        assert_eq!(
+            ordered_trie_root(&items[..1]),
+            B256::new(hex!("82794e8e09e80e4772b218f9ce62d6c1b21eb6f2dfdd678265766c9750d38327"))
+        );
+        assert_eq!(
+            ordered_trie_root(&items),
+            B256::new(hex!("68ce45e09238f587d6552751234a5897a8d717ed848d9c9995ba7087a4163297"))
+        );
+        assert_eq!(
+            ordered_trie_root_with_encoder(&items, |item, out| out.extend_from_slice(item)),
+            B256::new(hex!("2dd0b36e2c940588e168e50e2d3a5125fd5a27b12a5d21aaa96f63f156ff9576"))
+        );
+    }
+
+    #[test]
+    fn proofs() {
+        let items: Vec<Bytes> =
+            (0..300u32).map(|i| Bytes::from(i.to_be_bytes().repeat(i as usize % 7 + 1))).collect();
+        for len in [0, 1, 2, 16, 17, 128, 129, 300] {
+            let items = &items[..len];
+            let root = ordered_trie_root(items);
+            for index in 0..len {
+                let proof = ordered_trie_proof(items, index);
+                assert_eq!(sha3(&proof[0]), root);
+                verify_ordered_proof(root, index, &items[index], &proof).unwrap();
+
+                let other = Bytes::from_static(b"other");
+                assert_eq!(
+                    verify_ordered_proof(root, index, &other, &proof),
+                    Err(ProofError::ValueMismatch)
+                );
+            }
+
+            if len > 16 {
+                let proof = ordered_trie_proof(items, 3);
+                assert_eq!(
+                    verify_ordered_proof(root, 3, &items[3], &proof[..proof.len() - 1]),
+                    Err(ProofError::MissingNode)
+                );
+                assert!(matches!(
+                    verify_ordered_proof(EMPTY_ROOT_HASH, 3, &items[3], &proof),
+                    Err(ProofError::HashMismatch { .. })
+                ));
+                let mut extra = proof.clone();
+                extra.push(proof[0].clone());
+                assert_eq!(
+                    verify_ordered_proof(root, 3, &items[3], &extra),
+                    Err(ProofError::UnexpectedNode)
+                );
+            }
+
+            // absent keys
+            for index in [len, len + 1, 1000] {
+                let proof = ordered_trie_proof(items, index);
+                verify_proof(root, &ordered_trie_key(index), None, &proof).unwrap();
+                if len > 0 {
+                    assert!(verify_ordered_proof(root, index, &items[0], &proof).is_err());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use super::{ordered_trie_key, to_nibbles};
+use crate::{sha3, Bytes, B256};
+use alloc::vec::Vec;
+use alloy_rlp::{Encodable, Header, EMPTY_STRING_CODE};
+
+/// Errors in trie proof verification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofError {
+    /// The proof ends before the path to the key is resolved.
+    MissingNode,
+
+    /// A proof node does not hash to the root, or to its reference in the
+    /// parent node.
+    HashMismatch {
+        /// The hash the node was expected to have.
+        expected: B256,
+        /// The hash of the node in the proof.
+        got: B256,
+    },
+
+    /// The proof has nodes left over after the path to the key is resolved.
+    UnexpectedNode,
+
+    /// A proof node is not a valid trie node.
+    InvalidNode(&'static str),
+
+    /// A proof node is not valid RLP.
+    Rlp(alloy_rlp::Error),
+
+    /// The proof is valid, but the trie holds a different value for the key,
+    /// or does not hold the key.
+    ValueMismatch,
+}
+
+impl From<alloy_rlp::Error> for ProofError {
+    #[inline]
+    fn from(err: alloy_rlp::Error) -> Self {
+        Self::Rlp(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProofError {}
+
+impl core::fmt::Display for ProofError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingNode => f.write_str("proof is missing a node"),
+            Self::HashMismatch { expected, got } => {
+                write!(f, "proof node hash mismatch: expected {expected}, got {got}")
+            }
+            Self::UnexpectedNode => f.write_str("proof has unused nodes"),
+            Self::InvalidNode(e) => write!(f, "invalid trie node: {e}"),
+            Self::Rlp(e) => write!(f, "invalid trie node: {e}"),
+            Self::ValueMismatch => f.write_str("proof does not match the expected value"),
+        }
+    }
+}
+
+/// Verifies a [proof](super::ordered_trie_proof) that `item` is at `index` in
+/// the ordered trie with the given root.
+#[inline]
+pub fn verify_ordered_proof<T: Encodable>(
+    root: B256,
+    index: usize,
+    item: &T,
+    proof: &[Bytes],
+) -> Result<(), ProofError> {
+    let value = alloy_rlp::encode(item);
+    verify_proof(root, &ordered_trie_key(index), Some(&value), proof)
+}
+
+/// Verifies a [proof](super::trie_proof) that the trie with the given root
+/// holds `expected` for `key`, or does not hold `key` if `expected` is `None`.
+pub fn verify_proof(
+    root: B256,
+    key: &[u8],
+    expected: Option<&[u8]>,
+    proof: &[Bytes],
+) -> Result<(), ProofError> {
+    let mut nodes = proof.iter();
+    let mut node: &[u8] = next_node(&mut nodes, root)?;
+    let key = to_nibbles(key);
+    let mut path = &key[..];
+
+    let value = loop {
+        if node == [EMPTY_STRING_CODE] {
+            // Empty trie.
+            break None;
+        }
+        let items = decode_list(node)?;
+        let child = match items.len() {
+            17 => match path.split_first() {
+                Some((&nibble, rest)) => {
+                    path = rest;
+                    items[nibble as usize]
+                }
+                None => break decode_bytes(items[16]).filter(|v| !v.is_empty()),
+            },
+            2 => {
+                let encoded_path = decode_bytes(items[0]).unwrap_or_default();
+                let (node_path, leaf) = decode_hex_prefix(encoded_path)?;
+                if leaf {
+                    break (path == node_path).then(|| decode_bytes(items[1])).flatten();
+                }
+                match path.strip_prefix(&node_path[..]) {
+                    Some(rest) => {
+                        path = rest;
+                        items[1]
+                    }
+                    None => break None,
+                }
+            }
+            _ => return Err(ProofError::InvalidNode("expected 2 or 17 items")),
+        };
+
+        // Follow the reference to the child node.
+        node = match decode_bytes(child) {
+            // Inline node.
+            None => child,
+            Some([]) => break None,
+            Some(hash) if hash.len() == 32 => next_node(&mut nodes, B256::from_slice(hash))?,
+            Some(_) => return Err(ProofError::InvalidNode("invalid child reference")),
+        };
+    };
+
+    if nodes.next().is_some() {
+        return Err(ProofError::UnexpectedNode);
+    }
+    if value != expected {
+        return Err(ProofError::ValueMismatch);
+    }
+    Ok(())
+}
+
+fn next_node<'a>(
+    nodes: &mut core::slice::Iter<'a, Bytes>,
+    expected: B256,
+) -> Result<&'a [u8], ProofError> {
+    let node = nodes.next().ok_or(ProofError::MissingNode)?;
+    let got = sha3(node);
+    if got != expected {
+        return Err(ProofError::HashMismatch { expected, got });
+    }
+    Ok(node)
+}
+
+/// Splits an RLP list into the encodings of its items.
+fn decode_list(mut buf: &[u8]) -> Result<Vec<&[u8]>, ProofError> {
+    let header = Header::decode(&mut buf)?;
+    if !header.list {
+        return Err(ProofError::InvalidNode("expected a list"));
+    }
+    if header.payload_length != buf.len() {
+        return Err(alloy_rlp::Error::UnexpectedLength.into());
+    }
+    let mut items = Vec::with_capacity(17);
+    while !buf.is_empty() {
+        let start = buf;
+        let header = Header::decode(&mut buf)?;
+        if header.payload_length > buf.len() {
+            return Err(alloy_rlp::Error::InputTooShort.into());
+        }
+        buf = &buf[header.payload_length..];
+        items.push(&start[..start.len() - buf.len()]);
+    }
+    Ok(items)
+}
+
+/// Returns the payload of an RLP string, or `None` if the item is a list.
+fn decode_bytes(mut item: &[u8]) -> Option<&[u8]> {
+    let header = Header::decode(&mut item).ok()?;
+    (!header.list).then_some(item)
+}
+
+fn decode_hex_prefix(encoded: &[u8]) -> Result<(Vec<u8>, bool), ProofError> {
+    let (&first, rest) = encoded.split_first().ok_or(ProofError::InvalidNode("empty path"))?;
+    let flag = first >> 4;
+    if flag > 3 {
+        return Err(ProofError::InvalidNode("invalid path flag"));
+    }
+    let mut path = Vec::with_capacity(rest.len() * 2 + 1);
+    if flag & 1 == 1 {
+        path.push(first & 0x0f);
+    }
+    path.extend(to_nibbles(rest));
+    Ok((path, flag & 2 == 2))
+}
@@ -7,9 +7,12 @@ use core::{fmt, mem::MaybeUninit};
 mod units;
 use tiny_keccak::Hasher as _;
 pub use units::{
-    format_ether, format_units, parse_ether, parse_units, ParseUnits, Unit, UnitsError,
+    format_core, format_ether, format_units, parse_core, parse_ether, parse_units, ParseUnits,
+    Unit, UnitsError,
 };
 
+pub use crate::fixed_point::parse_decimal;
+
 #[doc(hidden)]
 #[deprecated(since = "0.5.0", note = "use `Unit::ETHER.wei()` instead")]
 pub const WEI_IN_ETHER: crate::U256 = Unit::ETHER.wei_const();
@@ -21,6 +21,23 @@ pub fn parse_ether(eth: &str) -> Result<U256, UnitsError> {
     ParseUnits::parse_units(eth, Unit::ETHER).map(Into::into)
 }
 
+/// Converts the input to a U256 and converts from Core to Ore.
+///
+/// # Examples
+///
+/// ```
+/// use base_primitives::{
+///     utils::{parse_core, Unit},
+///     U256,
+/// };
+///
+/// assert_eq!(parse_core("1").unwrap(), Unit::CORE.ore());
+/// assert_eq!(parse_core("0.000000001").unwrap(), Unit::NUCLE.ore());
+/// ```
+pub fn parse_core(core: &str) -> Result<U256, UnitsError> {
+    ParseUnits::parse_units(core, Unit::CORE).map(Into::into)
+}
+
 /// Parses a decimal number and multiplies it with 10^units.
 ///
 /// # Examples
@@ -66,6 +83,19 @@ pub fn format_ether<T: Into<ParseUnits>>(amount: T) -> String {
     amount.into().format_units(Unit::ETHER)
 }
 
+/// Formats the given number of Ore as a Core amount.
+///
+/// # Examples
+///
+/// ```
+/// use base_primitives::utils::format_core;
+///
+/// assert_eq!(format_core(1395633240123456000_u128), "1.395633240123456000");
+/// ```
+pub fn format_core<T: Into<ParseUnits>>(amount: T) -> String {
+    amount.into().format_units(Unit::CORE)
+}
+
 /// Formats the given number of Wei as the given unit.
 ///
 /// # Examples
@@ -368,70 +398,91 @@ impl core::str::FromStr for Unit {
         }
 
         Ok(match s.to_ascii_lowercase().as_str() {
-            "eth" | "ether" => Self::ETHER,
-            "pwei" | "milli" | "milliether" | "finney" => Self::PWEI,
-            "twei" | "micro" | "microether" | "szabo" => Self::TWEI,
-            "gwei" | "nano" | "nanoether" | "shannon" => Self::GWEI,
-            "mwei" | "pico" | "picoether" | "lovelace" => Self::MWEI,
-            "kwei" | "femto" | "femtoether" | "babbage" => Self::KWEI,
-            "wei" => Self::WEI,
+            "core" | "eth" | "ether" => Self::CORE,
+            "moli" | "pwei" | "milli" | "milliether" | "finney" => Self::MOLI,
+            "atom" | "twei" | "micro" | "microether" | "szabo" => Self::ATOM,
+            "nucle" | "gwei" | "nano" | "nanoether" | "shannon" => Self::NUCLE,
+            "grav" | "mwei" | "pico" | "picoether" | "lovelace" => Self::GRAV,
+            "wav" | "kwei" | "femto" | "femtoether" | "babbage" => Self::WAV,
+            "ore" | "wei" => Self::ORE,
             _ => return Err(UnitsError::InvalidUnit(s.to_string())),
         })
     }
 }
 
 impl Unit {
-    /// Wei is equivalent to 1 wei.
-    pub const WEI: Self = unsafe { Self::new_unchecked(0) };
+    /// Ore is the smallest Core denomination.
+    pub const ORE: Self = unsafe { Self::new_unchecked(0) };
+
+    /// Wav is equivalent to 1e3 ore.
+    pub const WAV: Self = unsafe { Self::new_unchecked(3) };
+
+    /// Grav is equivalent to 1e6 ore.
+    pub const GRAV: Self = unsafe { Self::new_unchecked(6) };
+
+    /// Nucle is equivalent to 1e9 ore.
+    pub const NUCLE: Self = unsafe { Self::new_unchecked(9) };
+
+    /// Atom is equivalent to 1e12 ore.
+    pub const ATOM: Self = unsafe { Self::new_unchecked(12) };
+
+    /// Moli is equivalent to 1e15 ore.
+    pub const MOLI: Self = unsafe { Self::new_unchecked(15) };
+
+    /// Core is equivalent to 1e18 ore.
+    pub const CORE: Self = unsafe { Self::new_unchecked(18) };
+
+    /// Wei is equivalent to 1 wei. Alias of [`ORE`](Self::ORE).
+    pub const WEI: Self = Self::ORE;
     #[allow(non_upper_case_globals)]
     #[doc(hidden)]
     #[deprecated(since = "0.5.0", note = "use `Unit::WEI` instead")]
     pub const Wei: Self = Self::WEI;
 
-    /// Kwei is equivalent to 1e3 wei.
-    pub const KWEI: Self = unsafe { Self::new_unchecked(3) };
+    /// Kwei is equivalent to 1e3 wei. Alias of [`WAV`](Self::WAV).
+    pub const KWEI: Self = Self::WAV;
     #[allow(non_upper_case_globals)]
     #[doc(hidden)]
     #[deprecated(since = "0.5.0", note = "use `Unit::KWEI` instead")]
     pub const Kwei: Self = Self::KWEI;
 
-    /// Mwei is equivalent to 1e6 wei.
-    pub const MWEI: Self = unsafe { Self::new_unchecked(6) };
+    /// Mwei is equivalent to 1e6 wei. Alias of [`GRAV`](Self::GRAV).
+    pub const MWEI: Self = Self::GRAV;
     #[allow(non_upper_case_globals)]
     #[doc(hidden)]
     #[deprecated(since = "0.5.0", note = "use `Unit::MWEI` instead")]
     pub const Mwei: Self = Self::MWEI;
 
-    /// Gwei is equivalent to 1e9 wei.
-    pub const GWEI: Self = unsafe { Self::new_unchecked(9) };
+    /// Gwei is equivalent to 1e9 wei. Alias of [`NUCLE`](Self::NUCLE).
+    pub const GWEI: Self = Self::NUCLE;
     #[allow(non_upper_case_globals)]
     #[doc(hidden)]
     #[deprecated(since = "0.5.0", note = "use `Unit::GWEI` instead")]
     pub const Gwei: Self = Self::GWEI;
 
-    /// Twei is equivalent to 1e12 wei.
-    pub const TWEI: Self = unsafe { Self::new_unchecked(12) };
+    /// Twei is equivalent to 1e12 wei. Alias of [`ATOM`](Self::ATOM).
+    pub const TWEI: Self = Self::ATOM;
     #[allow(non_upper_case_globals)]
     #[doc(hidden)]
     #[deprecated(since = "0.5.0", note = "use `Unit::TWEI` instead")]
     pub const Twei: Self = Self::TWEI;
 
-    /// Pwei is equivalent to 1e15 wei.
-    pub const PWEI: Self = unsafe { Self::new_unchecked(15) };
+    /// Pwei is equivalent to 1e15 wei. Alias of [`MOLI`](Self::MOLI).
+    pub const PWEI: Self = Self::MOLI;
     #[allow(non_upper_case_globals)]
     #[doc(hidden)]
     #[deprecated(since = "0.5.0", note = "use `Unit::PWEI` instead")]
     pub const Pwei: Self = Self::PWEI;
 
-    /// Ether is equivalent to 1e18 wei.
-    pub const ETHER: Self = unsafe { Self::new_unchecked(18) };
+    /// Ether is equivalent to 1e18 wei. Alias of [`CORE`](Self::CORE).
+    pub const ETHER: Self = Self::CORE;
     #[allow(non_upper_case_globals)]
     #[doc(hidden)]
     #[deprecated(since = "0.5.0", note = "use `Unit::ETHER` instead")]
     pub const Ether: Self = Self::ETHER;
 
     /// The smallest unit.
-    pub const MIN: Self = Self::WEI;
+    pub const MIN: Self = Self::ORE;
     /// The largest unit.
     pub const MAX: Self = unsafe { Self::new_unchecked(77) };
 
@@ -473,6 +524,22 @@ impl Unit {
     /// ```
     #[inline]
     pub fn wei(self) -> U256 {
+        self.ore()
+    }
+
+    /// Returns `10^self`, which is the number of Ore in this unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use base_primitives::{utils::Unit, U256};
+    ///
+    /// assert_eq!(U256::from(1u128), Unit::ORE.ore());
+    /// assert_eq!(U256::from(1_000_000_000u128), Unit::NUCLE.ore());
+    /// assert_eq!(U256::from(1_000_000_000_000_000_000u128), Unit::CORE.ore());
+    /// ```
+    #[inline]
+    pub fn ore(self) -> U256 {
         if self.get() <= MAX_U64_EXPONENT {
             self.wei_const()
         } else {
@@ -553,6 +620,32 @@ mod tests {
         assert_eq!(Unit::try_from("ether").unwrap(), Unit::ETHER);
     }
 
+    #[test]
+    fn core_units() {
+        assert_eq!(Unit::ORE, Unit::WEI);
+        assert_eq!(Unit::WAV, Unit::KWEI);
+        assert_eq!(Unit::GRAV, Unit::MWEI);
+        assert_eq!(Unit::NUCLE, Unit::GWEI);
+        assert_eq!(Unit::ATOM, Unit::TWEI);
+        assert_eq!(Unit::MOLI, Unit::PWEI);
+        assert_eq!(Unit::CORE, Unit::ETHER);
+
+        assert_eq!(Unit::try_from("ore").unwrap(), Unit::ORE);
+        assert_eq!(Unit::try_from("wav").unwrap(), Unit::WAV);
+        assert_eq!(Unit::try_from("grav").unwrap(), Unit::GRAV);
+        assert_eq!(Unit::try_from("nucle").unwrap(), Unit::NUCLE);
+        assert_eq!(Unit::try_from("atom").unwrap(), Unit::ATOM);
+        assert_eq!(Unit::try_from("moli").unwrap(), Unit::MOLI);
+        assert_eq!(Unit::try_from("core").unwrap(), Unit::CORE);
+        assert_eq!(Unit::try_from("CORE").unwrap(), Unit::CORE);
+
+        assert_eq!(parse_core("1.5").unwrap(), U256::from(1_500_000_000_000_000_000u128));
+        assert_eq!(parse_units("2", "nucle").unwrap(), ParseUnits::U256(U256::from(2e9 as u64)));
+        assert_eq!(format_core(U256::from(1_500_000_000_000_000_000u128)), "1.500000000000000000");
+        assert_eq!(format_units(2_000_000_000u64, "nucle").unwrap(), "2.000000000");
+        assert_eq!(format_core(-1_500_000_000_000_000_000i128), "-1.500000000000000000");
+    }
+
     #[test]
     fn wei_in_ether() {
         assert_eq!(Unit::ETHER.wei(), U256::from(1e18 as u64));
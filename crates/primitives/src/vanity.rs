@@ -0,0 +1,353 @@
+//! Multithreaded search for vanity [ICAN addresses](IcanAddress).
+//!
+//! A [`Search`] tries `CREATE2` salts or private keys until the resulting
+//! address matches a [`Pattern`]. Each attempt costs one hash for salts, and
+//! one Ed448 key derivation for private keys, so every additional hex digit in
+//! the pattern makes the search about 16 times longer.
+//!
+//! # Examples
+//!
+//! ```
+//! use base_primitives::{cAddress, sha3, vanity};
+//!
+//! let deployer = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+//! let init_code_hash = sha3([0x60, 0x80, 0x60, 0x40]);
+//!
+//! let pattern = vanity::Pattern::new("c0", "")?;
+//! let found = vanity::Search::new(pattern).create2_salt(&deployer, &init_code_hash).unwrap();
+//! assert_eq!(deployer.create2(found.value, init_code_hash), found.address);
+//! assert!(found.address.to_string()[4..].starts_with("c0"));
+//! # Ok::<_, base_primitives::hex::FromHexError>(())
+//! ```
+
+use crate::{FixedBytes, IcanAddress, B256};
+use core::fmt;
+use hex::FromHexError;
+use libgoldilocks::SigningKey;
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    thread,
+};
+
+/// The number of attempts each thread makes between progress reports.
+const BATCH: u64 = 1024;
+
+/// The hex digits an address must start and end with.
+///
+/// The pattern applies to the 40 digits of the address after the network
+/// prefix and the checksum, which are fixed by the network and derived from
+/// the rest of the address, respectively. Digits are matched case-insensitively.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Pattern {
+    prefix: Vec<u8>,
+    suffix: Vec<u8>,
+}
+
+impl Pattern {
+    /// Creates a pattern from the hex digits the address must start and end
+    /// with. Either may be empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the digits are not valid hex, or if there are more
+    /// than 40 of them in total.
+    pub fn new(prefix: &str, suffix: &str) -> Result<Self, FromHexError> {
+        let prefix = nibbles(prefix)?;
+        let suffix = nibbles(suffix)?;
+        if prefix.len() + suffix.len() > 40 {
+            return Err(FromHexError::InvalidStringLength);
+        }
+        Ok(Self { prefix, suffix })
+    }
+
+    /// Returns `true` if the address matches the pattern.
+    pub fn matches(&self, address: &IcanAddress) -> bool {
+        let nibble = |i: usize| {
+            let byte = address[2 + i / 2];
+            if i % 2 == 0 {
+                byte >> 4
+            } else {
+                byte & 0x0f
+            }
+        };
+        let suffix_start = 40 - self.suffix.len();
+        self.prefix.iter().enumerate().all(|(i, &n)| nibble(i) == n)
+            && self.suffix.iter().enumerate().all(|(i, &n)| nibble(suffix_start + i) == n)
+    }
+
+    /// Returns the expected number of attempts to find a match.
+    #[inline]
+    pub fn difficulty(&self) -> f64 {
+        16f64.powi((self.prefix.len() + self.suffix.len()) as i32)
+    }
+}
+
+fn nibbles(s: &str) -> Result<Vec<u8>, FromHexError> {
+    s.chars()
+        .enumerate()
+        .map(|(index, c)| {
+            c.to_digit(16).map(|d| d as u8).ok_or(FromHexError::InvalidHexCharacter { c, index })
+        })
+        .collect()
+}
+
+/// A value found by a [`Search`], and the address it results in.
+///
+/// The value may be a private key, so it is left out of the [`Debug`] output.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Match<T> {
+    /// The salt or private key.
+    pub value: T,
+    /// The address that matches the pattern.
+    pub address: IcanAddress,
+    /// The number of attempts made by all threads until the match was found.
+    pub attempts: u64,
+}
+
+impl<T> fmt::Debug for Match<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Match")
+            .field("address", &self.address)
+            .field("attempts", &self.attempts)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A multithreaded search for an address that matches a [`Pattern`].
+///
+/// Each thread starts from a random value and increments it, so repeated
+/// searches find different matches.
+pub struct Search<'a> {
+    pattern: Pattern,
+    threads: usize,
+    cancel: Option<&'a AtomicBool>,
+    progress: Option<&'a (dyn Fn(u64) + Sync)>,
+}
+
+impl fmt::Debug for Search<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Search")
+            .field("pattern", &self.pattern)
+            .field("threads", &self.threads)
+            .field("cancel", &self.cancel)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> Search<'a> {
+    /// Creates a new search for the given pattern, using all available
+    /// threads.
+    pub fn new(pattern: Pattern) -> Self {
+        let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        Self { pattern, threads, cancel: None, progress: None }
+    }
+
+    /// Sets the number of threads to search with.
+    ///
+    /// # Panics
+    ///
+    /// If `threads` is zero.
+    #[inline]
+    pub fn threads(mut self, threads: usize) -> Self {
+        assert!(threads > 0, "a search needs at least one thread");
+        self.threads = threads;
+        self
+    }
+
+    /// Stops the search when `cancel` is set to `true`.
+    #[inline]
+    pub const fn cancel_on(mut self, cancel: &'a AtomicBool) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// Calls `progress` periodically with the total number of attempts made
+    /// so far.
+    ///
+    /// The callback is called from the search threads, possibly concurrently.
+    #[inline]
+    pub fn on_progress(mut self, progress: &'a (dyn Fn(u64) + Sync)) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Searches for a salt for which
+    /// [`deployer.create2(salt, init_code_hash)`](IcanAddress::create2)
+    /// matches the pattern.
+    ///
+    /// The address is on the same network as the deployer. Returns `None` if
+    /// the search is cancelled.
+    pub fn create2_salt(
+        &self,
+        deployer: &IcanAddress,
+        init_code_hash: &B256,
+    ) -> Option<Match<B256>> {
+        self.run(|salt: &B256| deployer.create2(salt, init_code_hash))
+    }
+
+    /// Searches for an Ed448 private key whose address on the given network
+    /// matches the pattern.
+    ///
+    /// Returns `None` if the search is cancelled.
+    pub fn private_key(&self, network_id: u64) -> Option<Match<FixedBytes<57>>> {
+        self.run(|key: &FixedBytes<57>| {
+            IcanAddress::from_private_key(&SigningKey::from_bytes(key.as_slice()), network_id)
+        })
+    }
+
+    fn run<const N: usize>(
+        &self,
+        address_of: impl Fn(&FixedBytes<N>) -> IcanAddress + Sync,
+    ) -> Option<Match<FixedBytes<N>>> {
+        let done = AtomicBool::new(false);
+        let attempts = AtomicU64::new(0);
+        let stopped = || {
+            done.load(Ordering::Relaxed)
+                || self.cancel.map_or(false, |cancel| cancel.load(Ordering::Relaxed))
+        };
+
+        let worker = |mut value: FixedBytes<N>| {
+            let mut batch = 0;
+            while !stopped() {
+                let address = address_of(&value);
+                batch += 1;
+                if self.pattern.matches(&address) {
+                    done.store(true, Ordering::Relaxed);
+                    attempts.fetch_add(batch, Ordering::Relaxed);
+                    return Some((value, address));
+                }
+                if batch == BATCH {
+                    let total = attempts.fetch_add(batch, Ordering::Relaxed) + batch;
+                    batch = 0;
+                    if let Some(progress) = self.progress {
+                        progress(total);
+                    }
+                }
+                increment(&mut value);
+            }
+            attempts.fetch_add(batch, Ordering::Relaxed);
+            None
+        };
+
+        let found = thread::scope(|s| {
+            let handles = (0..self.threads)
+                .map(|_| {
+                    let start = FixedBytes::random();
+                    s.spawn(move || worker(start))
+                })
+                .collect::<Vec<_>>();
+            handles.into_iter().filter_map(|handle| handle.join().unwrap()).next()
+        });
+
+        found.map(|(value, address)| Match {
+            value,
+            address,
+            attempts: attempts.load(Ordering::Relaxed),
+        })
+    }
+}
+
+/// Increments the value as a big-endian integer, wrapping on overflow.
+fn increment<const N: usize>(value: &mut FixedBytes<N>) {
+    for byte in value.iter_mut().rev() {
+        let (next, overflow) = byte.overflowing_add(1);
+        *byte = next;
+        if !overflow {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cAddress, sha3};
+
+    #[test]
+    fn pattern() {
+        let address = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+        for (prefix, suffix) in
+            [("", ""), ("a", ""), ("A5FD", ""), ("", "5"), ("", "BFC5"), ("a5f", "fc5")]
+        {
+            let pattern = Pattern::new(prefix, suffix).unwrap();
+            assert!(pattern.matches(&address), "{prefix:?} {suffix:?}");
+        }
+        for (prefix, suffix) in [("cb", ""), ("82", ""), ("a6", ""), ("", "c4"), ("a5", "4")] {
+            let pattern = Pattern::new(prefix, suffix).unwrap();
+            assert!(!pattern.matches(&address), "{prefix:?} {suffix:?}");
+        }
+
+        let full = "a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5";
+        assert!(Pattern::new(full, "").unwrap().matches(&address));
+        assert!(Pattern::new(&full[..20], &full[20..]).unwrap().matches(&address));
+        assert_eq!(Pattern::new(full, "5"), Err(FromHexError::InvalidStringLength));
+        assert_eq!(
+            Pattern::new("c0ffee", "0x"),
+            Err(FromHexError::InvalidHexCharacter { c: 'x', index: 1 })
+        );
+        assert_eq!(Pattern::new("c0", "ff").unwrap().difficulty(), 65536.0);
+    }
+
+    #[test]
+    fn increment() {
+        let mut value = FixedBytes::new([0x00, 0xfe, 0xff]);
+        super::increment(&mut value);
+        assert_eq!(value, FixedBytes::new([0x00, 0xff, 0x00]));
+        let mut value = FixedBytes::new([0x00, 0xff, 0xff]);
+        super::increment(&mut value);
+        assert_eq!(value, FixedBytes::new([0x01, 0x00, 0x00]));
+        let mut value = FixedBytes::new([0xff; 2]);
+        super::increment(&mut value);
+        assert_eq!(value, FixedBytes::ZERO);
+    }
+
+    #[test]
+    fn create2_salt() {
+        let deployer = cAddress!("ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+        let init_code_hash = sha3([0x60, 0x80, 0x60, 0x40]);
+        let pattern = Pattern::new("be", "f").unwrap();
+        let found = Search::new(pattern.clone())
+            .threads(4)
+            .create2_salt(&deployer, &init_code_hash)
+            .unwrap();
+        assert_eq!(deployer.create2(found.value, init_code_hash), found.address);
+        assert!(pattern.matches(&found.address));
+        assert_eq!(found.address.prefix(), deployer.prefix());
+        assert!(found.address.has_valid_checksum());
+        assert!(found.attempts > 0);
+    }
+
+    #[test]
+    fn private_key() {
+        let pattern = Pattern::new("", "a").unwrap();
+        let found = Search::new(pattern.clone()).threads(2).private_key(3).unwrap();
+        let key = SigningKey::from_bytes(found.value.as_slice());
+        assert_eq!(IcanAddress::from_private_key(&key, 3), found.address);
+        assert!(pattern.matches(&found.address));
+        assert!(!format!("{found:?}").contains(&crate::hex::encode(found.value)));
+    }
+
+    #[test]
+    fn cancel() {
+        let deployer = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+        let pattern = Pattern::new("0123456789abcdef0123456789abcdef01234567", "").unwrap();
+
+        let cancel = AtomicBool::new(true);
+        let search = Search::new(pattern.clone()).cancel_on(&cancel);
+        assert_eq!(search.create2_salt(&deployer, &B256::ZERO), None);
+
+        // Cancel from the progress callback.
+        let cancel = AtomicBool::new(false);
+        let reported = AtomicU64::new(0);
+        let progress = |attempts: u64| {
+            reported.fetch_max(attempts, Ordering::Relaxed);
+            if attempts >= 10 * BATCH {
+                cancel.store(true, Ordering::Relaxed);
+            }
+        };
+        let search = Search::new(pattern).threads(3).cancel_on(&cancel).on_progress(&progress);
+        assert_eq!(search.create2_salt(&deployer, &B256::ZERO), None);
+        assert!(reported.load(Ordering::Relaxed) >= 10 * BATCH);
+    }
+}
@@ -6,8 +6,6 @@ use syn::{
     LitFloat, LitInt, Result,
 };
 
-// TODO: Fixed point numbers
-
 /// An integer or fixed-point number literal: `1` or `1.0`.
 #[derive(Clone)]
 pub enum LitNumber {
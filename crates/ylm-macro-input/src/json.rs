@@ -1,5 +1,5 @@
 use crate::{YlmInput, YlmInputKind};
-use base_json_abi::{ContractObject, JsonAbi, ToYlmConfig};
+use base_json_abi::{ContractObject, JsonAbi, ToYlmConfig, UnlinkedBytecode};
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, TokenStreamExt};
 use syn::Result;
@@ -10,12 +10,33 @@ impl YlmInput {
         let YlmInput {
             attrs,
             path,
-            kind: YlmInputKind::Json(name, ContractObject { abi, bytecode, deployed_bytecode }),
+            kind:
+                YlmInputKind::Json(
+                    name,
+                    ContractObject {
+                        abi,
+                        bytecode,
+                        deployed_bytecode,
+                        unlinked_bytecode,
+                        unlinked_deployed_bytecode,
+                    },
+                ),
         } = self
         else {
             return Ok(self);
         };
 
+        let libraries = unlinked_libraries(
+            [&unlinked_bytecode, &unlinked_deployed_bytecode].into_iter().flatten(),
+        );
+        if !libraries.is_empty() {
+            let msg = format!(
+                "the bytecode of `{name}` is not linked, unresolved libraries: {}",
+                libraries.join(", ")
+            );
+            return Err(syn::Error::new(name.span(), msg));
+        }
+
         let mut abi = abi.ok_or_else(|| syn::Error::new(name.span(), "ABI not found in JSON"))?;
         let ylm = abi_to_ylm(&name, &mut abi);
         let ylm_interface_tokens = tokens_for_sol(&name, &ylm)?;
@@ -70,6 +91,41 @@ fn abi_to_ylm(name: &Ident, abi: &mut JsonAbi) -> String {
     abi.to_ylm(&name.to_string(), Some(ToYlmConfig::new().print_constructors(true)))
 }
 
+/// Returns the libraries that the given bytecode still needs to be linked with:
+/// the fully qualified names of the link references, followed by the legacy
+/// library names or hashed placeholders that have no link reference.
+fn unlinked_libraries<'a>(codes: impl IntoIterator<Item = &'a UnlinkedBytecode>) -> Vec<String> {
+    let mut libraries = Vec::new();
+    let mut push = |library: String| {
+        if !libraries.contains(&library) {
+            libraries.push(library);
+        }
+    };
+    for code in codes {
+        let mut referenced = Vec::new();
+        for (file, file_libraries) in code.link_references() {
+            for library in file_libraries.keys() {
+                let fqn = format!("{file}:{library}");
+                referenced.push(UnlinkedBytecode::placeholder(&fqn));
+                referenced.push(UnlinkedBytecode::legacy_placeholder(&fqn));
+                referenced.push(UnlinkedBytecode::legacy_placeholder(library));
+                push(fqn);
+            }
+        }
+        for placeholder in code.placeholders() {
+            if referenced.iter().any(|r| r == placeholder) {
+                continue;
+            }
+            if placeholder.starts_with("__$") {
+                push(placeholder.to_string());
+            } else {
+                push(placeholder.trim_matches('_').to_string());
+            }
+        }
+    }
+    libraries
+}
+
 /// Returns `ylm!` tokens.
 fn tokens_for_sol(name: &Ident, sol: &str) -> Result<TokenStream> {
     let mk_err = |s: &str| {
@@ -256,6 +312,33 @@ mod tests {
         (c, name)
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore = "no fs")]
+    fn unlinked() {
+        let path =
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../json-abi/tests/testdata/UnlinkedYlem.json");
+        let contract: ContractObject =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        let input = |contract| YlmInput {
+            attrs: Vec::new(),
+            path: None,
+            kind: YlmInputKind::Json(id("Unlinked"), contract),
+        };
+
+        let e = input(contract.clone()).normalize_json().unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "the bytecode of `Unlinked` is not linked, unresolved libraries: \
+             src/Math.ylm:Math, Strings"
+        );
+
+        let mut contract = contract;
+        contract.link("src/Math.ylm:Math", Default::default());
+        contract.link("Strings", Default::default());
+        assert!(contract.is_linked());
+        assert!(input(contract).normalize_json().is_ok());
+    }
+
     fn write_tmp_sol(name: &str, contents: &str) -> PathBuf {
         let path = std::env::temp_dir().join(format!("ylm-macro-{name}.sol"));
         std::fs::write(&path, contents).unwrap();
@@ -53,6 +53,16 @@ pub(crate) fn rec_expand_type(ty: &Type, crates: &ExternCrates, tokens: &mut Tok
 
             quote_spanned! {span=> #base_ylm_types::ylm_data::#name<#size> }
         }
+        Type::Fixed(span, size) | Type::Ufixed(span, size) => {
+            let name = match ty {
+                Type::Fixed(..) => "Fixed",
+                Type::Ufixed(..) => "Ufixed",
+                _ => unreachable!(),
+            };
+            let name = Ident::new(name, span);
+            let (bits, decimals) = fixed_size(size);
+            quote_spanned! {span=> #base_ylm_types::ylm_data::#name<#bits, #decimals> }
+        }
 
         Type::Tuple(ref tuple) => {
             return tuple.paren_token.surround(tokens, |tokens| {
@@ -122,6 +132,16 @@ pub(crate) fn rec_expand_rust_type(ty: &Type, crates: &ExternCrates, tokens: &mu
                 _ => unreachable!(),
             }
         }
+        Type::Fixed(span, size) | Type::Ufixed(span, size) => {
+            let name = match ty {
+                Type::Fixed(..) => "Fixed",
+                Type::Ufixed(..) => "Ufixed",
+                _ => unreachable!(),
+            };
+            let name = Ident::new(name, span);
+            let (bits, decimals) = fixed_size(size);
+            quote_spanned! {span=> #base_ylm_types::private::#name<#bits, #decimals> }
+        }
 
         Type::Tuple(ref tuple) => {
             return tuple.paren_token.surround(tokens, |tokens| {
@@ -158,6 +178,14 @@ pub(crate) fn rec_expand_rust_type(ty: &Type, crates: &ExternCrates, tokens: &mu
     tokens.extend(tts);
 }
 
+/// Returns the bits and decimals of a `u?fixed` type as literals. `u?fixed` is
+/// an alias for `u?fixed128x18`.
+fn fixed_size(size: Option<(NonZeroU16, u8)>) -> (Literal, Literal) {
+    let (bits, decimals) = size.map_or((128, 18), |(bits, decimals)| (bits.get(), decimals));
+    assert!(bits <= 256 && bits % 8 == 0 && decimals <= 80);
+    (Literal::u16_unsuffixed(bits), Literal::u8_unsuffixed(decimals))
+}
+
 /// Calculates the base ABI-encoded size of the given parameters in bytes.
 ///
 /// See [`type_base_data_size`] for more information.
@@ -177,6 +205,8 @@ pub(super) fn type_base_data_size(cx: &ExpCtxt<'_>, ty: &Type) -> usize {
         | Type::Bool(_)
         | Type::Int(..)
         | Type::Uint(..)
+        | Type::Fixed(..)
+        | Type::Ufixed(..)
         | Type::FixedBytes(..)
         | Type::Function(_) => 32,
 
@@ -302,6 +332,8 @@ impl fmt::Display for TypePrinter<'_> {
         match self.ty {
             Type::Int(_, None) => f.write_str("int256"),
             Type::Uint(_, None) => f.write_str("uint256"),
+            Type::Fixed(_, None) => f.write_str("fixed128x18"),
+            Type::Ufixed(_, None) => f.write_str("ufixed128x18"),
 
             Type::Array(array) => {
                 Self::new(self.cx, &array.ty).fmt(f)?;
@@ -85,10 +85,13 @@ impl<'a> RootType<'a> {
                     return Self("uint8");
                 }
 
-                // Normalize the `u?int` aliases to the canonical `u?int256`
+                // Normalize the `u?int` and `u?fixed` aliases to the canonical
+                // `u?int256` and `u?fixed128x18`
                 match ident {
                     "uint" => Self("uint256"),
                     "int" => Self("int256"),
+                    "ufixed" => Self("ufixed128x18"),
+                    "fixed" => Self("fixed128x18"),
                     _ => Self(ident),
                 }
             })
@@ -106,7 +109,8 @@ impl<'a> RootType<'a> {
     #[inline]
     pub fn try_basic_solidity(self) -> Result<()> {
         match self.0 {
-            "address" | "bool" | "string" | "bytes" | "uint" | "int" | "function" => Ok(()),
+            "address" | "bool" | "string" | "bytes" | "uint" | "int" | "ufixed" | "fixed"
+            | "function" => Ok(()),
             name => {
                 if let Some(sz) = name.strip_prefix("bytes") {
                     if let Ok(sz) = sz.parse::<usize>() {
@@ -129,6 +133,19 @@ impl<'a> RootType<'a> {
                     return Err(Error::invalid_size(name));
                 }
 
+                // `u?fixedMxN`, with the same bit sizes as the integers and at
+                // most 80 decimals
+                if let Some(sz) = s.strip_prefix("fixed") {
+                    if let Some((m, n)) = sz.split_once('x') {
+                        if let (Ok(m), Ok(n)) = (m.parse::<usize>(), n.parse::<usize>()) {
+                            if m != 0 && m <= 256 && m % 8 == 0 && n <= 80 {
+                                return Ok(());
+                            }
+                        }
+                    }
+                    return Err(Error::invalid_size(name));
+                }
+
                 Err(Error::invalid_type_string(name))
             }
         }
@@ -145,5 +162,25 @@ mod tests {
 
         assert_eq!(RootType::parse("int"), Ok(RootType("int256")));
         assert_eq!(RootType::parse("uint"), Ok(RootType("uint256")));
+        assert_eq!(RootType::parse("fixed"), Ok(RootType("fixed128x18")));
+        assert_eq!(RootType::parse("ufixed"), Ok(RootType("ufixed128x18")));
+    }
+
+    #[test]
+    fn fixed() {
+        for ty in ["fixed128x18", "ufixed128x18", "fixed8x0", "ufixed256x80", "fixed"] {
+            assert_eq!(RootType(ty).try_basic_solidity(), Ok(()), "{ty}");
+        }
+        for ty in [
+            "fixed128",
+            "ufixed0x18",
+            "fixed264x18",
+            "fixed12x18",
+            "ufixed128x81",
+            "fixed128x",
+            "fixedx18",
+        ] {
+            assert_eq!(RootType(ty).try_basic_solidity(), Err(Error::invalid_size(ty)), "{ty}");
+        }
     }
 }
@@ -606,7 +606,7 @@ mod tests {
 
         let encoded = hex!(
             "
-    		0000000000000000000000008497afefdc5ac170a664a231f6efb25526ef813f
+    		0000000000000000000000768497afefdc5ac170a664a231f6efb25526ef813f
     		0101010101010101010101010101010101010101010101010101010101010101
     		0202020202020202020202020202020202020202020202020202020202020202
     		0000000000000000000000000000000000000000000000000000000000000080
@@ -618,7 +618,7 @@ mod tests {
         assert_eq!(
             MyTy::abi_decode_params(&encoded, false).unwrap(),
             (
-                cAddress!("00008497afefdc5ac170a664a231f6efb25526ef813f"),
+                cAddress!("00768497afefdc5ac170a664a231f6efb25526ef813f"),
                 B256::repeat_byte(0x01),
                 [0x02; 4].into(),
                 "0x0000001F".into(),
@@ -1117,7 +1117,7 @@ mod tests {
 
         let data = (
             Vec::from(bytes!("09736b79736b79736b79026f7300")),
-            cAddress!("0000B7b54cd129e6D8B24e6AE652a473449B273eE3E4"),
+            cAddress!("0043B7b54cd129e6D8B24e6AE652a473449B273eE3E4"),
             Vec::<Vec<u8>>::new(),
         );
 
@@ -1126,7 +1126,7 @@ mod tests {
         let expected = hex!(
             "
             0000000000000000000000000000000000000000000000000000000000000060
-            000000000000000000000000B7b54cd129e6D8B24e6AE652a473449B273eE3E4
+            000000000000000000000043B7b54cd129e6D8B24e6AE652a473449B273eE3E4
             00000000000000000000000000000000000000000000000000000000000000a0
             000000000000000000000000000000000000000000000000000000000000000e
             09736b79736b79736b79026f7300000000000000000000000000000000000000
@@ -8,9 +8,15 @@ use base_primitives::{sha3, FixedBytes, IcanAddress, B256, U256};
 ///
 /// Protocol designers only need to include the fields that make sense for
 /// their signing domain.
+///
+/// Core contracts identify the network by its network ID, encoded as
+/// `uint256 networkId` with the JSON field `networkId`, matching go-core. The
+/// EIP-155 `chainId` field is still supported for domains that declare it.
+/// Unknown fields are rejected when deserializing rather than silently left out
+/// of the separator.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "eip712-serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "eip712-serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "eip712-serde", serde(rename_all = "camelCase", deny_unknown_fields))]
 pub struct Eip712Domain {
     /// The user readable name of signing domain, i.e. the name of the DApp or
     /// the protocol.
@@ -27,6 +33,11 @@ pub struct Eip712Domain {
     #[cfg_attr(feature = "eip712-serde", serde(default, skip_serializing_if = "Option::is_none"))]
     pub chain_id: Option<U256>,
 
+    /// The Core network ID. The user-agent should refuse signing if it does
+    /// not match the currently active network.
+    #[cfg_attr(feature = "eip712-serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub network_id: Option<U256>,
+
     /// The address of the contract that will verify the signature.
     #[cfg_attr(feature = "eip712-serde", serde(default, skip_serializing_if = "Option::is_none"))]
     pub verifying_contract: Option<IcanAddress>,
@@ -50,10 +61,11 @@ impl Eip712Domain {
         name: Option<Cow<'static, str>>,
         version: Option<Cow<'static, str>>,
         chain_id: Option<U256>,
+        network_id: Option<U256>,
         verifying_contract: Option<IcanAddress>,
         salt: Option<B256>,
     ) -> Self {
-        Self { name, version, chain_id, verifying_contract, salt }
+        Self { name, version, chain_id, network_id, verifying_contract, salt }
     }
 
     /// Calculate the domain separator for the domain object.
@@ -91,6 +103,7 @@ impl Eip712Domain {
             name               => "string name,",
             version            => "string version,",
             chain_id           => "uint256 chainId,",
+            network_id         => "uint256 networkId,",
             verifying_contract => "address verifyingContract,",
             salt               => "bytes32 salt",
         }
@@ -113,6 +126,7 @@ impl Eip712Domain {
         self.name.is_some() as usize
             + self.version.is_some() as usize
             + self.chain_id.is_some() as usize
+            + self.network_id.is_some() as usize
             + self.verifying_contract.is_some() as usize
             + self.salt.is_some() as usize
     }
@@ -145,6 +159,7 @@ impl Eip712Domain {
         encode_opt!(self.name.as_ref().map(cow_sha3));
         encode_opt!(self.version.as_ref().map(cow_sha3));
         encode_opt!(&self.chain_id);
+        encode_opt!(&self.network_id);
         encode_opt!(&self.verifying_contract);
         encode_opt!(&self.salt);
     }
@@ -177,6 +192,7 @@ impl Eip712Domain {
 /// - `name`
 /// - `version`
 /// - `chain_id`
+/// - `network_id`
 /// - `verifying_contract`
 /// - `salt`
 ///
@@ -193,6 +209,7 @@ impl Eip712Domain {
 /// let my_other_domain: Eip712Domain = eip712_domain! {
 ///     name: dynamic_name,
 ///     version: "1.0.0",
+///     network_id: 1,
 ///     salt: sha3("my domain salt"),
 /// };
 /// ```
@@ -210,6 +227,7 @@ macro_rules! eip712_domain {
         $(name: $name:expr,)?
         $(version: $version:expr,)?
         $(chain_id: $chain_id:expr,)?
+        $(network_id: $network_id:expr,)?
         $(verifying_contract: $verifying_contract:expr,)?
         $(salt: $salt:expr)?
         $(,)?
@@ -218,6 +236,7 @@ macro_rules! eip712_domain {
             $crate::eip712_domain!(@cow $($name)?),
             $crate::eip712_domain!(@cow $($version)?),
             $crate::eip712_domain!(@opt $($crate::private::u256($chain_id))?),
+            $crate::eip712_domain!(@opt $($crate::private::u256($network_id))?),
             $crate::eip712_domain!(@opt $($verifying_contract)?),
             $crate::eip712_domain!(@opt $($salt)?),
         )
@@ -238,25 +257,30 @@ mod tests {
     const _: Eip712Domain = eip712_domain! {
         name: "abcd",
         version: "1",
-        chain_id: 1,
+        network_id: 1,
     };
     const _: Eip712Domain = eip712_domain! {
         name: "abcd",
         version: "1",
         chain_id: 1,
+    };
+    const _: Eip712Domain = eip712_domain! {
+        name: "abcd",
+        version: "1",
+        network_id: 1,
         verifying_contract: IcanAddress::ZERO,
     };
     const _: Eip712Domain = eip712_domain! {
         name: "abcd",
         version: "1",
-        chain_id: 1,
+        network_id: 1,
         verifying_contract: IcanAddress::ZERO,
         salt: B256::ZERO // no trailing comma
     };
     const _: Eip712Domain = eip712_domain! {
         name: "abcd",
         version: "1",
-        chain_id: 1,
+        network_id: 1,
         verifying_contract: IcanAddress::ZERO,
         salt: B256::ZERO, // trailing comma
     };
@@ -264,42 +288,42 @@ mod tests {
     const _: Eip712Domain = eip712_domain! {
         name: "abcd",
         version: "1",
-        // chain_id: 1,
+        // network_id: 1,
         verifying_contract: IcanAddress::ZERO,
         salt: B256::ZERO,
     };
     const _: Eip712Domain = eip712_domain! {
         name: "abcd",
         // version: "1",
-        chain_id: 1,
+        network_id: 1,
         verifying_contract: IcanAddress::ZERO,
         salt: B256::ZERO,
     };
     const _: Eip712Domain = eip712_domain! {
         name: "abcd",
         // version: "1",
-        // chain_id: 1,
+        // network_id: 1,
         verifying_contract: IcanAddress::ZERO,
         salt: B256::ZERO,
     };
     const _: Eip712Domain = eip712_domain! {
         name: "abcd",
         // version: "1",
-        // chain_id: 1,
+        // network_id: 1,
         // verifying_contract: IcanAddress::ZERO,
         salt: B256::ZERO,
     };
     const _: Eip712Domain = eip712_domain! {
         // name: "abcd",
         version: "1",
-        // chain_id: 1,
+        // network_id: 1,
         // verifying_contract: IcanAddress::ZERO,
         salt: B256::ZERO,
     };
     const _: Eip712Domain = eip712_domain! {
         // name: "abcd",
         version: "1",
-        // chain_id: 1,
+        // network_id: 1,
         verifying_contract: IcanAddress::ZERO,
         salt: B256::ZERO,
     };
@@ -323,4 +347,54 @@ mod tests {
             version: my_cow.into_owned(),
         };
     }
+
+    #[test]
+    fn network_id() {
+        let domain = eip712_domain! {
+            name: "Exchange",
+            version: "1",
+            network_id: 3,
+            verifying_contract: base_primitives::cAddress!("cb203333333333333333333333333333333333333333"),
+        };
+        assert_eq!(
+            domain.encode_type(),
+            "EIP712Domain(string name,string version,uint256 networkId,address verifyingContract)"
+        );
+        assert_eq!(
+            domain.type_hash(),
+            base_primitives::b256!(
+                "ad9882c79b5c42809b78a08339443ad633a6d4ca03d62385cd985c860094a9fa"
+            )
+        );
+        assert_eq!(
+            domain.separator(),
+            base_primitives::b256!(
+                "863e46e1f09f2e87fdc5ac6efa316e8cae31ddefe5d3073b444ccf57897cd01e"
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "eip712-serde")]
+    fn network_id_serde() {
+        let domain = eip712_domain! {
+            name: "Exchange",
+            network_id: 3,
+        };
+        let json = serde_json::to_string(&domain).unwrap();
+        assert_eq!(json, r#"{"name":"Exchange","networkId":"0x3"}"#);
+        assert_eq!(serde_json::from_str::<Eip712Domain>(&json).unwrap(), domain);
+
+        let domain = eip712_domain! {
+            name: "Exchange",
+            chain_id: 3,
+        };
+        let json = serde_json::to_string(&domain).unwrap();
+        assert_eq!(json, r#"{"name":"Exchange","chainId":"0x3"}"#);
+        assert_eq!(serde_json::from_str::<Eip712Domain>(&json).unwrap(), domain);
+
+        let err = serde_json::from_str::<Eip712Domain>(r#"{"name":"Exchange","networkID":"0x3"}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown field `networkID`"), "{err}");
+    }
 }
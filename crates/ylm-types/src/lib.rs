@@ -214,8 +214,8 @@ pub mod private {
         vec::Vec,
     };
     pub use base_primitives::{
-        bytes, sha3, Bytes, FixedBytes, Function, IcanAddress as Address, LogData, Signed, Uint,
-        B256, I256, U256,
+        bytes, sha3, Bytes, Fixed, FixedBytes, Function, IcanAddress as Address, LogData, Signed,
+        Ufixed, Uint, B256, I256, U256,
     };
     pub use core::{
         borrow::{Borrow, BorrowMut},
@@ -11,8 +11,8 @@
 use crate::{abi::token::*, private::YlmTypeValue, utils, Word, YlmType};
 use alloc::{string::String as RustString, vec::Vec};
 use base_primitives::{
-    sha3, Bytes as RustBytes, FixedBytes as RustFixedBytes, Function as RustFunction,
-    IcanAddress as RustIcanAddress, I256, U256,
+    sha3, Bytes as RustBytes, Fixed as RustFixed, FixedBytes as RustFixedBytes,
+    Function as RustFunction, IcanAddress as RustIcanAddress, Ufixed as RustUfixed, I256, U256,
 };
 use core::{borrow::Borrow, fmt::*, hash::Hash, marker::PhantomData, ops::*};
 
@@ -155,6 +155,106 @@ where
     }
 }
 
+/// Fixed - `fixedMxN`
+pub struct Fixed<const M: usize, const N: usize>;
+
+impl<T, const M: usize, const N: usize> YlmTypeValue<Fixed<M, N>> for T
+where
+    T: Borrow<RustFixed<M, N>>,
+{
+    #[inline]
+    fn stv_to_tokens(&self) -> WordToken {
+        WordToken(self.borrow().into_raw().to_be_bytes::<32>().into())
+    }
+
+    #[inline]
+    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.borrow().into_raw().to_be_bytes::<32>()[32 - M / 8..]);
+    }
+
+    #[inline]
+    fn stv_eip712_data_word(&self) -> Word {
+        YlmTypeValue::<Fixed<M, N>>::stv_to_tokens(self).0
+    }
+}
+
+impl<const M: usize, const N: usize> YlmType for Fixed<M, N> {
+    type RustType = RustFixed<M, N>;
+    type Token<'a> = WordToken;
+
+    const YLM_NAME: &'static str = NameBuffer::new()
+        .write_str("fixed")
+        .write_usize(M)
+        .write_byte(b'x')
+        .write_usize(N)
+        .as_str();
+    const ENCODED_SIZE: Option<usize> = Some(32);
+
+    #[inline]
+    fn valid_token(token: &Self::Token<'_>) -> bool {
+        let msb = 32 - M / 8;
+        let sign_extension = (token.0[msb] & 0x80 == 0x80) as u8 * 0xff;
+        token.0[..msb].iter().all(|byte| *byte == sign_extension)
+    }
+
+    #[inline]
+    fn detokenize(mut token: Self::Token<'_>) -> Self::RustType {
+        // sign extend bits to ignore
+        let msb = 32 - M / 8;
+        let sign_extension = (token.0[msb] & 0x80 == 0x80) as u8 * 0xff;
+        token.0[..msb].fill(sign_extension);
+        RustFixed::from_raw(I256::from_be_bytes(token.0 .0)).expect("value fits in M bits")
+    }
+}
+
+/// Ufixed - `ufixedMxN`
+pub struct Ufixed<const M: usize, const N: usize>;
+
+impl<T, const M: usize, const N: usize> YlmTypeValue<Ufixed<M, N>> for T
+where
+    T: Borrow<RustUfixed<M, N>>,
+{
+    #[inline]
+    fn stv_to_tokens(&self) -> WordToken {
+        WordToken(self.borrow().into_raw().to_be_bytes::<32>().into())
+    }
+
+    #[inline]
+    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.borrow().into_raw().to_be_bytes::<32>()[32 - M / 8..]);
+    }
+
+    #[inline]
+    fn stv_eip712_data_word(&self) -> Word {
+        YlmTypeValue::<Ufixed<M, N>>::stv_to_tokens(self).0
+    }
+}
+
+impl<const M: usize, const N: usize> YlmType for Ufixed<M, N> {
+    type RustType = RustUfixed<M, N>;
+    type Token<'a> = WordToken;
+
+    const YLM_NAME: &'static str = NameBuffer::new()
+        .write_str("ufixed")
+        .write_usize(M)
+        .write_byte(b'x')
+        .write_usize(N)
+        .as_str();
+    const ENCODED_SIZE: Option<usize> = Some(32);
+
+    #[inline]
+    fn valid_token(token: &Self::Token<'_>) -> bool {
+        utils::check_zeroes(&token.0[..32 - M / 8])
+    }
+
+    #[inline]
+    fn detokenize(mut token: Self::Token<'_>) -> Self::RustType {
+        // zero out bits to ignore
+        token.0[..32 - M / 8].fill(0);
+        RustUfixed::from_raw(U256::from_be_bytes(token.0 .0)).expect("value fits in M bits")
+    }
+}
+
 /// FixedBytes - `bytesX`
 #[derive(Clone, Copy, Debug)]
 pub struct FixedBytes<const N: usize>;
@@ -243,7 +343,7 @@ impl YlmType for Address {
 /// Function - `function`
 pub struct Function;
 
-impl<T: Borrow<[u8; 24]>> YlmTypeValue<Function> for T {
+impl<T: Borrow<[u8; 26]>> YlmTypeValue<Function> for T {
     #[inline]
     fn stv_to_tokens(&self) -> WordToken {
         WordToken(RustFunction::new(*self.borrow()).into_word())
@@ -274,7 +374,7 @@ impl YlmType for Function {
 
     #[inline]
     fn valid_token(token: &Self::Token<'_>) -> bool {
-        utils::check_zeroes(&token.0[24..])
+        utils::check_zeroes(&token.0[26..])
     }
 }
 
@@ -802,7 +902,8 @@ impl<const N: usize> Sealed for IntBitCount<N> {}
 
 // Declares types with the same traits
 // TODO: Add more traits
-// TODO: Integrate `num_traits` (needs `ruint`)
+// TODO: Bound by the `num_traits` traits, which `Signed` and `Uint` implement
+// behind their `num-traits` features
 macro_rules! declare_int_types {
     ($($(#[$attr:meta])* type $name:ident;)*) => {$(
         $(#[$attr])*
@@ -1142,6 +1243,9 @@ mod tests {
         assert_name!(Int<8>, "int8");
         assert_name!(Int<16>, "int16");
         assert_name!(Int<32>, "int32");
+        assert_name!(Fixed<128, 18>, "fixed128x18");
+        assert_name!(Ufixed<8, 0>, "ufixed8x0");
+        assert_name!(Ufixed<256, 80>, "ufixed256x80");
         assert_name!(FixedBytes<1>, "bytes1");
         assert_name!(FixedBytes<16>, "bytes16");
         assert_name!(FixedBytes<32>, "bytes32");
@@ -1185,6 +1289,8 @@ mod tests {
         assert_encoded_size!(Int<128>, Some(32));
         assert_encoded_size!(Uint<256>, Some(32));
         assert_encoded_size!(Int<256>, Some(32));
+        assert_encoded_size!(Fixed<128, 18>, Some(32));
+        assert_encoded_size!(Ufixed<128, 18>, Some(32));
 
         assert_encoded_size!(Address, Some(32));
         assert_encoded_size!(Function, Some(32));
@@ -1351,6 +1457,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fixed() {
+        let value: RustFixed<24, 2> = "-1.5".parse().unwrap();
+        let token = <Fixed<24, 2>>::tokenize(&value);
+        assert_eq!(
+            token,
+            WordToken::new(hex!(
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff6a"
+            ))
+        );
+        assert!(<Fixed<24, 2>>::valid_token(&token));
+        assert_eq!(<Fixed<24, 2>>::detokenize(token), value);
+        assert_eq!(<Fixed<24, 2>>::abi_encode_packed(&value), hex!("ffff6a"));
+
+        let value: RustUfixed<128, 18> = "1.5".parse().unwrap();
+        let token = <Ufixed<128, 18>>::tokenize(&value);
+        assert_eq!(
+            token,
+            WordToken::new(hex!(
+                "00000000000000000000000000000000000000000000000014d1120d7b160000"
+            ))
+        );
+        assert!(<Ufixed<128, 18>>::valid_token(&token));
+        assert_eq!(<Ufixed<128, 18>>::detokenize(token), value);
+        assert_eq!(
+            <Ufixed<128, 18>>::abi_encode_packed(&value),
+            hex!("000000000000000014d1120d7b160000")
+        );
+
+        // dirty upper bytes
+        let token = WordToken::new(hex!(
+            "00000000000000000000000000000000000000000000000000000000ff00006a"
+        ));
+        assert!(!<Fixed<24, 2>>::valid_token(&token));
+        assert!(!<Ufixed<24, 2>>::valid_token(&token));
+        assert_eq!(<Fixed<24, 2>>::detokenize(token).to_string(), "1.06");
+        assert_eq!(<Ufixed<24, 2>>::detokenize(token).to_string(), "1.06");
+    }
+
     #[test]
     fn detokenize_ints() {
         /*
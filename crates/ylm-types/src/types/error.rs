@@ -499,13 +499,13 @@ mod tests {
             }
         }
 
-        let data = hex!("d5e29942000000000000000000000000a48388222c7ee7daefde5d0b9c99319995c4a990");
+        let data = hex!("d5e29942000000000000000000000041a48388222c7ee7daefde5d0b9c99319995c4a990");
         assert_eq!(decode_revert_reason(&data), None);
 
         let C::CErrors::SenderAddressError(decoded) = C::CErrors::abi_decode(&data, true).unwrap();
         assert_eq!(
             decoded,
-            C::SenderAddressError { _0: cAddress!("0000a48388222c7ee7daefde5d0b9c99319995c4a990") }
+            C::SenderAddressError { _0: cAddress!("0041a48388222c7ee7daefde5d0b9c99319995c4a990") }
         );
     }
 }
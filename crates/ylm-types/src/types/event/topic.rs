@@ -91,6 +91,14 @@ where
     word_impl!();
 }
 
+impl<const M: usize, const N: usize> EventTopic for Fixed<M, N> {
+    word_impl!();
+}
+
+impl<const M: usize, const N: usize> EventTopic for Ufixed<M, N> {
+    word_impl!();
+}
+
 impl<const N: usize> EventTopic for FixedBytes<N>
 where
     ByteCount<N>: SupportedFixedBytes,
@@ -4,7 +4,7 @@
 use super::YlmType;
 use crate::Eip712Domain;
 use alloc::{borrow::Cow, string::String, vec::Vec};
-use base_primitives::{sha3, B256};
+use base_primitives::{sha3, IcanAddress, Signature, SignatureError, B256};
 
 /// A Ylem struct.
 ///
@@ -105,4 +105,43 @@ pub trait YlmStruct: YlmType<RustType = Self> {
         digest_input[34..66].copy_from_slice(&self.eip712_hash_struct()[..]);
         sha3(digest_input)
     }
+
+    /// Signs this struct's [signing hash](Self::eip712_signing_hash) in the
+    /// given domain.
+    #[cfg(feature = "signer")]
+    #[inline]
+    fn eip712_sign(
+        &self,
+        domain: &Eip712Domain,
+        signer: &base_primitives::LocalSigner,
+    ) -> Result<Signature, base_primitives::SignerError> {
+        signer.sign_hash(&self.eip712_signing_hash(domain))
+    }
+
+    /// Checks that `signature` is a valid signature of this struct's
+    /// [signing hash](Self::eip712_signing_hash) in the given domain, made by
+    /// `expected` on the given network.
+    #[inline]
+    fn eip712_verify(
+        &self,
+        domain: &Eip712Domain,
+        signature: &Signature,
+        expected: &IcanAddress,
+        network_id: u64,
+    ) -> Result<(), SignatureError> {
+        signature.verify(&self.eip712_signing_hash(domain), expected, network_id)
+    }
+
+    /// Verifies `signature` against this struct's
+    /// [signing hash](Self::eip712_signing_hash) in the given domain, and
+    /// returns the address of the signer on the given network.
+    #[inline]
+    fn eip712_recover_address(
+        &self,
+        domain: &Eip712Domain,
+        signature: &Signature,
+        network_id: u64,
+    ) -> Result<IcanAddress, SignatureError> {
+        signature.recover_address_from_prehash(&self.eip712_signing_hash(domain), network_id)
+    }
 }
@@ -1,4 +1,4 @@
-use base_primitives::B256;
+use base_primitives::{B256, U256};
 use base_ylm_types::{eip712_domain, ylm, YlmStruct};
 
 #[test]
@@ -63,3 +63,77 @@ fn encode_data_nesting() {
         "be504c79df6f0a61fbafb0d84827b301d2e888d9e578eea504654f73e33705be".parse::<B256>().unwrap()
     )
 }
+
+#[test]
+fn sign_verify_nested() {
+    use base_primitives::{b256, cAddress, hex, Signature};
+
+    ylm! {
+        struct Person {
+            string name;
+            address wallet;
+        }
+
+        struct Order {
+            Person maker;
+            Person[] takers;
+            uint256[] amounts;
+            bytes32 salt;
+        }
+    }
+
+    let domain = eip712_domain! {
+        name: "Exchange",
+        version: "1",
+        verifying_contract: cAddress!("cb203333333333333333333333333333333333333333"),
+    };
+    let maker = cAddress!("cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5");
+    let order = Order {
+        maker: Person { name: "Cow".to_owned(), wallet: maker },
+        takers: vec![
+            Person {
+                name: "Bob".to_owned(),
+                wallet: cAddress!("cb751111111111111111111111111111111111111111"),
+            },
+            Person {
+                name: "Alice".to_owned(),
+                wallet: cAddress!("cb962222222222222222222222222222222222222222"),
+            },
+        ],
+        amounts: vec![U256::from(100), U256::from(2).pow(U256::from(128))],
+        salt: B256::repeat_byte(0x42),
+    };
+
+    assert_eq!(
+        Order::eip712_encode_type(),
+        "Order(Person maker,Person[] takers,uint256[] amounts,bytes32 salt)Person(string name,address wallet)"
+    );
+    assert_eq!(
+        order.eip712_signing_hash(&domain),
+        b256!("fe046b235feb4399f88f17b5638b94ea1739a1fe34414f8bcdbd582e75ecf754")
+    );
+
+    let signature = Signature::from_bytes(&hex!(
+        "ef5caff99a454ff14b60370496b0a49067deb37bde920d2e6dde1ad542a0a7406a67844e83a2b72b71e21376e155d3372c77fc1016e0765680cc6f7b28021af3b711ff8bc3cbf4d0ae3683329c925a8de8384f2c5c778af0e86a76c24a4ac3f9a5b93e9dd61e18c455ce6aa30d40ad303600"
+        "315484db568379ce94f9c894e3e6e4c7ee216676b713ca892d9b26746ae902a772e217a6a8bb493ce2bb313cf0cb66e76765d4c45ec6b68600"
+    ))
+    .unwrap();
+
+    #[cfg(feature = "signer")]
+    {
+        let signer = base_primitives::LocalSigner::from_slice(&hex!("69bb68c3a00a0cd9cbf2cab316476228c758329bbfe0b1759e8634694a9497afea05bcbf24e2aa0627eac4240484bb71de646a9296872a3c0e")).unwrap();
+        assert_eq!(order.eip712_sign(&domain, &signer).unwrap(), signature);
+    }
+
+    order.eip712_verify(&domain, &signature, &maker, 1).unwrap();
+    assert_eq!(order.eip712_recover_address(&domain, &signature, 1).unwrap(), maker);
+    assert_eq!(
+        order.eip712_recover_address(&domain, &signature, 3).unwrap(),
+        cAddress!("ab03a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5")
+    );
+    assert!(order.eip712_verify(&domain, &signature, &maker, 3).is_err());
+
+    let mut tampered = order.clone();
+    tampered.amounts[1] += U256::from(1);
+    assert!(tampered.eip712_verify(&domain, &signature, &maker, 1).is_err());
+}
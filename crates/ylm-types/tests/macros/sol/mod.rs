@@ -805,3 +805,39 @@ fn bytecode_attributes() {
     assert_eq!(Dummy::BYTECODE[..], hex::decode("1234").unwrap());
     assert_eq!(Dummy::DEPLOYED_BYTECODE[..], hex::decode("5678").unwrap());
 }
+
+#[test]
+fn fixed_point() {
+    ylm! {
+        #[derive(Debug, PartialEq)]
+        struct Position {
+            ufixed128x18 price;
+            fixed amount;
+            ufixed8x1[2] fees;
+        }
+
+        function open(Position position, fixed64x4 leverage) returns (ufixed price);
+    }
+
+    assert_eq!(openCall::SIGNATURE, "open((ufixed128x18,fixed128x18,ufixed8x1[2]),fixed64x4)");
+
+    let position = Position {
+        price: "1234.5".parse().unwrap(),
+        amount: "-0.000000000000000001".parse().unwrap(),
+        fees: ["0.3".parse().unwrap(), "25.5".parse().unwrap()],
+    };
+    let encoded = Position::abi_encode(&position);
+    assert_eq!(
+        encoded,
+        hex!(
+            "000000000000000000000000000000000000000000000042ec210956b3ba0000"
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+            "0000000000000000000000000000000000000000000000000000000000000003"
+            "00000000000000000000000000000000000000000000000000000000000000ff"
+        )
+    );
+    assert_eq!(Position::abi_decode(&encoded, true).unwrap(), position);
+
+    let call = openCall { position, leverage: "2.5".parse().unwrap() };
+    assert_eq!(openCall::abi_decode(&call.abi_encode(), true).unwrap().leverage, call.leverage);
+}